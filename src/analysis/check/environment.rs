@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+
+use crate::ast::{
+    TypeAnnotation, TypeDeclaration, TypeGenericParameter, TypeReference, TypeTable, TypeTableEntry,
+};
+use crate::parser::{parse_declarations, ClassDeclaration, Declaration, ParseError};
+use crate::types::is_subtype_in;
+
+/// The global values, type aliases and classes available to a checked chunk.
+///
+/// Globals missing from the environment have the type `any`, as do references to types it does
+/// not define. Classes are compared by name, where a class is a subtype of the classes it extends.
+/// ```
+/// use luna::analysis::{check_in, TypeEnvironment};
+/// use luna::ast::{TypeFunction, TypePrimitive};
+/// use luna::parser::parse;
+///
+/// let mut environment = TypeEnvironment::new();
+/// environment.define_global(
+///     "wait",
+///     TypeFunction::builder().arg("seconds", TypePrimitive::number()).returns(TypePrimitive::number()).build(),
+/// );
+///
+/// let chunk = parse("wait(\"1\")").unwrap();
+/// assert_eq!(check_in(&chunk, &environment).len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TypeEnvironment {
+    globals: HashMap<String, TypeAnnotation>,
+    types: HashMap<String, TypeDefinition>,
+    classes: HashMap<String, ClassDeclaration>,
+}
+
+/// A type alias, with the generic parameters it is declared with.
+#[derive(Debug, Clone)]
+pub(crate) struct TypeDefinition {
+    pub(crate) generics: Vec<TypeGenericParameter>,
+    pub(crate) annotation: TypeAnnotation,
+}
+
+impl TypeEnvironment {
+    /// Constructs a new empty [`TypeEnvironment`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares a global with the given type, replacing any previous declaration of it.
+    pub fn define_global<N: Into<String>, T: Into<TypeAnnotation>>(
+        &mut self,
+        name: N,
+        annotation: T,
+    ) {
+        self.globals.insert(name.into(), annotation.into());
+    }
+
+    /// Returns the declared type of a global, if there is one.
+    pub fn global(&self, name: &str) -> Option<&TypeAnnotation> {
+        self.globals.get(name)
+    }
+
+    /// Returns the names of the declared globals, in no particular order.
+    pub fn globals(&self) -> impl Iterator<Item = &str> {
+        self.globals.keys().map(String::as_str)
+    }
+
+    /// Declares a type alias from a [`TypeDeclaration`], replacing any previous alias of the same
+    /// name.
+    pub fn define_type(&mut self, declaration: &TypeDeclaration) {
+        self.types.insert(
+            declaration.name().to_owned(),
+            TypeDefinition::from(declaration),
+        );
+    }
+
+    /// Returns whether a type alias of the given name is declared.
+    pub fn has_type(&self, name: &str) -> bool {
+        self.types.contains_key(name)
+    }
+
+    /// Declares a class, replacing any previous class of the same name. Its superclass can be
+    /// declared before or after it.
+    pub fn define_class(&mut self, class: ClassDeclaration) {
+        self.classes.insert(class.name().to_owned(), class);
+    }
+
+    /// Returns the declared class of the given name, if there is one.
+    pub fn class(&self, name: &str) -> Option<&ClassDeclaration> {
+        self.classes.get(name)
+    }
+
+    /// Adds a [`Declaration`] of a type definition file to this [`TypeEnvironment`].
+    pub fn declare(&mut self, declaration: Declaration) {
+        match declaration {
+            Declaration::Global(name, annotation) => self.define_global(name, annotation),
+            Declaration::Function(name, function) => self.define_global(name, function),
+            Declaration::Class(class) => self.define_class(*class),
+            Declaration::Type(declaration) => self.define_type(&declaration),
+        }
+    }
+
+    /// Parses a type definition file (`.d.luau`) and adds its declarations to this
+    /// [`TypeEnvironment`].
+    /// ```
+    /// use luna::analysis::{check_in, TypeEnvironment};
+    /// use luna::parser::parse;
+    ///
+    /// let mut environment = TypeEnvironment::new();
+    /// environment
+    ///     .load(
+    ///         "declare class Instance
+    ///             Name: string
+    ///             function Destroy(self): ()
+    ///         end
+    ///         declare class Part extends Instance
+    ///             Transparency: number
+    ///         end
+    ///         declare function spawn(part: Part): Part",
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let chunk = parse(
+    ///     "local part = spawn(spawn(nil))
+    ///     local name: string = part.Name
+    ///     local instance: Instance = part
+    ///     part.Transparency = \"clear\"
+    ///     part:Destroy()
+    ///     part:Remove()",
+    /// )
+    /// .unwrap();
+    ///
+    /// let diagnostics = check_in(&chunk, &environment);
+    /// let messages = diagnostics.iter().map(|diagnostic| diagnostic.message()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     messages,
+    ///     vec![
+    ///         "argument 1 of `spawn`: cannot convert `nil` to `Part`",
+    ///         "cannot assign `string` to `part.Transparency` of type `number`",
+    ///         "property `Remove` does not exist on `part` of type `Part`",
+    ///     ]
+    /// );
+    /// ```
+    pub fn load(&mut self, source: &str) -> Result<(), ParseError> {
+        for declaration in parse_declarations(source)? {
+            self.declare(declaration);
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the class `class` is `ancestor` or extends it, directly or not.
+    pub fn is_subclass(&self, class: &str, ancestor: &str) -> bool {
+        let mut current = Some(class);
+
+        // The depth is bounded by the number of classes, in case they extend each other in a cycle.
+        for _ in 0..=self.classes.len() {
+            match current {
+                Some(name) if name == ancestor => return true,
+                Some(name) => current = self.class(name).and_then(ClassDeclaration::superclass),
+                None => return false,
+            }
+        }
+
+        false
+    }
+
+    /// Returns whether `sub` is a subtype of `sup`, where references to declared classes are
+    /// compared with [`is_subclass`](Self::is_subclass).
+    pub fn is_subtype(&self, sub: &TypeAnnotation, sup: &TypeAnnotation) -> bool {
+        let nominal = |sub: &TypeReference, sup: &TypeReference| {
+            sub.module().is_none()
+                && sup.module().is_none()
+                && self.classes.contains_key(sub.name())
+                && self.is_subclass(sub.name(), sup.name())
+        };
+
+        is_subtype_in(sub, sup, &nominal)
+    }
+
+    pub(crate) fn type_definition(&self, name: &str) -> Option<&TypeDefinition> {
+        self.types.get(name)
+    }
+
+    /// Returns the properties of a class and of the classes it extends, as a table type, where
+    /// the properties of a class take precedence over those it inherits.
+    pub(crate) fn class_type(&self, name: &str) -> Option<TypeTable> {
+        let mut class = self.class(name)?;
+        let mut entries = Vec::<TypeTableEntry>::new();
+        let mut indexer = None;
+
+        for _ in 0..self.classes.len() {
+            for property in class.properties() {
+                if entries
+                    .iter()
+                    .all(|entry| entry.index() != property.index())
+                {
+                    entries.push(property.clone());
+                }
+            }
+
+            indexer = indexer.or_else(|| class.indexer().cloned());
+
+            match class
+                .superclass()
+                .and_then(|superclass| self.class(superclass))
+            {
+                Some(superclass) => class = superclass,
+                None => break,
+            }
+        }
+
+        Some(TypeTable::new(entries, indexer))
+    }
+}
+
+impl From<&TypeDeclaration> for TypeDefinition {
+    fn from(declaration: &TypeDeclaration) -> Self {
+        Self {
+            generics: declaration.generics().clone(),
+            annotation: declaration.annotation().clone(),
+        }
+    }
+}
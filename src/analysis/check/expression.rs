@@ -0,0 +1,845 @@
+use super::{
+    any, describe, describe_expression, Checker, Diagnostic, DiagnosticKind, TypeEnvironment,
+};
+use crate::ast::{
+    BinaryOperation, BinaryOperator, Expression, Function, FunctionCall, Index, IndexKey,
+    TableConstructor, TableField, TypeAnnotation, TypeArgument, TypeArray, TypeBuiltIn,
+    TypeCombination, TypeFunction, TypeOperator, TypePack, TypePackTail, TypePrimitive,
+    TypeSingleton, TypeTable, TypeTableEntry, TypeTableIndexer, UnaryOperation, UnaryOperator,
+};
+use crate::evaluation::{Constant, ConstantValue};
+use crate::parser::Span;
+use crate::types::normalize;
+
+/// The result of looking up a property in a type.
+pub(super) enum Property {
+    Found(TypeAnnotation),
+    /// The type is a table without the property.
+    Missing,
+    /// The type cannot be indexed at all.
+    NotIndexable,
+    /// The type does not tell whether the property exists.
+    Unknown,
+}
+
+impl Checker<'_> {
+    /// Infers the type of an expression, truncated to its first value.
+    pub(super) fn infer(&mut self, expression: &Expression) -> TypeAnnotation {
+        match expression {
+            Expression::NilLiteral(_) => TypePrimitive::Nil.into(),
+            Expression::BooleanLiteral(_) => TypePrimitive::Boolean.into(),
+            Expression::StringLiteral(_) => TypePrimitive::String.into(),
+            Expression::NumberLiteral(_) => TypePrimitive::Number.into(),
+            Expression::InterpolatedString(string) => {
+                for expression in string.expressions() {
+                    self.infer(expression);
+                }
+
+                TypePrimitive::String.into()
+            }
+            Expression::VarargLiteral(_) | Expression::FunctionCall(_) => {
+                first(&self.infer_multiple(expression))
+            }
+
+            Expression::Identifier(identifier) => self.variable(identifier.name()),
+            Expression::Index(index) => self.infer_index(index),
+
+            Expression::Function(function) => {
+                let signature = self.signature(function, false);
+                self.check_function_body(function, &signature);
+
+                signature.into()
+            }
+            Expression::TableConstructor(table) => self.infer_table(table),
+
+            Expression::UnaryOperation(operation) => self.infer_unary(operation),
+            Expression::BinaryOperation(operation) => self.infer_binary(operation),
+
+            Expression::Parenthesized(parenthesized) => self.infer(parenthesized.expression()),
+            Expression::IfExpression(expression) => {
+                self.infer(expression.condition());
+                let mut branches = vec![self.infer(expression.consequent())];
+
+                for (condition, consequent) in expression.else_ifs() {
+                    self.infer(condition);
+                    branches.push(self.infer(consequent));
+                }
+
+                branches.push(self.infer(expression.alternative()));
+                union_of(branches)
+            }
+            Expression::TypeAssertion(assertion) => {
+                let value = self.infer(assertion.expression());
+                let target = self.resolve(assertion.annotation());
+
+                if !self.environment.is_subtype(&value, &target)
+                    && !self.environment.is_subtype(&target, &value)
+                {
+                    self.report(
+                        DiagnosticKind::TypeMismatch,
+                        format!(
+                            "cannot cast `{}` to `{}`",
+                            describe(&value),
+                            describe(&target)
+                        ),
+                    );
+                }
+
+                target
+            }
+        }
+    }
+
+    /// Infers the types of all the values of an expression, which are more than one only for
+    /// calls and varargs.
+    pub(super) fn infer_multiple(&mut self, expression: &Expression) -> TypePack {
+        match expression {
+            Expression::VarargLiteral(_) => match self.functions.last() {
+                Some(function) => function.varargs.clone(),
+                None => TypePack::variadic(any()),
+            },
+            Expression::FunctionCall(call) => self.infer_call(call),
+            _ => TypePack::list(vec![self.infer(expression)]),
+        }
+    }
+
+    /// Infers the types of a list of expressions, where only the last expands to all of its
+    /// values.
+    pub(super) fn infer_list(&mut self, expressions: &[Expression]) -> TypePack {
+        let Some((last, rest)) = expressions.split_last() else {
+            return TypePack::empty();
+        };
+
+        let mut types = rest
+            .iter()
+            .map(|expression| self.infer(expression))
+            .collect::<Vec<_>>();
+
+        let (last, tail) = self.infer_multiple(last).into_parts();
+        types.extend(last);
+
+        TypePack::new(types, tail)
+    }
+
+    /// Returns the type of a local or global variable.
+    pub(super) fn variable(&self, name: &str) -> TypeAnnotation {
+        match self.local(name) {
+            Some(local) => local.annotation.clone(),
+            None => match self.environment.global(name) {
+                Some(annotation) => self.resolve(annotation),
+                None => any(),
+            },
+        }
+    }
+
+    fn infer_index(&mut self, index: &Index) -> TypeAnnotation {
+        let object = self.infer(index.object());
+
+        match index.key() {
+            IndexKey::Field(name) => self.property(&object, index.object(), name),
+            IndexKey::Computed(key) => {
+                let key_type = self.infer(key);
+
+                match string_constant(key) {
+                    Some(name) => self.property(&object, index.object(), &name),
+                    None => element(self.environment, &object, &key_type).unwrap_or_else(any),
+                }
+            }
+        }
+    }
+
+    /// Returns the type of a property read from a value, reporting properties that do not exist.
+    pub(super) fn property(
+        &mut self,
+        object: &TypeAnnotation,
+        expression: &Expression,
+        name: &str,
+    ) -> TypeAnnotation {
+        match self.lookup_property(object, name) {
+            Property::Found(annotation) => annotation,
+            // Properties of unsealed tables may be added anywhere, so reading them is allowed.
+            Property::Missing if self.unsealed_table(expression).is_some() => any(),
+            Property::Missing => {
+                self.report_missing_property(object, expression, name);
+                any()
+            }
+            Property::NotIndexable => {
+                self.report_not_indexable(object, expression);
+                any()
+            }
+            Property::Unknown => any(),
+        }
+    }
+
+    /// Looks up a property in a type, including the properties of declared classes and the
+    /// methods of strings declared by the global `string` table.
+    pub(super) fn lookup_property(&self, object: &TypeAnnotation, name: &str) -> Property {
+        match normalize(object.clone()) {
+            TypeAnnotation::Table(table) => {
+                if let Some(entry) = table.entries().iter().find(|entry| entry.index() == name) {
+                    return Property::Found(entry.value().clone());
+                }
+
+                let string = TypePrimitive::String.into();
+
+                match table.indexer() {
+                    Some(indexer) if self.environment.is_subtype(&string, indexer.index()) => {
+                        Property::Found(indexer.value().clone())
+                    }
+                    _ => Property::Missing,
+                }
+            }
+            TypeAnnotation::Reference(reference) => {
+                let class = match reference.module() {
+                    None => self.environment.class_type(reference.name()),
+                    Some(_) => None,
+                };
+
+                match class.map(|class| self.lookup_property(&class.into(), name)) {
+                    Some(Property::Found(annotation)) => Property::Found(self.resolve(&annotation)),
+                    Some(property) => property,
+                    None => Property::Unknown,
+                }
+            }
+            TypeAnnotation::Array(_) => Property::Missing,
+            // Values that may be `nil` are not reported in non-strict mode.
+            TypeAnnotation::Optional(optional) => self.lookup_property(optional.annotation(), name),
+            TypeAnnotation::Combination(combination)
+                if combination.operator() == TypeOperator::Intersection =>
+            {
+                let mut missing = true;
+
+                for member in members(&combination.into()) {
+                    match self.lookup_property(&member, name) {
+                        Property::Found(annotation) => return Property::Found(annotation),
+                        Property::Missing => {}
+                        _ => missing = false,
+                    }
+                }
+
+                match missing {
+                    true => Property::Missing,
+                    false => Property::Unknown,
+                }
+            }
+            TypeAnnotation::Primitive(TypePrimitive::String)
+            | TypeAnnotation::Singleton(TypeSingleton::String(_)) => {
+                let library = self
+                    .environment
+                    .global("string")
+                    .map(|library| self.resolve(library));
+
+                match library.map(|library| self.lookup_property(&library, name)) {
+                    Some(Property::Found(annotation)) => Property::Found(annotation),
+                    _ => Property::Unknown,
+                }
+            }
+            TypeAnnotation::Primitive(_)
+            | TypeAnnotation::Singleton(_)
+            | TypeAnnotation::Function(_) => Property::NotIndexable,
+            _ => Property::Unknown,
+        }
+    }
+
+    pub(super) fn report_missing_property(
+        &mut self,
+        object: &TypeAnnotation,
+        expression: &Expression,
+        name: &str,
+    ) {
+        self.report(
+            DiagnosticKind::UnknownProperty,
+            format!(
+                "property `{}` does not exist on `{}` of type `{}`",
+                name,
+                describe_expression(expression),
+                describe(object)
+            ),
+        );
+    }
+
+    pub(super) fn report_not_indexable(
+        &mut self,
+        object: &TypeAnnotation,
+        expression: &Expression,
+    ) {
+        self.report(
+            DiagnosticKind::InvalidOperation,
+            format!(
+                "cannot index `{}` of type `{}`",
+                describe_expression(expression),
+                describe(object)
+            ),
+        );
+    }
+
+    fn infer_table(&mut self, table: &TableConstructor) -> TypeAnnotation {
+        let mut entries = Vec::<TypeTableEntry>::new();
+        let mut keys = Vec::new();
+        let mut values = Vec::new();
+        let mut items = Vec::new();
+
+        let mut add_entry = |name: String, value: TypeAnnotation| {
+            entries.retain(|entry| entry.index() != name);
+            entries.push(TypeTableEntry::new(name, value));
+        };
+
+        for (index, field) in table.fields().iter().enumerate() {
+            match field {
+                TableField::Named(name, value) => add_entry(name.clone(), self.infer(value)),
+                TableField::Keyed(key, value) => {
+                    let key_type = self.infer(key);
+                    let value_type = self.infer(value);
+
+                    match string_constant(key) {
+                        Some(name) => add_entry(name, value_type),
+                        None => {
+                            keys.push(key_type);
+                            values.push(value_type);
+                        }
+                    }
+                }
+                TableField::Positional(value) if index + 1 == table.fields().len() => {
+                    let (types, tail) = self.infer_multiple(value).into_parts();
+                    items.extend(types);
+
+                    if let Some(tail) = tail {
+                        items.push(tail_type(&tail));
+                    }
+                }
+                TableField::Positional(value) => items.push(self.infer(value)),
+            }
+        }
+
+        if entries.is_empty() && keys.is_empty() && !items.is_empty() {
+            return TypeArray::new(Some(Box::new(union_of(items)))).into();
+        }
+
+        if !items.is_empty() {
+            keys.push(TypePrimitive::Number.into());
+            values.push(union_of(items));
+        }
+
+        let indexer = match keys.is_empty() {
+            true => None,
+            false => Some(TypeTableIndexer::new(union_of(keys), union_of(values))),
+        };
+
+        TypeTable::new(entries, indexer).into()
+    }
+
+    fn infer_unary(&mut self, operation: &UnaryOperation) -> TypeAnnotation {
+        let operand = self.infer(operation.operand());
+
+        match operation.operator() {
+            UnaryOperator::Not => TypePrimitive::Boolean.into(),
+            UnaryOperator::Negate => {
+                self.expect_operand(
+                    &operand,
+                    operation.operand(),
+                    &[TypePrimitive::Number, TypePrimitive::String],
+                    "perform arithmetic on",
+                );
+                arithmetic_result(self.environment, &operand, &operand)
+            }
+            UnaryOperator::Length => {
+                self.expect_operand(
+                    &operand,
+                    operation.operand(),
+                    &[TypePrimitive::String],
+                    "get the length of",
+                );
+                TypePrimitive::Number.into()
+            }
+        }
+    }
+
+    fn infer_binary(&mut self, operation: &BinaryOperation) -> TypeAnnotation {
+        let left = self.infer(operation.left());
+        let right = self.infer(operation.right());
+
+        match operation.operator() {
+            BinaryOperator::And => {
+                let falsy = falsy_members(&left);
+
+                match falsy.is_empty() {
+                    true => right,
+                    false => union_of(falsy.into_iter().chain(Some(right)).collect()),
+                }
+            }
+            BinaryOperator::Or => {
+                if falsy_members(&left).is_empty() {
+                    return left;
+                }
+
+                union_of(
+                    truthy_members(&left)
+                        .into_iter()
+                        .chain(Some(right))
+                        .collect(),
+                )
+            }
+            operator => self.operate(operator, &left, &right, operation.left(), operation.right()),
+        }
+    }
+
+    /// Returns the type of applying a binary operator other than `and` and `or`, reporting
+    /// operands it is not defined for.
+    pub(super) fn operate(
+        &mut self,
+        operator: BinaryOperator,
+        left: &TypeAnnotation,
+        right: &TypeAnnotation,
+        left_expression: &Expression,
+        right_expression: &Expression,
+    ) -> TypeAnnotation {
+        match operator {
+            BinaryOperator::Equal | BinaryOperator::NotEqual => TypePrimitive::Boolean.into(),
+            BinaryOperator::LessThan
+            | BinaryOperator::LessThanOrEqual
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::GreaterThanOrEqual => {
+                let comparable = [TypePrimitive::Number, TypePrimitive::String];
+                let valid = self.expect_operand(left, left_expression, &comparable, "compare")
+                    & self.expect_operand(right, right_expression, &comparable, "compare");
+
+                if let (true, Some(l), Some(r)) = (valid, primitive(left), primitive(right)) {
+                    if l != r {
+                        self.report(
+                            DiagnosticKind::InvalidOperation,
+                            format!("cannot compare `{}` with `{}`", l.text(), r.text()),
+                        );
+                    }
+                }
+
+                TypePrimitive::Boolean.into()
+            }
+            BinaryOperator::Concat => {
+                let concatenable = [TypePrimitive::String, TypePrimitive::Number];
+                self.expect_operand(left, left_expression, &concatenable, "concatenate");
+                self.expect_operand(right, right_expression, &concatenable, "concatenate");
+
+                TypePrimitive::String.into()
+            }
+            _ => {
+                let numbers = [TypePrimitive::Number, TypePrimitive::String];
+                self.expect_operand(left, left_expression, &numbers, "perform arithmetic on");
+                self.expect_operand(right, right_expression, &numbers, "perform arithmetic on");
+
+                arithmetic_result(self.environment, left, right)
+            }
+        }
+    }
+
+    /// Reports an operand whose type is known not to support an operation, unless it is one of
+    /// the allowed primitives. Tables are never reported, since metatables can define operators.
+    fn expect_operand(
+        &mut self,
+        operand: &TypeAnnotation,
+        expression: &Expression,
+        allowed: &[TypePrimitive],
+        action: &str,
+    ) -> bool {
+        let supported = match normalize(operand.clone()) {
+            TypeAnnotation::Function(_) => false,
+            operand => primitive(&operand).is_none_or(|primitive| allowed.contains(&primitive)),
+        };
+
+        if !supported {
+            self.report(
+                DiagnosticKind::InvalidOperation,
+                format!(
+                    "cannot {} `{}` of type `{}`",
+                    action,
+                    describe_expression(expression),
+                    describe(operand)
+                ),
+            );
+        }
+
+        supported
+    }
+
+    pub(super) fn infer_call(&mut self, call: &FunctionCall) -> TypePack {
+        let object = self.infer(call.function());
+
+        let (function, mut arguments, name) = match call.method_name() {
+            Some(method) => (
+                self.property(&object, call.function(), method),
+                vec![object],
+                format!("{}:{}", describe_expression(call.function()), method),
+            ),
+            None => (object, Vec::new(), describe_expression(call.function())),
+        };
+
+        let implicit = arguments.len();
+        let (types, tail) = self.infer_list(call.arguments()).into_parts();
+        arguments.extend(types);
+
+        self.apply(&function, &TypePack::new(arguments, tail), &name, implicit)
+    }
+
+    /// Returns the results of calling a value with arguments of the given types, reporting
+    /// arguments the function does not accept. The first `implicit` arguments are not written in
+    /// the call, like the `self` of a method call, and are not counted in messages.
+    fn apply(
+        &mut self,
+        function: &TypeAnnotation,
+        arguments: &TypePack,
+        name: &str,
+        implicit: usize,
+    ) -> TypePack {
+        match normalize(function.clone()) {
+            TypeAnnotation::Function(function) => {
+                let diagnostics = check_arguments(
+                    self.environment,
+                    &self.span,
+                    &function,
+                    arguments,
+                    name,
+                    implicit,
+                );
+                self.diagnostics.extend(diagnostics);
+
+                function.result().clone()
+            }
+            TypeAnnotation::Combination(combination)
+                if combination.operator() == TypeOperator::Intersection =>
+            {
+                let overloads = members(&combination.into())
+                    .into_iter()
+                    .map(|member| match member {
+                        TypeAnnotation::Function(function) => Some(function),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                let Some(overloads) = overloads else {
+                    return TypePack::variadic(any());
+                };
+
+                let matching = overloads.iter().find(|overload| {
+                    check_arguments(
+                        self.environment,
+                        &self.span,
+                        overload,
+                        arguments,
+                        name,
+                        implicit,
+                    )
+                    .is_empty()
+                });
+
+                match matching {
+                    Some(overload) => overload.result().clone(),
+                    None => {
+                        let given = arguments.types()[implicit..]
+                            .iter()
+                            .map(describe)
+                            .collect::<Vec<_>>();
+
+                        self.report(
+                            DiagnosticKind::TypeMismatch,
+                            format!(
+                                "no overload of `{}` accepts arguments of types `({})`",
+                                name,
+                                given.join(", ")
+                            ),
+                        );
+
+                        TypePack::variadic(any())
+                    }
+                }
+            }
+            TypeAnnotation::Primitive(_) | TypeAnnotation::Singleton(_) => {
+                self.report(
+                    DiagnosticKind::NotCallable,
+                    format!("cannot call `{}` of type `{}`", name, describe(function)),
+                );
+
+                TypePack::variadic(any())
+            }
+            _ => TypePack::variadic(any()),
+        }
+    }
+
+    /// Returns the type of a function from its annotations, where parameters and results that
+    /// are not annotated have the type `any`. Methods take an implicit `self` parameter first.
+    pub(super) fn signature(&self, function: &Function, method: bool) -> TypeFunction {
+        let mut arguments = Vec::new();
+
+        if method {
+            arguments.push(TypeArgument::named(String::from("self"), any()));
+        }
+
+        arguments.extend(function.parameters().iter().map(|parameter| {
+            let annotation = match &parameter.annotation {
+                Some(annotation) => self.resolve(annotation),
+                None => any(),
+            };
+
+            TypeArgument::named(parameter.name.clone(), annotation)
+        }));
+
+        let variadic = function
+            .variadic()
+            .map(|variadic| match variadic.annotation() {
+                Some(tail) => self.resolve_tail(tail),
+                None => TypePackTail::variadic(any()),
+            });
+
+        let result = match function.returns() {
+            Some(returns) => self.resolve_pack(returns),
+            None => TypePack::variadic(any()),
+        };
+
+        TypeFunction::new(Vec::new(), arguments, variadic, result)
+    }
+}
+
+/// Checks the arguments of a call against the parameters of a function, reporting problems at the
+/// given [`Span`].
+fn check_arguments(
+    environment: &TypeEnvironment,
+    span: &Span,
+    function: &TypeFunction,
+    arguments: &TypePack,
+    name: &str,
+    implicit: usize,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let parameters = function.arguments();
+    let given = arguments.types();
+
+    let mut mismatch = |position: usize, argument: &TypeAnnotation, parameter: &TypeAnnotation| {
+        if !environment.is_subtype(argument, parameter) {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticKind::TypeMismatch,
+                format!(
+                    "argument {} of `{}`: cannot convert `{}` to `{}`",
+                    position + 1 - implicit,
+                    name,
+                    describe(argument),
+                    describe(parameter)
+                ),
+                span.clone(),
+            ));
+        }
+    };
+
+    for (position, parameter) in parameters.iter().enumerate() {
+        match given.get(position) {
+            Some(argument) => mismatch(position, argument, parameter.val()),
+            None => break,
+        }
+    }
+
+    for (position, argument) in given.iter().enumerate().skip(parameters.len()) {
+        if let Some(TypePackTail::Variadic(parameter)) = function.variadic() {
+            mismatch(position, argument, parameter);
+        }
+    }
+
+    // Parameters accepting `nil` can be omitted.
+    let nil = TypePrimitive::Nil.into();
+    let required = parameters
+        .iter()
+        .rposition(|parameter| !environment.is_subtype(&nil, parameter.val()))
+        .map_or(0, |position| position + 1)
+        .max(implicit);
+
+    let count = |count: usize| {
+        let count = count.saturating_sub(implicit);
+        format!("{} argument{}", count, if count == 1 { "" } else { "s" })
+    };
+
+    if given.len() < required && arguments.pack_tail().is_none() {
+        let expected = match required == parameters.len() && function.variadic().is_none() {
+            true => count(required),
+            false => format!("at least {}", count(required)),
+        };
+
+        diagnostics.push(Diagnostic::new(
+            DiagnosticKind::ArgumentCount,
+            format!(
+                "`{}` expects {}, but {} given",
+                name,
+                expected,
+                given_count(given.len() - implicit)
+            ),
+            span.clone(),
+        ));
+    } else if given.len() > parameters.len() && function.variadic().is_none() {
+        let expected = match required == parameters.len() {
+            true => count(parameters.len()),
+            false => format!("at most {}", count(parameters.len())),
+        };
+
+        diagnostics.push(Diagnostic::new(
+            DiagnosticKind::ArgumentCount,
+            format!(
+                "`{}` expects {}, but {} given",
+                name,
+                expected,
+                given_count(given.len() - implicit)
+            ),
+            span.clone(),
+        ));
+    }
+
+    diagnostics
+}
+
+fn given_count(count: usize) -> String {
+    format!("{} {}", count, if count == 1 { "is" } else { "are" })
+}
+
+/// Returns the type of an element read from a value with a computed key, if the type of the
+/// value declares it.
+pub(super) fn element(
+    environment: &TypeEnvironment,
+    object: &TypeAnnotation,
+    key: &TypeAnnotation,
+) -> Option<TypeAnnotation> {
+    let table = match normalize(object.clone()) {
+        TypeAnnotation::Array(array) => {
+            return environment
+                .is_subtype(key, &TypePrimitive::Number.into())
+                .then(|| array.item().as_deref().cloned().unwrap_or_else(any));
+        }
+        TypeAnnotation::Table(table) => table,
+        TypeAnnotation::Reference(reference) if reference.module().is_none() => {
+            environment.class_type(reference.name())?
+        }
+        _ => return None,
+    };
+
+    table
+        .indexer()
+        .as_ref()
+        .filter(|indexer| environment.is_subtype(key, indexer.index()))
+        .map(|indexer| indexer.value().clone())
+}
+
+/// Returns the string a key expression always evaluates to, if it is constant.
+pub(super) fn string_constant(expression: &Expression) -> Option<String> {
+    match expression.constant() {
+        Some(ConstantValue::String(bytes)) => String::from_utf8(bytes).ok(),
+        _ => None,
+    }
+}
+
+/// Returns the type of the value at a position of a pack, if it has one.
+pub(super) fn pack_value(pack: &TypePack, position: usize) -> Option<TypeAnnotation> {
+    match pack.types().get(position) {
+        Some(annotation) => Some(annotation.clone()),
+        None => pack.pack_tail().map(tail_type),
+    }
+}
+
+/// Returns the type of the first value of a pack, which is `nil` for an empty pack.
+pub(super) fn first(pack: &TypePack) -> TypeAnnotation {
+    pack_value(pack, 0).unwrap_or_else(|| TypePrimitive::Nil.into())
+}
+
+fn tail_type(tail: &TypePackTail) -> TypeAnnotation {
+    match tail {
+        TypePackTail::Variadic(annotation) => annotation.as_ref().clone(),
+        TypePackTail::Generic(_) => any(),
+    }
+}
+
+/// Returns the normalized union of the given types.
+pub(super) fn union_of(annotations: Vec<TypeAnnotation>) -> TypeAnnotation {
+    let union = annotations
+        .into_iter()
+        .reduce(|left, right| TypeCombination::union(left, right).into());
+
+    normalize(union.unwrap_or_else(|| TypeBuiltIn::Never.into()))
+}
+
+/// Returns the members of a normalized union or intersection, with the `nil` of optionals.
+fn members(annotation: &TypeAnnotation) -> Vec<TypeAnnotation> {
+    match annotation {
+        TypeAnnotation::Combination(combination) => {
+            let mut members = members_of(combination.left(), combination.operator());
+            members.extend(members_of(combination.right(), combination.operator()));
+            members
+        }
+        TypeAnnotation::Optional(optional) => {
+            let mut members = members_of(optional.annotation(), TypeOperator::Union);
+            members.push(TypePrimitive::Nil.into());
+            members
+        }
+        annotation => vec![annotation.clone()],
+    }
+}
+
+fn members_of(annotation: &TypeAnnotation, operator: TypeOperator) -> Vec<TypeAnnotation> {
+    match annotation {
+        TypeAnnotation::Combination(combination) if combination.operator() == operator => {
+            members(annotation)
+        }
+        TypeAnnotation::Optional(_) if operator == TypeOperator::Union => members(annotation),
+        annotation => vec![annotation.clone()],
+    }
+}
+
+/// Returns the members of a type that a truthy value can have, so that `x or y` has them.
+fn truthy_members(annotation: &TypeAnnotation) -> Vec<TypeAnnotation> {
+    members(&normalize(annotation.clone()))
+        .into_iter()
+        .filter_map(|member| match member {
+            TypeAnnotation::Primitive(TypePrimitive::Nil)
+            | TypeAnnotation::Singleton(TypeSingleton::Boolean(false)) => None,
+            TypeAnnotation::Primitive(TypePrimitive::Boolean) => {
+                Some(TypeSingleton::Boolean(true).into())
+            }
+            member => Some(member),
+        })
+        .collect()
+}
+
+/// Returns the members of a type that a falsy value can have, so that `x and y` has them.
+fn falsy_members(annotation: &TypeAnnotation) -> Vec<TypeAnnotation> {
+    members(&normalize(annotation.clone()))
+        .into_iter()
+        .filter_map(|member| match member {
+            TypeAnnotation::Primitive(TypePrimitive::Nil)
+            | TypeAnnotation::Singleton(TypeSingleton::Boolean(false))
+            | TypeAnnotation::BuiltIn(TypeBuiltIn::Any | TypeBuiltIn::Unknown) => Some(member),
+            TypeAnnotation::Primitive(TypePrimitive::Boolean) => {
+                Some(TypeSingleton::Boolean(false).into())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the primitive a normalized type is, or the primitive of a singleton.
+fn primitive(annotation: &TypeAnnotation) -> Option<TypePrimitive> {
+    match annotation {
+        TypeAnnotation::Primitive(primitive) => Some(*primitive),
+        TypeAnnotation::Singleton(TypeSingleton::String(_)) => Some(TypePrimitive::String),
+        TypeAnnotation::Singleton(TypeSingleton::Boolean(_)) => Some(TypePrimitive::Boolean),
+        _ => None,
+    }
+}
+
+/// Returns the type of an arithmetic operation, which is a number unless an operand may be a
+/// table with metamethods. Strings are converted to numbers, as they are at runtime.
+fn arithmetic_result(
+    environment: &TypeEnvironment,
+    left: &TypeAnnotation,
+    right: &TypeAnnotation,
+) -> TypeAnnotation {
+    let numeric = union_of(vec![
+        TypePrimitive::Number.into(),
+        TypePrimitive::String.into(),
+    ]);
+
+    match environment.is_subtype(left, &numeric) && environment.is_subtype(right, &numeric) {
+        true => TypePrimitive::Number.into(),
+        false => any(),
+    }
+}
@@ -0,0 +1,299 @@
+//! Type inference and checking of a chunk, following the non-strict semantics of Luau.
+//!
+//! [`check`] infers the types of locals and expressions from literals, operators, table
+//! constructors and annotated function signatures, and reports a [`Diagnostic`] wherever a value
+//! cannot have the type it is used as, with the [`Span`] of the statement it is found in.
+//! Whatever cannot be inferred has the type `any`, which is compatible with every other type, and
+//! tables inferred for locals without annotations are unsealed, so unannotated code produces no
+//! false positives.
+//! ```
+//! use luna::analysis::{check, DiagnosticKind};
+//! use luna::parser::parse;
+//!
+//! let chunk = parse(
+//!     "local x: number = \"a\"
+//!     local function add(a: number, b: number): number
+//!         return a + b
+//!     end
+//!     local sum = add(1)
+//!     local text: string = add(1, 2)",
+//! )
+//! .unwrap();
+//!
+//! let diagnostics = check(&chunk);
+//! let kinds = diagnostics.iter().map(|diagnostic| diagnostic.kind()).collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     kinds,
+//!     vec![DiagnosticKind::TypeMismatch, DiagnosticKind::ArgumentCount, DiagnosticKind::TypeMismatch]
+//! );
+//! assert_eq!(
+//!     diagnostics[0].message(),
+//!     "cannot assign `string` to local `x` of type `number`"
+//! );
+//! assert_eq!(diagnostics[2].span(), &(131..161));
+//!
+//! let chunk = parse(
+//!     "local config = { debug = false, name = \"a\" }
+//!     config.name = nil
+//!     config.debug = \"yes\"
+//!     if config.verbose then end
+//!     local n = \"10\" + 1
+//!     local scores: {[string]: number} = { alice = 1 }
+//!     local names = { \"a\" }
+//!     names[2] = 1",
+//! )
+//! .unwrap();
+//!
+//! assert!(check(&chunk).is_empty());
+//!
+//! let chunk = parse(
+//!     "local t = {}
+//!     t[(function() local a: number = \"s\" end)()] = (function() local c: number = \"u\" end)()",
+//! )
+//! .unwrap();
+//!
+//! let messages = check(&chunk)
+//!     .iter()
+//!     .map(|diagnostic| diagnostic.message().to_owned())
+//!     .collect::<Vec<_>>();
+//!
+//! assert_eq!(
+//!     messages,
+//!     vec![
+//!         "cannot assign `string` to local `a` of type `number`",
+//!         "cannot assign `string` to local `c` of type `number`"
+//!     ]
+//! );
+//! ```
+mod environment;
+mod expression;
+mod resolve;
+mod statement;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::mem;
+
+use environment::TypeDefinition;
+pub use environment::TypeEnvironment;
+
+use crate::ast::{Block, Expression, TypeAnnotation, TypeBuiltIn, TypePack};
+use crate::format::{SourceFormat, SourceFormatSettings};
+use crate::parser::{Chunk, Span};
+use crate::types::normalize;
+
+/// The kind of problem a [`Diagnostic`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticKind {
+    /// A value cannot be converted to the type it is assigned, passed or returned as.
+    TypeMismatch,
+    /// A function is called with more or fewer arguments than it accepts.
+    ArgumentCount,
+    /// A function returns more or fewer values than its signature declares.
+    ReturnCount,
+    /// A value that is not a function is called.
+    NotCallable,
+    /// A table is indexed with a property its type does not have.
+    UnknownProperty,
+    /// An operator is applied to values it is not defined for.
+    InvalidOperation,
+}
+
+/// A problem found by [`check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    kind: DiagnosticKind,
+    message: String,
+    span: Span,
+}
+
+impl Diagnostic {
+    /// Constructs a new [`Diagnostic`] of the given [`DiagnosticKind`] with the given message, at
+    /// the given [`Span`].
+    pub fn new<T: Into<String>>(kind: DiagnosticKind, message: T, span: Span) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            span,
+        }
+    }
+
+    /// Returns the [`DiagnosticKind`] of this [`Diagnostic`].
+    pub fn kind(&self) -> DiagnosticKind {
+        self.kind
+    }
+
+    /// Returns the message of this [`Diagnostic`].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the [`Span`] of the statement this [`Diagnostic`] is found in.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+/// Checks a chunk with no global types declared, returning the [`Diagnostic`]s in the order of
+/// the code they are found in.
+pub fn check(chunk: &Chunk) -> Vec<Diagnostic> {
+    check_in(chunk, &TypeEnvironment::new())
+}
+
+/// Checks a chunk against the global values and types declared in a [`TypeEnvironment`],
+/// returning the [`Diagnostic`]s in the order of the code they are found in.
+pub fn check_in(chunk: &Chunk, environment: &TypeEnvironment) -> Vec<Diagnostic> {
+    let mut checker = Checker::new(environment);
+    checker.check_chunk(chunk.block());
+
+    // Values are checked before the targets they are assigned to, which may come first.
+    let mut diagnostics = checker.diagnostics;
+    diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+    diagnostics
+}
+
+/// A local variable in scope, with the type it was declared with or inferred from its value.
+#[derive(Debug, Clone)]
+struct Local {
+    annotation: TypeAnnotation,
+    /// The annotation the local is declared with, as written, which every assignment must
+    /// respect.
+    declared: Option<TypeAnnotation>,
+}
+
+#[derive(Debug, Default)]
+struct Scope {
+    locals: HashMap<String, Local>,
+    types: HashMap<String, TypeDefinition>,
+}
+
+/// The function whose body is being checked.
+#[derive(Debug)]
+struct FunctionContext {
+    /// The declared results, if the function is annotated with them.
+    returns: Option<TypePack>,
+    varargs: TypePack,
+}
+
+struct Checker<'e> {
+    environment: &'e TypeEnvironment,
+    scopes: Vec<Scope>,
+    functions: Vec<FunctionContext>,
+    diagnostics: Vec<Diagnostic>,
+    /// The span of the statement being checked.
+    span: Span,
+}
+
+impl<'e> Checker<'e> {
+    fn new(environment: &'e TypeEnvironment) -> Self {
+        Self {
+            environment,
+            scopes: Vec::new(),
+            functions: Vec::new(),
+            diagnostics: Vec::new(),
+            span: 0..0,
+        }
+    }
+
+    fn check_chunk(&mut self, block: &Block) {
+        self.functions.push(FunctionContext {
+            returns: None,
+            varargs: TypePack::variadic(any()),
+        });
+        self.check_block(block);
+        self.functions.pop();
+    }
+
+    fn report<T: Into<String>>(&mut self, kind: DiagnosticKind, message: T) {
+        self.diagnostics
+            .push(Diagnostic::new(kind, message, self.span.clone()));
+    }
+
+    /// Enters a statement with the given span, making it the one diagnostics are reported at, and
+    /// returns the span of the statement it is nested in. Statements without a span are reported
+    /// at the statement they are nested in.
+    fn enter_statement(&mut self, span: Option<&Span>) -> Span {
+        let span = span.unwrap_or(&self.span).clone();
+        mem::replace(&mut self.span, span)
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(Scope::default());
+    }
+
+    fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn declare_local<T: Into<String>>(
+        &mut self,
+        name: T,
+        annotation: TypeAnnotation,
+        declared: Option<TypeAnnotation>,
+    ) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.locals.insert(
+                name.into(),
+                Local {
+                    annotation,
+                    declared,
+                },
+            );
+        }
+    }
+
+    fn local(&self, name: &str) -> Option<&Local> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.locals.get(name))
+    }
+
+    fn local_mut(&mut self, name: &str) -> Option<&mut Local> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.locals.get_mut(name))
+    }
+
+    fn type_definition(&self, name: &str) -> Option<&TypeDefinition> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.types.get(name))
+            .or_else(|| self.environment.type_definition(name))
+    }
+}
+
+fn any() -> TypeAnnotation {
+    TypeBuiltIn::Any.into()
+}
+
+/// Formats a type for a message, in its normalized form.
+fn describe(annotation: &TypeAnnotation) -> String {
+    normalize(annotation.clone()).format_string(&message_settings())
+}
+
+/// Formats an expression for a message.
+fn describe_expression(expression: &Expression) -> String {
+    expression.format_string(&message_settings())
+}
+
+/// The settings types and expressions are formatted with in messages, which keep them on one line.
+fn message_settings() -> SourceFormatSettings {
+    SourceFormatSettings {
+        max_line_width: usize::MAX,
+        ..Default::default()
+    }
+}
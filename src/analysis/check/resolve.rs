@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use super::{any, Checker};
+use crate::ast::{
+    Expression, TypeAnnotation, TypeFunction, TypeGenericParameter, TypePack, TypePackTail,
+    TypeReference, TypeReferenceArgument,
+};
+use crate::fold::{fold_type_function, fold_type_pack_tail, fold_type_reference, Fold};
+
+/// How many nested aliases are expanded before a reference resolves to `any`, which bounds the
+/// expansion of recursive aliases.
+const MAX_EXPANSION_DEPTH: usize = 6;
+
+impl<'e> Checker<'e> {
+    /// Expands the type aliases referenced by an annotation, so that it can be compared
+    /// structurally.
+    ///
+    /// References to declared classes are kept, while generic parameters, `typeof` and references
+    /// to undeclared types resolve to `any`.
+    pub(super) fn resolve(&self, annotation: &TypeAnnotation) -> TypeAnnotation {
+        self.resolver().fold_type_annotation(annotation.clone())
+    }
+
+    /// Expands the type aliases referenced by the types of a pack.
+    pub(super) fn resolve_pack(&self, pack: &TypePack) -> TypePack {
+        self.resolver().fold_type_pack(pack.clone())
+    }
+
+    /// Expands the type aliases referenced by the tail of a pack.
+    pub(super) fn resolve_tail(&self, tail: &TypePackTail) -> TypePackTail {
+        self.resolver().fold_type_pack_tail(tail.clone())
+    }
+
+    fn resolver(&self) -> Resolver<'_, 'e> {
+        Resolver {
+            checker: self,
+            bound: Vec::new(),
+            depth: 0,
+        }
+    }
+}
+
+struct Resolver<'c, 'e> {
+    checker: &'c Checker<'e>,
+    /// The generic parameters of the functions being resolved.
+    bound: Vec<String>,
+    depth: usize,
+}
+
+impl Fold for Resolver<'_, '_> {
+    fn fold_type_reference(&mut self, annotation: TypeReference) -> TypeAnnotation {
+        if annotation.module().is_some() || self.bound.iter().any(|name| name == annotation.name())
+        {
+            return any();
+        }
+
+        let checker = self.checker;
+        let definition = match checker.type_definition(annotation.name()) {
+            Some(definition) if self.depth < MAX_EXPANSION_DEPTH => definition,
+            Some(_) => return any(),
+            // Classes are compared by name, so references to them are kept.
+            None if checker.environment.class(annotation.name()).is_some() => {
+                return annotation.into();
+            }
+            None => return any(),
+        };
+
+        let mut arguments = fold_type_reference(self, annotation)
+            .into_parts()
+            .2
+            .into_iter()
+            .filter_map(|argument| match argument {
+                TypeReferenceArgument::Type(annotation) => Some(annotation),
+                TypeReferenceArgument::Pack(_) => None,
+            });
+
+        let substitutions = definition
+            .generics
+            .iter()
+            .filter_map(|generic| match generic {
+                TypeGenericParameter::Type(generic) => Some(generic),
+                TypeGenericParameter::Pack(_) => None,
+            })
+            .map(|generic| {
+                let argument = arguments
+                    .next()
+                    .or_else(|| generic.default().cloned())
+                    .unwrap_or_else(any);
+
+                (generic.name().to_owned(), argument)
+            })
+            .collect();
+
+        let expanded =
+            Substitution(substitutions).fold_type_annotation(definition.annotation.clone());
+
+        self.depth += 1;
+        let resolved = self.fold_type_annotation(expanded);
+        self.depth -= 1;
+
+        resolved
+    }
+
+    fn fold_type_function(&mut self, annotation: TypeFunction) -> TypeAnnotation {
+        let generics = annotation.generics().len();
+        self.bound.extend(
+            annotation
+                .generics()
+                .iter()
+                .map(|generic| generic.name().to_owned()),
+        );
+
+        let (_, arguments, variadic, result) = fold_type_function(self, annotation).into_parts();
+        self.bound.truncate(self.bound.len() - generics);
+
+        // The generics were resolved to `any`, so the function is no longer generic.
+        TypeFunction::new(Vec::new(), arguments, variadic, result).into()
+    }
+
+    fn fold_type_pack_tail(&mut self, tail: TypePackTail) -> TypePackTail {
+        match tail {
+            TypePackTail::Generic(_) => TypePackTail::variadic(any()),
+            tail => fold_type_pack_tail(self, tail),
+        }
+    }
+
+    fn fold_type_typeof(&mut self, _: Expression) -> TypeAnnotation {
+        any()
+    }
+}
+
+/// Replaces the generic parameters of an alias with the arguments of a reference to it.
+struct Substitution(HashMap<String, TypeAnnotation>);
+
+impl Fold for Substitution {
+    fn fold_type_reference(&mut self, annotation: TypeReference) -> TypeAnnotation {
+        match self.0.get(annotation.name()) {
+            Some(argument)
+                if annotation.module().is_none() && annotation.arguments().is_empty() =>
+            {
+                argument.clone()
+            }
+            _ => fold_type_reference(self, annotation).into(),
+        }
+    }
+}
@@ -0,0 +1,600 @@
+use super::environment::TypeDefinition;
+use super::expression::{element, pack_value, string_constant, union_of, Property};
+use super::{any, describe, describe_expression, Checker, DiagnosticKind, FunctionContext};
+use crate::ast::{
+    Block, Expression, Function, Identifier, Index, IndexKey, Statement, Trivia, TypeAnnotation,
+    TypeFunction, TypePack, TypePackTail, TypePrimitive, TypeTable, TypeTableEntry,
+};
+use crate::parser::Span;
+
+impl Checker<'_> {
+    /// Checks the statements of a block in a new scope.
+    pub(super) fn check_block(&mut self, block: &Block) {
+        self.push_scope();
+        self.check_statements(block);
+        self.pop_scope();
+    }
+
+    /// Checks the statements of a block in the current scope.
+    fn check_statements(&mut self, block: &Block) {
+        // Type aliases can be used anywhere in the block declaring them.
+        for statement in block.statements() {
+            if let (Statement::TypeDeclaration(declaration), Some(scope)) =
+                (statement, self.scopes.last_mut())
+            {
+                scope.types.insert(
+                    declaration.name().to_owned(),
+                    TypeDefinition::from(declaration),
+                );
+            }
+        }
+
+        for (index, statement) in block.statements().iter().enumerate() {
+            let span = block.trivia().get(index).and_then(Trivia::span);
+            self.check_statement(statement, span);
+        }
+    }
+
+    fn check_statement(&mut self, statement: &Statement, span: Option<&Span>) {
+        let outer = self.enter_statement(span);
+        self.check_statement_kind(statement);
+        self.span = outer;
+    }
+
+    fn check_statement_kind(&mut self, statement: &Statement) {
+        match statement {
+            Statement::LocalAssign(assign) => {
+                let values = self.infer_list(assign.values());
+
+                for (position, binding) in assign.bindings().iter().enumerate() {
+                    let value = pack_value(&values, position);
+
+                    match &binding.annotation {
+                        Some(written) => {
+                            let annotation = self.resolve(written);
+
+                            if let Some(value) = value
+                                .filter(|value| !self.environment.is_subtype(value, &annotation))
+                            {
+                                self.report(
+                                    DiagnosticKind::TypeMismatch,
+                                    format!(
+                                        "cannot assign `{}` to local `{}` of type `{}`",
+                                        describe(&value),
+                                        binding.name,
+                                        describe(written)
+                                    ),
+                                );
+                            }
+
+                            self.declare_local(&binding.name, annotation, Some(written.clone()));
+                        }
+                        None => {
+                            // A local starting as `nil` is usually assigned later, so its type is
+                            // not inferred from its initial value.
+                            let nil = TypePrimitive::Nil.into();
+                            let value = value.filter(|value| *value != nil).unwrap_or_else(any);
+
+                            self.declare_local(&binding.name, value, None);
+                        }
+                    }
+                }
+            }
+            Statement::Assign(assign) => {
+                let values = self.infer_list(assign.values());
+
+                for (position, target) in assign.targets().iter().enumerate() {
+                    let value =
+                        pack_value(&values, position).unwrap_or_else(|| TypePrimitive::Nil.into());
+
+                    self.assign(target, value);
+                }
+            }
+            Statement::CompoundAssign(assign) => {
+                let current = self.infer(assign.target());
+                let value = self.infer(assign.value());
+                let result = self.operate(
+                    assign.operator().binary_operator(),
+                    &current,
+                    &value,
+                    assign.target(),
+                    assign.value(),
+                );
+
+                self.assign(assign.target(), result);
+            }
+
+            Statement::FunctionCall(call) => {
+                self.infer_call(call);
+            }
+
+            Statement::Do(block) => self.check_block(block.block()),
+            Statement::While(loop_) => {
+                self.infer(loop_.condition());
+                self.check_block(loop_.block());
+            }
+            Statement::Repeat(loop_) => {
+                // The condition can refer to the locals of the block.
+                self.push_scope();
+                self.check_statements(loop_.block());
+                self.infer(loop_.condition());
+                self.pop_scope();
+            }
+            Statement::If(statement) => {
+                self.infer(statement.condition());
+                self.check_block(statement.block());
+
+                for else_if in statement.else_ifs() {
+                    self.infer(else_if.condition());
+                    self.check_block(else_if.block());
+                }
+
+                if let Some(block) = statement.else_block() {
+                    self.check_block(block);
+                }
+            }
+            Statement::NumericFor(loop_) => {
+                let bounds = [
+                    (Some(loop_.start()), "initial value"),
+                    (Some(loop_.end()), "limit"),
+                    (loop_.step(), "step"),
+                ];
+
+                for (expression, role) in bounds {
+                    let Some(expression) = expression else {
+                        continue;
+                    };
+
+                    let bound = self.infer(expression);
+
+                    if !self
+                        .environment
+                        .is_subtype(&bound, &TypePrimitive::Number.into())
+                    {
+                        self.report(
+                            DiagnosticKind::TypeMismatch,
+                            format!(
+                                "the {} of a numeric `for` must be a `number`, not `{}`",
+                                role,
+                                describe(&bound)
+                            ),
+                        );
+                    }
+                }
+
+                self.push_scope();
+
+                let binding = loop_.binding();
+                let counter = match &binding.annotation {
+                    Some(annotation) => self.resolve(annotation),
+                    None => TypePrimitive::Number.into(),
+                };
+
+                self.declare_local(&binding.name, counter, binding.annotation.clone());
+                self.check_statements(loop_.block());
+                self.pop_scope();
+            }
+            Statement::GenericFor(loop_) => {
+                self.infer_list(loop_.values());
+                self.push_scope();
+
+                for binding in loop_.bindings() {
+                    let annotation = match &binding.annotation {
+                        Some(annotation) => self.resolve(annotation),
+                        None => any(),
+                    };
+
+                    self.declare_local(&binding.name, annotation, binding.annotation.clone());
+                }
+
+                self.check_statements(loop_.block());
+                self.pop_scope();
+            }
+
+            Statement::FunctionDeclaration(declaration) => {
+                let name = declaration.name();
+                let signature = self.signature(declaration.function(), name.is_method());
+
+                let mut keys = name.path()[1..]
+                    .iter()
+                    .map(String::as_str)
+                    .chain(name.method())
+                    .collect::<Vec<_>>();
+
+                match keys.pop() {
+                    None => self.assign_variable(name.root(), signature.clone().into()),
+                    Some(last) => {
+                        let mut object = Expression::from(Identifier::new(name.root()));
+
+                        for key in keys {
+                            object = Index::field(object, key).into();
+                        }
+
+                        let object_type = self.infer(&object);
+                        self.assign_property(&object, &object_type, last, signature.clone().into());
+                    }
+                }
+
+                self.check_function_body(declaration.function(), &signature);
+            }
+            Statement::LocalFunction(declaration) => {
+                // The local is declared first, so that the function can call itself.
+                let signature = self.signature(declaration.function(), false);
+                self.declare_local(declaration.name(), signature.clone().into(), None);
+                self.check_function_body(declaration.function(), &signature);
+            }
+
+            Statement::Return(statement) => {
+                let values = self.infer_list(statement.values());
+                let expected = self
+                    .functions
+                    .last()
+                    .and_then(|function| function.returns.clone());
+
+                if let Some(expected) = expected {
+                    self.check_returns(&values, &expected);
+                }
+            }
+            Statement::Break(_) | Statement::Continue(_) => {}
+
+            // Type aliases are declared before the statements of their block are checked.
+            Statement::TypeDeclaration(_) => {}
+        }
+    }
+
+    /// Checks the body of a function, with its parameters typed by its signature.
+    pub(super) fn check_function_body(&mut self, function: &Function, signature: &TypeFunction) {
+        self.push_scope();
+
+        let implicit = signature.arguments().len() - function.parameters().len();
+
+        if implicit > 0 {
+            self.declare_local("self", any(), None);
+        }
+
+        for (parameter, argument) in function
+            .parameters()
+            .iter()
+            .zip(&signature.arguments()[implicit..])
+        {
+            self.declare_local(
+                &parameter.name,
+                argument.val().clone(),
+                parameter.annotation.clone(),
+            );
+        }
+
+        let varargs = match signature.variadic() {
+            Some(tail) => TypePack::tail(tail.clone()),
+            None => TypePack::variadic(any()),
+        };
+
+        self.functions.push(FunctionContext {
+            returns: function.returns().map(|_| signature.result().clone()),
+            varargs,
+        });
+        self.check_statements(function.block());
+        self.functions.pop();
+
+        self.pop_scope();
+    }
+
+    /// Checks the values returned by a function against the results it declares.
+    fn check_returns(&mut self, values: &TypePack, expected: &TypePack) {
+        let given = values.types().len();
+
+        for (position, result) in expected.types().iter().enumerate() {
+            match pack_value(values, position) {
+                Some(value) if !self.environment.is_subtype(&value, result) => self.report(
+                    DiagnosticKind::TypeMismatch,
+                    format!(
+                        "return value {}: cannot convert `{}` to `{}`",
+                        position + 1,
+                        describe(&value),
+                        describe(result)
+                    ),
+                ),
+                Some(_) => {}
+                None if self
+                    .environment
+                    .is_subtype(&TypePrimitive::Nil.into(), result) => {}
+                None => {
+                    self.report(
+                        DiagnosticKind::ReturnCount,
+                        format!(
+                            "function returns {} {}, but {} {} expected",
+                            given,
+                            values_noun(given),
+                            expected.types().len(),
+                            if expected.types().len() == 1 {
+                                "is"
+                            } else {
+                                "are"
+                            }
+                        ),
+                    );
+                    break;
+                }
+            }
+        }
+
+        match expected.pack_tail() {
+            Some(TypePackTail::Variadic(result)) => {
+                for (position, value) in values
+                    .types()
+                    .iter()
+                    .enumerate()
+                    .skip(expected.types().len())
+                {
+                    if !self.environment.is_subtype(value, result) {
+                        self.report(
+                            DiagnosticKind::TypeMismatch,
+                            format!(
+                                "return value {}: cannot convert `{}` to `{}`",
+                                position + 1,
+                                describe(value),
+                                describe(result)
+                            ),
+                        );
+                    }
+                }
+            }
+            Some(TypePackTail::Generic(_)) => {}
+            None if given > expected.types().len() => self.report(
+                DiagnosticKind::ReturnCount,
+                format!(
+                    "function returns {} {}, but at most {} {} expected",
+                    given,
+                    values_noun(given),
+                    expected.types().len(),
+                    if expected.types().len() == 1 {
+                        "is"
+                    } else {
+                        "are"
+                    }
+                ),
+            ),
+            None => {}
+        }
+    }
+
+    /// Checks a value assigned to a target of an assignment.
+    fn assign(&mut self, target: &Expression, value: TypeAnnotation) {
+        match target {
+            Expression::Identifier(identifier) => self.assign_variable(identifier.name(), value),
+            Expression::Index(index) => {
+                let object = self.infer(index.object());
+
+                let name = match index.key() {
+                    IndexKey::Field(name) => Some(name.clone()),
+                    IndexKey::Computed(key) => {
+                        let key_type = self.infer(key);
+                        let name = string_constant(key);
+
+                        if name.is_none() && !self.widen_element(index.object(), &key_type, &value)
+                        {
+                            if let Some(element) = element(self.environment, &object, &key_type) {
+                                self.expect_assignable(&value, &element, target);
+                            }
+                        }
+
+                        name
+                    }
+                };
+
+                if let Some(name) = name {
+                    self.assign_property(index.object(), &object, &name, value);
+                }
+            }
+            _ => {
+                self.infer(target);
+            }
+        }
+    }
+
+    fn assign_variable(&mut self, name: &str, value: TypeAnnotation) {
+        let environment = self.environment;
+
+        if let Some(local) = self.local_mut(name) {
+            if let Some(written) = local.declared.clone() {
+                if !environment.is_subtype(&value, &local.annotation) {
+                    self.report(
+                        DiagnosticKind::TypeMismatch,
+                        format!(
+                            "cannot assign `{}` to local `{}` of type `{}`",
+                            describe(&value),
+                            name,
+                            describe(&written)
+                        ),
+                    );
+                }
+            } else if !environment.is_subtype(&value, &local.annotation)
+                || !environment.is_subtype(&local.annotation, &value)
+            {
+                // Locals without annotations can hold values of any type in non-strict mode.
+                local.annotation = union_of(vec![local.annotation.clone(), value]);
+            }
+
+            return;
+        }
+
+        if let Some(annotation) = self.environment.global(name) {
+            let annotation = self.resolve(annotation);
+
+            if !self.environment.is_subtype(&value, &annotation) {
+                self.report(
+                    DiagnosticKind::TypeMismatch,
+                    format!(
+                        "cannot assign `{}` to global `{}` of type `{}`",
+                        describe(&value),
+                        name,
+                        describe(&annotation)
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Checks a value assigned to a property. Tables inferred for locals without annotations are
+    /// unsealed, like tables created by constructors in Luau, so their properties can be added and
+    /// given values of other types.
+    fn assign_property(
+        &mut self,
+        object_expression: &Expression,
+        object: &TypeAnnotation,
+        name: &str,
+        value: TypeAnnotation,
+    ) {
+        let environment = self.environment;
+
+        if let Some(table) = self.unsealed_table(object_expression) {
+            let position = table
+                .entries()
+                .iter()
+                .position(|entry| entry.index() == name);
+
+            match position {
+                Some(position) => {
+                    let current = table.entries_mut()[position].value_mut();
+
+                    if !environment.is_subtype(&value, current) {
+                        *current = union_of(vec![current.clone(), value]);
+                    }
+
+                    return;
+                }
+                None if table.indexer().is_none() => {
+                    table
+                        .entries_mut()
+                        .push(TypeTableEntry::new(name.to_owned(), value));
+                    return;
+                }
+                None => {}
+            }
+        }
+
+        match self.lookup_property(object, name) {
+            Property::Found(property) => {
+                let target = Index::field(object_expression.clone(), name).into();
+                self.expect_assignable(&value, &property, &target);
+            }
+            Property::Missing => self.report_missing_property(object, object_expression, name),
+            Property::NotIndexable => self.report_not_indexable(object, object_expression),
+            Property::Unknown => {}
+        }
+    }
+
+    fn expect_assignable(
+        &mut self,
+        value: &TypeAnnotation,
+        target_type: &TypeAnnotation,
+        target: &Expression,
+    ) {
+        if !self.environment.is_subtype(value, target_type) {
+            self.report(
+                DiagnosticKind::TypeMismatch,
+                format!(
+                    "cannot assign `{}` to `{}` of type `{}`",
+                    describe(value),
+                    describe_expression(target),
+                    describe(target_type)
+                ),
+            );
+        }
+    }
+
+    /// Returns the unsealed table type of an expression, which is the type inferred for a local
+    /// without an annotation, or of a table nested in its properties or elements.
+    pub(super) fn unsealed_table(&mut self, object: &Expression) -> Option<&mut TypeTable> {
+        match self.unsealed(object)? {
+            TypeAnnotation::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Returns the unsealed type of an expression, which is either a table or an array.
+    fn unsealed(&mut self, object: &Expression) -> Option<&mut TypeAnnotation> {
+        let mut path = Vec::new();
+        let mut current = object;
+
+        let root = loop {
+            match current {
+                Expression::Identifier(identifier) => break identifier.name(),
+                Expression::Index(index) => {
+                    path.push(match index.key() {
+                        IndexKey::Field(field) => Some(field.clone()),
+                        IndexKey::Computed(key) => string_constant(key),
+                    });
+                    current = index.object();
+                }
+                _ => return None,
+            }
+        };
+
+        let local = self
+            .local_mut(root)
+            .filter(|local| local.declared.is_none())?;
+
+        let mut annotation = &mut local.annotation;
+
+        for key in path.into_iter().rev() {
+            annotation = match (annotation, key) {
+                (TypeAnnotation::Table(table), Some(field)) => table
+                    .entries_mut()
+                    .iter_mut()
+                    .find(|entry| entry.index() == field)?
+                    .value_mut(),
+                (TypeAnnotation::Table(table), None) => table.indexer_mut()?.value_mut(),
+                (TypeAnnotation::Array(array), None) => array.item_mut()?,
+                _ => return None,
+            };
+        }
+
+        match annotation {
+            TypeAnnotation::Table(_) | TypeAnnotation::Array(_) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Widens the elements of an unsealed array, or the values of the indexer of an unsealed
+    /// table, to hold a value assigned to a computed key. Returns whether the object is unsealed.
+    fn widen_element(
+        &mut self,
+        object: &Expression,
+        key: &TypeAnnotation,
+        value: &TypeAnnotation,
+    ) -> bool {
+        let environment = self.environment;
+
+        let element = match self.unsealed(object) {
+            Some(TypeAnnotation::Array(array))
+                if environment.is_subtype(key, &TypePrimitive::Number.into()) =>
+            {
+                array.item_mut()
+            }
+            Some(TypeAnnotation::Table(table)) => table
+                .indexer_mut()
+                .filter(|indexer| environment.is_subtype(key, indexer.index()))
+                .map(|indexer| indexer.value_mut()),
+            _ => None,
+        };
+
+        let Some(element) = element else {
+            return false;
+        };
+
+        if !environment.is_subtype(value, element) {
+            *element = union_of(vec![element.clone(), value.clone()]);
+        }
+
+        true
+    }
+}
+
+fn values_noun(count: usize) -> &'static str {
+    if count == 1 {
+        "value"
+    } else {
+        "values"
+    }
+}
@@ -0,0 +1,44 @@
+//! Static analysis of a parsed chunk.
+//!
+//! [`ScopeTree`] resolves every name to the local, parameter or loop variable declaring it,
+//! following the scoping rules of Luau, and [`rename`] uses it to rename a local safely. [`check`]
+//! infers the types of locals and expressions, and reports values used with the wrong type.
+//! ```
+//! use luna::analysis::{Resolution, ScopeTree};
+//! use luna::ast::{Binding, Block, BooleanLiteral, Function, Identifier, LocalAssign, Repeat, Return};
+//!
+//! // repeat
+//! //     local done = true
+//! //     local check = function() return done end
+//! // until done
+//! let chunk = Block::new(vec![Repeat::new(
+//!     Block::new(vec![
+//!         LocalAssign::assign_one(Binding::named("done"), BooleanLiteral::from(true)).into(),
+//!         LocalAssign::assign_one(
+//!             Binding::named("check"),
+//!             Function::simple(vec![], Block::new(vec![Return::new(vec![Identifier::new("done").into()]).into()])),
+//!         )
+//!         .into(),
+//!     ]),
+//!     Identifier::new("done"),
+//! )
+//! .into()]);
+//!
+//! let tree = ScopeTree::analyze(&chunk);
+//! let done = tree.symbol_at(0).unwrap();
+//!
+//! // The function captures `done`, and the condition can see the locals of the body.
+//! assert_eq!(tree.reference_at(2).unwrap().resolution(), Resolution::Upvalue(done));
+//! assert_eq!(tree.reference_at(3).unwrap().resolution(), Resolution::Local(done));
+//! ```
+pub mod check;
+pub mod rename;
+pub mod scope;
+
+pub use check::{check, check_in, Diagnostic, DiagnosticKind, TypeEnvironment};
+pub use rename::{rename, RenameError};
+
+pub use scope::{
+    Reference, ReferenceAccess, ReferenceId, Resolution, Scope, ScopeId, ScopeKind, ScopeTree,
+    Symbol, SymbolId, SymbolKind,
+};
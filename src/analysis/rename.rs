@@ -0,0 +1,131 @@
+use std::fmt;
+
+use crate::analysis::ScopeTree;
+use crate::edit::{apply_edits, TextEdit};
+use crate::lexer::is_identifier;
+use crate::parser::{parse, ParseError, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// The source could not be parsed.
+    Parse(ParseError),
+    /// The new name is not a valid identifier, or would change how the source parses.
+    InvalidName(String),
+    /// The position is not on the name of a local, such as a global or the implicit `self` of a method.
+    NotALocal,
+    /// A reference to the renamed local would instead refer to another declaration of the new name.
+    Captured(Span),
+    /// A reference to another declaration of the new name would instead refer to the renamed local.
+    Shadowed(Span),
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => write!(f, "{}", error),
+            Self::InvalidName(name) => write!(f, "`{}` is not a valid name", name),
+            Self::NotALocal => write!(f, "only locals can be renamed"),
+            Self::Captured(span) => write!(
+                f,
+                "the reference at {}..{} would refer to another declaration",
+                span.start, span.end
+            ),
+            Self::Shadowed(span) => write!(
+                f,
+                "the reference at {}..{} would refer to the renamed local",
+                span.start, span.end
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RenameError {}
+
+impl From<ParseError> for RenameError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+/// Renames the local at the given byte position, along with every reference to it.
+///
+/// The rename is rejected if it would change what any name in the chunk refers to, such as when a
+/// reference would be captured by an inner declaration of the new name, or when the renamed local
+/// would shadow another declaration of the new name.
+/// ```
+/// use luna::analysis::{rename, RenameError};
+/// use luna::edit::apply_edits;
+///
+/// let source = "local count = 0\nlocal function bump()\n    count += 1\nend";
+/// let edits = rename(source, 6, "total").unwrap();
+///
+/// assert_eq!(
+///     apply_edits(source, &edits),
+///     "local total = 0\nlocal function bump()\n    total += 1\nend",
+/// );
+///
+/// // `count` inside the function would refer to the parameter instead.
+/// let source = "local count = 0\nlocal function bump(step)\n    count += step\nend";
+/// assert_eq!(rename(source, 40, "count"), Err(RenameError::Shadowed(46..51)));
+/// ```
+pub fn rename(source: &str, position: usize, new_name: &str) -> Result<Vec<TextEdit>, RenameError> {
+    if !is_identifier(new_name) {
+        return Err(RenameError::InvalidName(new_name.to_string()));
+    }
+
+    let chunk = parse(source)?;
+    let tree = ScopeTree::analyze(chunk.block());
+
+    let occurrence = chunk
+        .names()
+        .iter()
+        .position(|span| span.start <= position && position <= span.end)
+        .ok_or(RenameError::NotALocal)?;
+
+    let symbol = tree.symbol_at(occurrence).ok_or(RenameError::NotALocal)?;
+    let declaration = tree
+        .symbol(symbol)
+        .occurrence()
+        .ok_or(RenameError::NotALocal)?;
+
+    let mut renamed = tree
+        .references_to(symbol)
+        .map(|reference| reference.occurrence())
+        .collect::<Vec<_>>();
+    renamed.push(declaration);
+    renamed.sort_unstable();
+
+    let edits = renamed
+        .iter()
+        .map(|occurrence| TextEdit::new(chunk.names()[*occurrence].clone(), new_name))
+        .collect::<Vec<_>>();
+
+    // The renamed source is analyzed again, and every name must still refer to the same symbol.
+    let invalid = || RenameError::InvalidName(new_name.to_string());
+    let renamed_chunk = parse(&apply_edits(source, &edits)).map_err(|_| invalid())?;
+    let renamed_tree = ScopeTree::analyze(renamed_chunk.block());
+
+    let before = tree.occurrences();
+    let after = renamed_tree.occurrences();
+
+    if before.len() != after.len() {
+        return Err(invalid());
+    }
+
+    let changed = before
+        .iter()
+        .zip(after.iter())
+        .position(|(before, after)| before != after);
+
+    match changed {
+        Some(occurrence) => {
+            let span = chunk.names()[occurrence].clone();
+
+            match renamed.binary_search(&occurrence) {
+                Ok(_) => Err(RenameError::Captured(span)),
+                Err(_) => Err(RenameError::Shadowed(span)),
+            }
+        }
+        None => Ok(edits),
+    }
+}
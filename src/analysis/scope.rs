@@ -0,0 +1,602 @@
+use crate::ast::{
+    Assign, Binding, Block, CompoundAssign, Expression, Function, FunctionDeclaration, GenericFor,
+    Identifier, LocalAssign, LocalFunction, NumericFor, Repeat,
+};
+use crate::visit::visitor::walk_block;
+use crate::visit::Visitor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReferenceId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+    /// The top level of a chunk.
+    Chunk,
+    /// The body of a function, which also holds its parameters.
+    Function,
+    /// Any other block, such as the body of a `do`, `if` or loop.
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Local,
+    LocalFunction,
+    Parameter,
+    LoopVariable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceAccess {
+    Read,
+    Write,
+    /// Both read and written, such as the target of `x += 1`.
+    ReadWrite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    /// A symbol declared in the same function as the reference.
+    Local(SymbolId),
+    /// A symbol declared in an enclosing function, captured by the function of the reference.
+    Upvalue(SymbolId),
+    /// A name with no visible declaration.
+    Global,
+}
+
+impl Resolution {
+    /// Returns the [`SymbolId`] this [`Resolution`] refers to, or `None` for a global.
+    pub fn symbol(&self) -> Option<SymbolId> {
+        match self {
+            Self::Local(symbol) | Self::Upvalue(symbol) => Some(*symbol),
+            Self::Global => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scope {
+    kind: ScopeKind,
+    parent: Option<ScopeId>,
+    function: ScopeId,
+    children: Vec<ScopeId>,
+    symbols: Vec<SymbolId>,
+}
+
+impl Scope {
+    /// Returns the [`ScopeKind`] of this [`Scope`].
+    pub fn kind(&self) -> ScopeKind {
+        self.kind
+    }
+
+    /// Returns the enclosing [`Scope`] of this [`Scope`], or `None` for the chunk.
+    pub fn parent(&self) -> Option<ScopeId> {
+        self.parent
+    }
+
+    /// Returns the nearest function or chunk [`Scope`] containing this [`Scope`], which may be itself.
+    pub fn function(&self) -> ScopeId {
+        self.function
+    }
+
+    /// Returns the scopes directly nested in this [`Scope`], in source order.
+    pub fn children(&self) -> &Vec<ScopeId> {
+        &self.children
+    }
+
+    /// Returns the symbols declared in this [`Scope`], in source order.
+    pub fn symbols(&self) -> &Vec<SymbolId> {
+        &self.symbols
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    name: String,
+    kind: SymbolKind,
+    scope: ScopeId,
+    occurrence: Option<usize>,
+    references: Vec<ReferenceId>,
+}
+
+impl Symbol {
+    /// Returns the name of this [`Symbol`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`SymbolKind`] of this [`Symbol`].
+    pub fn kind(&self) -> SymbolKind {
+        self.kind
+    }
+
+    /// Returns the [`Scope`] this [`Symbol`] is declared in.
+    pub fn scope(&self) -> ScopeId {
+        self.scope
+    }
+
+    /// Returns the occurrence of the name declaring this [`Symbol`].
+    ///
+    /// This is `None` for the implicit `self` parameter of a method.
+    pub fn occurrence(&self) -> Option<usize> {
+        self.occurrence
+    }
+
+    /// Returns the references resolved to this [`Symbol`], in source order.
+    pub fn references(&self) -> &Vec<ReferenceId> {
+        &self.references
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    name: String,
+    scope: ScopeId,
+    occurrence: usize,
+    access: ReferenceAccess,
+    resolution: Resolution,
+}
+
+impl Reference {
+    /// Returns the name of this [`Reference`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`Scope`] this [`Reference`] appears in.
+    pub fn scope(&self) -> ScopeId {
+        self.scope
+    }
+
+    /// Returns the occurrence of the name of this [`Reference`].
+    pub fn occurrence(&self) -> usize {
+        self.occurrence
+    }
+
+    /// Returns the [`ReferenceAccess`] of this [`Reference`].
+    pub fn access(&self) -> ReferenceAccess {
+        self.access
+    }
+
+    /// Returns the [`Resolution`] of this [`Reference`].
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+}
+
+/// The scopes, symbols and references of a chunk.
+///
+/// Every name written in the chunk, whether declaring a symbol or referring to one, is numbered
+/// by its occurrence: its position among all such names in source order. Field names, method names
+/// and the names of types are not counted, as they never refer to a local.
+/// ```
+/// use luna::analysis::{Resolution, ScopeTree};
+/// use luna::ast::{Binding, Block, FunctionCall, Identifier, LocalAssign, NumberLiteral};
+///
+/// // local x = 1
+/// // local x = x
+/// // print(x)
+/// let chunk = Block::new(vec![
+///     LocalAssign::assign_one(Binding::named("x"), NumberLiteral::from("1")).into(),
+///     LocalAssign::assign_one(Binding::named("x"), Identifier::new("x")).into(),
+///     FunctionCall::call(Identifier::new("print"), vec![Identifier::new("x").into()]).into(),
+/// ]);
+///
+/// let tree = ScopeTree::analyze(&chunk);
+/// let [outer, inner] = [0, 2].map(|occurrence| tree.symbol_at(occurrence).unwrap());
+///
+/// // The value of the second declaration refers to the first `x`, as it is not yet in scope.
+/// assert_eq!(tree.references_to(outer).count(), 1);
+/// assert_eq!(tree.references_to(inner).count(), 1);
+/// assert_eq!(tree.reference_at(3).unwrap().resolution(), Resolution::Global);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeTree {
+    scopes: Vec<Scope>,
+    symbols: Vec<Symbol>,
+    references: Vec<Reference>,
+}
+
+impl ScopeTree {
+    /// Analyzes the given chunk, resolving every name to the symbol declaring it.
+    pub fn analyze(chunk: &Block) -> Self {
+        let mut analyzer = ScopeAnalyzer {
+            tree: Self {
+                scopes: Vec::new(),
+                symbols: Vec::new(),
+                references: Vec::new(),
+            },
+            current: ScopeId(0),
+            occurrences: 0,
+        };
+
+        analyzer.enter(ScopeKind::Chunk);
+        walk_block(&mut analyzer, chunk);
+
+        analyzer.tree
+    }
+}
+
+impl ScopeTree {
+    /// Returns the chunk [`Scope`], which contains every other scope.
+    pub fn root(&self) -> ScopeId {
+        ScopeId(0)
+    }
+
+    /// Returns the [`Scope`] with the given [`ScopeId`].
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.0]
+    }
+
+    /// Returns the [`Symbol`] with the given [`SymbolId`].
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        &self.symbols[id.0]
+    }
+
+    /// Returns the [`Reference`] with the given [`ReferenceId`].
+    pub fn reference(&self, id: ReferenceId) -> &Reference {
+        &self.references[id.0]
+    }
+
+    /// Returns every [`Symbol`] of this [`ScopeTree`], in source order.
+    pub fn symbols(&self) -> impl Iterator<Item = (SymbolId, &Symbol)> {
+        self.symbols
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| (SymbolId(index), symbol))
+    }
+
+    /// Returns every [`Reference`] of this [`ScopeTree`], in source order.
+    pub fn references(&self) -> impl Iterator<Item = (ReferenceId, &Reference)> {
+        self.references
+            .iter()
+            .enumerate()
+            .map(|(index, reference)| (ReferenceId(index), reference))
+    }
+
+    /// Returns every [`Reference`] resolved to the given symbol.
+    pub fn references_to(&self, symbol: SymbolId) -> impl Iterator<Item = &Reference> {
+        self.symbol(symbol)
+            .references()
+            .iter()
+            .map(|reference| self.reference(*reference))
+    }
+
+    /// Returns every [`Reference`] that does not resolve to a symbol.
+    pub fn globals(&self) -> impl Iterator<Item = &Reference> {
+        self.references
+            .iter()
+            .filter(|reference| reference.resolution() == Resolution::Global)
+    }
+
+    /// Returns the [`Reference`] at the given occurrence, if that occurrence is not a declaration.
+    pub fn reference_at(&self, occurrence: usize) -> Option<&Reference> {
+        self.references
+            .iter()
+            .find(|reference| reference.occurrence() == occurrence)
+    }
+
+    /// Returns the symbol declared or referred to at the given occurrence.
+    pub fn symbol_at(&self, occurrence: usize) -> Option<SymbolId> {
+        let declared = self
+            .symbols()
+            .find(|(_, symbol)| symbol.occurrence() == Some(occurrence))
+            .map(|(id, _)| id);
+
+        declared.or_else(|| {
+            self.reference_at(occurrence)
+                .and_then(|reference| reference.resolution().symbol())
+        })
+    }
+
+    /// Returns the symbol declared or referred to at every occurrence, indexed by occurrence.
+    ///
+    /// Occurrences that refer to a global have no symbol.
+    pub fn occurrences(&self) -> Vec<Option<SymbolId>> {
+        let count =
+            self.symbols.iter().filter_map(Symbol::occurrence).count() + self.references.len();
+        let mut occurrences = vec![None; count];
+
+        for (id, symbol) in self.symbols() {
+            if let Some(occurrence) = symbol.occurrence() {
+                occurrences[occurrence] = Some(id);
+            }
+        }
+
+        for reference in &self.references {
+            occurrences[reference.occurrence()] = reference.resolution().symbol();
+        }
+
+        occurrences
+    }
+
+    /// Returns the symbol the given name would resolve to if it were written in the given scope,
+    /// after every declaration of that scope.
+    pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<SymbolId> {
+        let mut current = Some(scope);
+
+        while let Some(id) = current {
+            let scope = self.scope(id);
+
+            let found = scope
+                .symbols()
+                .iter()
+                .rev()
+                .find(|symbol| self.symbol(**symbol).name() == name);
+
+            if let Some(symbol) = found {
+                return Some(*symbol);
+            }
+
+            current = scope.parent();
+        }
+
+        None
+    }
+}
+
+struct ScopeAnalyzer {
+    tree: ScopeTree,
+    current: ScopeId,
+    occurrences: usize,
+}
+
+impl ScopeAnalyzer {
+    fn enter(&mut self, kind: ScopeKind) {
+        let id = ScopeId(self.tree.scopes.len());
+
+        let (parent, function) = match self.tree.scopes.is_empty() {
+            true => (None, id),
+            false => (Some(self.current), self.tree.scope(self.current).function()),
+        };
+
+        let function = match kind {
+            ScopeKind::Block => function,
+            ScopeKind::Chunk | ScopeKind::Function => id,
+        };
+
+        self.tree.scopes.push(Scope {
+            kind,
+            parent,
+            function,
+            children: Vec::new(),
+            symbols: Vec::new(),
+        });
+
+        if let Some(parent) = parent {
+            self.tree.scopes[parent.0].children.push(id);
+        }
+
+        self.current = id;
+    }
+
+    fn exit(&mut self) {
+        if let Some(parent) = self.tree.scope(self.current).parent() {
+            self.current = parent;
+        }
+    }
+
+    fn occurrence(&mut self) -> usize {
+        self.occurrences += 1;
+        self.occurrences - 1
+    }
+
+    /// Reserves the occurrence of a binding and resolves its annotation, before it is in scope.
+    fn reserve(&mut self, binding: &Binding) -> usize {
+        let occurrence = self.occurrence();
+        self.visit_binding(binding);
+
+        occurrence
+    }
+
+    fn declare(&mut self, name: &str, kind: SymbolKind, occurrence: Option<usize>) -> SymbolId {
+        let id = SymbolId(self.tree.symbols.len());
+
+        self.tree.symbols.push(Symbol {
+            name: name.to_string(),
+            kind,
+            scope: self.current,
+            occurrence,
+            references: Vec::new(),
+        });
+
+        self.tree.scopes[self.current.0].symbols.push(id);
+
+        id
+    }
+
+    fn refer(&mut self, name: &str, access: ReferenceAccess) {
+        let occurrence = self.occurrence();
+        let id = ReferenceId(self.tree.references.len());
+
+        let resolution = match self.tree.lookup(self.current, name) {
+            Some(symbol) => {
+                let declared = self.tree.symbol(symbol).scope();
+
+                match self.tree.scope(declared).function()
+                    == self.tree.scope(self.current).function()
+                {
+                    true => Resolution::Local(symbol),
+                    false => Resolution::Upvalue(symbol),
+                }
+            }
+            None => Resolution::Global,
+        };
+
+        if let Some(symbol) = resolution.symbol() {
+            self.tree.symbols[symbol.0].references.push(id);
+        }
+
+        self.tree.references.push(Reference {
+            name: name.to_string(),
+            scope: self.current,
+            occurrence,
+            access,
+            resolution,
+        });
+    }
+
+    fn visit_target(&mut self, target: &Expression, access: ReferenceAccess) {
+        match target {
+            Expression::Identifier(identifier) => self.refer(identifier.name(), access),
+            _ => self.visit_expression(target),
+        }
+    }
+
+    fn visit_function_body(&mut self, function: &Function, method: bool) {
+        self.enter(ScopeKind::Function);
+
+        for generic in function.generics() {
+            self.visit_type_generic_parameter(generic);
+        }
+
+        if method {
+            self.declare("self", SymbolKind::Parameter, None);
+        }
+
+        for parameter in function.parameters() {
+            let occurrence = self.reserve(parameter);
+            self.declare(&parameter.name, SymbolKind::Parameter, Some(occurrence));
+        }
+
+        if let Some(variadic) = function.variadic() {
+            self.visit_function_variadic(variadic);
+        }
+
+        if let Some(returns) = function.returns() {
+            self.visit_type_pack(returns);
+        }
+
+        walk_block(self, function.block());
+
+        self.exit();
+    }
+}
+
+impl Visitor for ScopeAnalyzer {
+    fn visit_local_assign(&mut self, local_assign: &LocalAssign) {
+        let occurrences = local_assign
+            .bindings()
+            .iter()
+            .map(|binding| self.reserve(binding))
+            .collect::<Vec<_>>();
+
+        // The values are resolved before the bindings are declared, so `local x = x` refers to an outer `x`.
+        for value in local_assign.values() {
+            self.visit_expression(value);
+        }
+
+        for (binding, occurrence) in local_assign.bindings().iter().zip(occurrences) {
+            self.declare(&binding.name, SymbolKind::Local, Some(occurrence));
+        }
+    }
+
+    fn visit_local_function(&mut self, local_function: &LocalFunction) {
+        // The name is declared before the body, so the function can call itself.
+        let occurrence = self.occurrence();
+        self.declare(
+            local_function.name(),
+            SymbolKind::LocalFunction,
+            Some(occurrence),
+        );
+
+        self.visit_function_body(local_function.function(), false);
+    }
+
+    fn visit_function_declaration(&mut self, function_declaration: &FunctionDeclaration) {
+        let name = function_declaration.name();
+
+        // Only `function name()` assigns to its root, `function name.field()` reads it.
+        let access = match name.path().len() == 1 && !name.is_method() {
+            true => ReferenceAccess::Write,
+            false => ReferenceAccess::Read,
+        };
+
+        self.refer(name.root(), access);
+        self.visit_function_body(function_declaration.function(), name.is_method());
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.visit_function_body(function, false);
+    }
+
+    fn visit_assign(&mut self, assign: &Assign) {
+        for target in assign.targets() {
+            self.visit_target(target, ReferenceAccess::Write);
+        }
+
+        for value in assign.values() {
+            self.visit_expression(value);
+        }
+    }
+
+    fn visit_compound_assign(&mut self, compound_assign: &CompoundAssign) {
+        self.visit_target(compound_assign.target(), ReferenceAccess::ReadWrite);
+        self.visit_expression(compound_assign.value());
+    }
+
+    fn visit_repeat(&mut self, repeat_loop: &Repeat) {
+        // The condition of `repeat ... until` can see the locals of the body.
+        self.enter(ScopeKind::Block);
+        walk_block(self, repeat_loop.block());
+        self.visit_expression(repeat_loop.condition());
+        self.exit();
+    }
+
+    fn visit_numeric_for(&mut self, numeric_for: &NumericFor) {
+        let occurrence = self.reserve(numeric_for.binding());
+
+        self.visit_expression(numeric_for.start());
+        self.visit_expression(numeric_for.end());
+
+        if let Some(step) = numeric_for.step() {
+            self.visit_expression(step);
+        }
+
+        self.enter(ScopeKind::Block);
+        self.declare(
+            &numeric_for.binding().name,
+            SymbolKind::LoopVariable,
+            Some(occurrence),
+        );
+        walk_block(self, numeric_for.block());
+        self.exit();
+    }
+
+    fn visit_generic_for(&mut self, generic_for: &GenericFor) {
+        let occurrences = generic_for
+            .bindings()
+            .iter()
+            .map(|binding| self.reserve(binding))
+            .collect::<Vec<_>>();
+
+        for value in generic_for.values() {
+            self.visit_expression(value);
+        }
+
+        self.enter(ScopeKind::Block);
+
+        for (binding, occurrence) in generic_for.bindings().iter().zip(occurrences) {
+            self.declare(&binding.name, SymbolKind::LoopVariable, Some(occurrence));
+        }
+
+        walk_block(self, generic_for.block());
+        self.exit();
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        self.enter(ScopeKind::Block);
+        walk_block(self, block);
+        self.exit();
+    }
+
+    fn visit_identifier(&mut self, identifier: &Identifier) {
+        self.refer(identifier.name(), ReferenceAccess::Read);
+    }
+}
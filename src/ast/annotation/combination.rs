@@ -21,6 +21,21 @@ impl TypeCombination {
         }
     }
 
+    /// Constructs a new union [`TypeCombination`] with the given left and right [`TypeAnnotation`].
+    pub fn union<L: Into<TypeAnnotation>, R: Into<TypeAnnotation>>(left: L, right: R) -> Self {
+        Self::new(TypeOperator::Union, left, right)
+    }
+
+    /// Constructs a new intersection [`TypeCombination`] with the given left and right [`TypeAnnotation`].
+    pub fn intersection<L: Into<TypeAnnotation>, R: Into<TypeAnnotation>>(
+        left: L,
+        right: R,
+    ) -> Self {
+        Self::new(TypeOperator::Intersection, left, right)
+    }
+}
+
+impl TypeCombination {
     /// Returns the [`TypeOperator`] of this [`TypeCombination`].
     pub fn operator(&self) -> TypeOperator {
         self.operator
@@ -31,8 +46,23 @@ impl TypeCombination {
         &self.left
     }
 
+    /// Returns a mutable reference to the left [`TypeAnnotation`] of this [`TypeCombination`].
+    pub fn left_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.left
+    }
+
     /// Returns the right [`TypeAnnotation`] of this [`TypeCombination`].
     pub fn right(&self) -> &TypeAnnotation {
         &self.right
     }
+
+    /// Returns a mutable reference to the right [`TypeAnnotation`] of this [`TypeCombination`].
+    pub fn right_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.right
+    }
+
+    /// Consumes this [`TypeCombination`], returning its [`TypeOperator`] and left and right [`TypeAnnotation`].
+    pub fn into_parts(self) -> (TypeOperator, TypeAnnotation, TypeAnnotation) {
+        (self.operator, *self.left, *self.right)
+    }
 }
@@ -1,4 +1,6 @@
-use crate::ast::TypeAnnotation;
+use crate::ast::{
+    TypeAnnotation, TypeGeneric, TypeGenericPack, TypeGenericParameter, TypePack, TypePackTail,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeArgument {
@@ -36,20 +38,199 @@ impl TypeArgument {
     pub fn val(&self) -> &TypeAnnotation {
         &self.val
     }
+
+    /// Returns a mutable reference to the [`TypeAnnotation`] of this [`TypeArgument`].
+    pub fn val_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.val
+    }
+
+    /// Consumes this [`TypeArgument`], returning its name and [`TypeAnnotation`].
+    pub fn into_parts(self) -> (Option<String>, TypeAnnotation) {
+        (self.name, self.val)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeFunction {
+    generics: Vec<TypeGenericParameter>,
     arguments: Vec<TypeArgument>,
-    result: Vec<TypeAnnotation>,
+    variadic: Option<TypePackTail>,
+    result: TypePack,
+}
+
+impl TypeFunction {
+    /// Constructs a new [`TypeFunction`] with the given [`TypeGenericParameter`]s, [`TypeArgument`]s,
+    /// optional variadic [`TypePackTail`] and result [`TypePack`].
+    pub fn new(
+        generics: Vec<TypeGenericParameter>,
+        arguments: Vec<TypeArgument>,
+        variadic: Option<TypePackTail>,
+        result: TypePack,
+    ) -> Self {
+        Self {
+            generics,
+            arguments,
+            variadic,
+            result,
+        }
+    }
+
+    /// Constructs a new [`TypeFunctionBuilder`] for a function with no arguments and no results.
+    /// ```
+    /// use luna::ast::{TypeFunction, TypePrimitive};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let function = TypeFunction::builder()
+    ///     .generic("T")
+    ///     .arg("x", TypePrimitive::number())
+    ///     .variadic(TypePrimitive::string())
+    ///     .returns(TypePrimitive::boolean())
+    ///     .build();
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(function.format_string(&settings), "<T>(x: number, ...string) -> boolean");
+    /// ```
+    pub fn builder() -> TypeFunctionBuilder {
+        TypeFunctionBuilder::default()
+    }
 }
 
 impl TypeFunction {
+    /// Returns the [`TypeGenericParameter`]s of this [`TypeFunction`], such as `T` in `<T>(T) -> T`.
+    pub fn generics(&self) -> &Vec<TypeGenericParameter> {
+        &self.generics
+    }
+
+    /// Returns a mutable reference to the [`TypeGenericParameter`]s of this [`TypeFunction`].
+    pub fn generics_mut(&mut self) -> &mut Vec<TypeGenericParameter> {
+        &mut self.generics
+    }
+
     pub fn arguments(&self) -> &Vec<TypeArgument> {
         &self.arguments
     }
 
-    pub fn result(&self) -> &Vec<TypeAnnotation> {
+    /// Returns a mutable reference to the [`TypeArgument`]s of this [`TypeFunction`].
+    pub fn arguments_mut(&mut self) -> &mut Vec<TypeArgument> {
+        &mut self.arguments
+    }
+
+    /// Returns the variadic tail of the arguments of this [`TypeFunction`], such as `...string` in `(...string) -> ()`.
+    pub fn variadic(&self) -> Option<&TypePackTail> {
+        self.variadic.as_ref()
+    }
+
+    /// Returns a mutable reference to the variadic tail of the arguments of this [`TypeFunction`].
+    pub fn variadic_mut(&mut self) -> Option<&mut TypePackTail> {
+        self.variadic.as_mut()
+    }
+
+    pub fn result(&self) -> &TypePack {
         &self.result
     }
+
+    /// Returns a mutable reference to the result [`TypePack`] of this [`TypeFunction`].
+    pub fn result_mut(&mut self) -> &mut TypePack {
+        &mut self.result
+    }
+
+    /// Consumes this [`TypeFunction`], returning its [`TypeGenericParameter`]s, [`TypeArgument`]s, variadic [`TypePackTail`] and result [`TypePack`].
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<TypeGenericParameter>,
+        Vec<TypeArgument>,
+        Option<TypePackTail>,
+        TypePack,
+    ) {
+        (self.generics, self.arguments, self.variadic, self.result)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TypeFunctionBuilder {
+    generics: Vec<TypeGenericParameter>,
+    arguments: Vec<TypeArgument>,
+    variadic: Option<TypePackTail>,
+    results: Vec<TypeAnnotation>,
+    result_tail: Option<TypePackTail>,
+}
+
+impl TypeFunctionBuilder {
+    /// Adds a generic type parameter, such as `T` in `<T>(T) -> T`.
+    pub fn generic<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.generics.push(TypeGeneric::named(name).into());
+        self
+    }
+
+    /// Adds a generic type pack parameter, such as `T...` in `<T...>(T...) -> ()`.
+    pub fn generic_pack<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.generics.push(TypeGenericPack::named(name).into());
+        self
+    }
+
+    /// Adds a named argument with the given [`TypeAnnotation`].
+    pub fn arg<N: AsRef<str>, T: Into<TypeAnnotation>>(mut self, name: N, annotation: T) -> Self {
+        let name = name.as_ref().to_string();
+        self.arguments.push(TypeArgument::named(name, annotation));
+        self
+    }
+
+    /// Adds an anonymous argument with the given [`TypeAnnotation`].
+    pub fn anonymous_arg<T: Into<TypeAnnotation>>(mut self, annotation: T) -> Self {
+        self.arguments.push(TypeArgument::anonymous(annotation));
+        self
+    }
+
+    /// Sets the variadic tail of the arguments, such as `...string`.
+    pub fn variadic<T: Into<TypeAnnotation>>(mut self, annotation: T) -> Self {
+        self.variadic = Some(TypePackTail::variadic(annotation));
+        self
+    }
+
+    /// Sets the tail of the arguments to a generic type pack, such as `T...`.
+    pub fn variadic_generic<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.variadic = Some(TypePackTail::generic(name));
+        self
+    }
+
+    /// Adds a result with the given [`TypeAnnotation`].
+    pub fn returns<T: Into<TypeAnnotation>>(mut self, annotation: T) -> Self {
+        self.results.push(annotation.into());
+        self
+    }
+
+    /// Sets the variadic tail of the results, such as `...any`.
+    pub fn returns_variadic<T: Into<TypeAnnotation>>(mut self, annotation: T) -> Self {
+        self.result_tail = Some(TypePackTail::variadic(annotation));
+        self
+    }
+
+    /// Sets the tail of the results to a generic type pack, such as `T...`.
+    pub fn returns_generic<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.result_tail = Some(TypePackTail::generic(name));
+        self
+    }
+
+    /// Constructs the [`TypeFunction`].
+    pub fn build(self) -> TypeFunction {
+        TypeFunction::new(
+            self.generics,
+            self.arguments,
+            self.variadic,
+            TypePack::new(self.results, self.result_tail),
+        )
+    }
+}
+
+impl From<TypeFunctionBuilder> for TypeFunction {
+    fn from(builder: TypeFunctionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<TypeFunctionBuilder> for TypeAnnotation {
+    fn from(builder: TypeFunctionBuilder) -> Self {
+        builder.build().into()
+    }
 }
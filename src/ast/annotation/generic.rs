@@ -0,0 +1,115 @@
+use derive_more::From;
+
+use crate::ast::{TypeAnnotation, TypePack};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeGeneric {
+    name: String,
+    default: Option<TypeAnnotation>,
+}
+
+impl TypeGeneric {
+    /// Constructs a new [`TypeGeneric`] with the given name and optional default [`TypeAnnotation`].
+    pub fn new<T: AsRef<str>>(name: T, default: Option<TypeAnnotation>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            default,
+        }
+    }
+
+    /// Constructs a new [`TypeGeneric`] with the given name and no default.
+    pub fn named<T: AsRef<str>>(name: T) -> Self {
+        Self::new(name, None)
+    }
+
+    /// Constructs a new [`TypeGeneric`] with the given name and default [`TypeAnnotation`].
+    pub fn defaulted<T: AsRef<str>, D: Into<TypeAnnotation>>(name: T, default: D) -> Self {
+        Self::new(name, Some(default.into()))
+    }
+}
+
+impl TypeGeneric {
+    /// Returns the name of this [`TypeGeneric`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the default [`TypeAnnotation`] of this [`TypeGeneric`].
+    pub fn default(&self) -> Option<&TypeAnnotation> {
+        self.default.as_ref()
+    }
+
+    /// Returns a mutable reference to the default [`TypeAnnotation`] of this [`TypeGeneric`].
+    pub fn default_mut(&mut self) -> Option<&mut TypeAnnotation> {
+        self.default.as_mut()
+    }
+
+    /// Consumes this [`TypeGeneric`], returning its name and default [`TypeAnnotation`].
+    pub fn into_parts(self) -> (String, Option<TypeAnnotation>) {
+        (self.name, self.default)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeGenericPack {
+    name: String,
+    default: Option<TypePack>,
+}
+
+impl TypeGenericPack {
+    /// Constructs a new [`TypeGenericPack`] with the given name and optional default [`TypePack`].
+    pub fn new<T: AsRef<str>>(name: T, default: Option<TypePack>) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            default,
+        }
+    }
+
+    /// Constructs a new [`TypeGenericPack`] with the given name and no default.
+    pub fn named<T: AsRef<str>>(name: T) -> Self {
+        Self::new(name, None)
+    }
+
+    /// Constructs a new [`TypeGenericPack`] with the given name and default [`TypePack`].
+    pub fn defaulted<T: AsRef<str>>(name: T, default: TypePack) -> Self {
+        Self::new(name, Some(default))
+    }
+}
+
+impl TypeGenericPack {
+    /// Returns the name of this [`TypeGenericPack`], without the trailing `...`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the default [`TypePack`] of this [`TypeGenericPack`].
+    pub fn default(&self) -> Option<&TypePack> {
+        self.default.as_ref()
+    }
+
+    /// Returns a mutable reference to the default [`TypePack`] of this [`TypeGenericPack`].
+    pub fn default_mut(&mut self) -> Option<&mut TypePack> {
+        self.default.as_mut()
+    }
+
+    /// Consumes this [`TypeGenericPack`], returning its name and default [`TypePack`].
+    pub fn into_parts(self) -> (String, Option<TypePack>) {
+        (self.name, self.default)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, From)]
+pub enum TypeGenericParameter {
+    Type(TypeGeneric),
+    Pack(TypeGenericPack),
+}
+
+impl TypeGenericParameter {
+    /// Returns the name of this [`TypeGenericParameter`].
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Type(generic) => generic.name(),
+            Self::Pack(pack) => pack.name(),
+        }
+    }
+}
@@ -1,29 +1,49 @@
 use derive_more::From;
 
+use crate::ast::Expression;
+
 pub mod built_in;
 pub mod combination;
 pub mod function;
+pub mod generic;
 pub mod optional;
+pub mod pack;
 pub mod primitive;
+pub mod reference;
 pub mod singleton;
 pub mod table;
 
 pub use built_in::TypeBuiltIn;
 pub use combination::TypeCombination;
-pub use function::{TypeArgument, TypeFunction};
+pub use function::{TypeArgument, TypeFunction, TypeFunctionBuilder};
+pub use generic::{TypeGeneric, TypeGenericPack, TypeGenericParameter};
 pub use optional::TypeOptional;
+pub use pack::{TypePack, TypePackTail};
 pub use primitive::TypePrimitive;
+pub use reference::{TypeReference, TypeReferenceArgument};
 pub use singleton::TypeSingleton;
-pub use table::{TypeArray, TypeTable, TypeTableEntry, TypeTableIndexer};
+pub use table::{
+    TypeArray, TypeTable, TypeTableAccess, TypeTableBuilder, TypeTableEntry, TypeTableIndexer,
+};
 
 #[derive(Debug, Clone, PartialEq, From)]
 pub enum TypeAnnotation {
     BuiltIn(TypeBuiltIn),
     Primitive(TypePrimitive),
+    Reference(TypeReference),
     Function(TypeFunction),
     Table(TypeTable),
     Array(TypeArray),
     Singleton(TypeSingleton),
     Optional(TypeOptional),
     Combination(TypeCombination),
+    #[from(ignore)]
+    Typeof(Expression),
+}
+
+impl TypeAnnotation {
+    /// Constructs a new `TypeAnnotation::Typeof` with the given [`Expression`].
+    pub fn typeof_expression<T: Into<Expression>>(expression: T) -> Self {
+        Self::Typeof(expression.into())
+    }
 }
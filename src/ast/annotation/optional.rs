@@ -17,4 +17,14 @@ impl TypeOptional {
     pub fn annotation(&self) -> &TypeAnnotation {
         &self.annotation
     }
+
+    /// Returns a mutable reference to the [`TypeAnnotation`] of this [`Optional`].
+    pub fn annotation_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.annotation
+    }
+
+    /// Consumes this [`Optional`], returning its [`TypeAnnotation`].
+    pub fn into_annotation(self) -> TypeAnnotation {
+        *self.annotation
+    }
 }
@@ -0,0 +1,92 @@
+use crate::ast::TypeAnnotation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypePackTail {
+    /// A variadic type pack, such as `...number`.
+    Variadic(Box<TypeAnnotation>),
+
+    /// A generic type pack, such as `T...`.
+    Generic(String),
+}
+
+impl TypePackTail {
+    /// Constructs a new `TypePackTail::Variadic` with the given [`TypeAnnotation`].
+    pub fn variadic<T: Into<TypeAnnotation>>(annotation: T) -> Self {
+        Self::Variadic(Box::new(annotation.into()))
+    }
+
+    /// Constructs a new `TypePackTail::Generic` with the given name.
+    pub fn generic<T: AsRef<str>>(name: T) -> Self {
+        Self::Generic(name.as_ref().to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypePack {
+    types: Vec<TypeAnnotation>,
+    tail: Option<TypePackTail>,
+}
+
+impl TypePack {
+    /// Constructs a new [`TypePack`] with the given [`TypeAnnotation`]s and optional [`TypePackTail`].
+    pub fn new(types: Vec<TypeAnnotation>, tail: Option<TypePackTail>) -> Self {
+        Self { types, tail }
+    }
+
+    /// Constructs a new empty [`TypePack`].
+    pub fn empty() -> Self {
+        Self::new(Vec::new(), None)
+    }
+
+    /// Constructs a new [`TypePack`] with the given [`TypeAnnotation`]s and no tail.
+    pub fn list(types: Vec<TypeAnnotation>) -> Self {
+        Self::new(types, None)
+    }
+
+    /// Constructs a new [`TypePack`] consisting only of the given [`TypePackTail`].
+    pub fn tail(tail: TypePackTail) -> Self {
+        Self::new(Vec::new(), Some(tail))
+    }
+
+    /// Constructs a new [`TypePack`] consisting only of a variadic tail, such as `...number`.
+    pub fn variadic<T: Into<TypeAnnotation>>(annotation: T) -> Self {
+        Self::tail(TypePackTail::variadic(annotation))
+    }
+
+    /// Constructs a new [`TypePack`] consisting only of a generic tail, such as `T...`.
+    pub fn generic<T: AsRef<str>>(name: T) -> Self {
+        Self::tail(TypePackTail::generic(name))
+    }
+}
+
+impl TypePack {
+    /// Returns the [`TypeAnnotation`]s of this [`TypePack`].
+    pub fn types(&self) -> &Vec<TypeAnnotation> {
+        &self.types
+    }
+
+    /// Returns a mutable reference to the [`TypeAnnotation`]s of this [`TypePack`].
+    pub fn types_mut(&mut self) -> &mut Vec<TypeAnnotation> {
+        &mut self.types
+    }
+
+    /// Returns the [`TypePackTail`] of this [`TypePack`].
+    pub fn pack_tail(&self) -> Option<&TypePackTail> {
+        self.tail.as_ref()
+    }
+
+    /// Returns a mutable reference to the [`TypePackTail`] of this [`TypePack`].
+    pub fn pack_tail_mut(&mut self) -> Option<&mut TypePackTail> {
+        self.tail.as_mut()
+    }
+
+    /// Consumes this [`TypePack`], returning its [`TypeAnnotation`]s and [`TypePackTail`].
+    pub fn into_parts(self) -> (Vec<TypeAnnotation>, Option<TypePackTail>) {
+        (self.types, self.tail)
+    }
+
+    /// Returns whether this [`TypePack`] consists only of a tail, such as `T...`.
+    pub fn is_bare_tail(&self) -> bool {
+        self.types.is_empty() && self.tail.is_some()
+    }
+}
@@ -5,7 +5,9 @@ pub enum TypePrimitive {
     Number,
     String,
     Thread,
-    // Userdata,
+    Userdata,
+    Buffer,
+    Vector,
 }
 
 impl TypePrimitive {
@@ -19,6 +21,11 @@ impl TypePrimitive {
         Self::Nil
     }
 
+    /// Constructs a new `TypePrimitive::Number`.
+    pub fn number() -> Self {
+        Self::Number
+    }
+
     /// Constructs a new `TypePrimitive::Boolean`.
     pub fn boolean() -> Self {
         Self::Boolean
@@ -34,10 +41,20 @@ impl TypePrimitive {
         Self::Thread
     }
 
-    // /// Constructs a new `TypePrimitive::Userdata`.
-    // pub fn userdata() -> Self {
-    //     Self::Userdata
-    // }
+    /// Constructs a new `TypePrimitive::Userdata`.
+    pub fn userdata() -> Self {
+        Self::Userdata
+    }
+
+    /// Constructs a new `TypePrimitive::Buffer`.
+    pub fn buffer() -> Self {
+        Self::Buffer
+    }
+
+    /// Constructs a new `TypePrimitive::Vector`.
+    pub fn vector() -> Self {
+        Self::Vector
+    }
 }
 
 impl TypePrimitive {
@@ -48,7 +65,9 @@ impl TypePrimitive {
             Self::Number => "number",
             Self::String => "string",
             Self::Thread => "thread",
-            // Self::Userdata => "userdata",
+            Self::Userdata => "userdata",
+            Self::Buffer => "buffer",
+            Self::Vector => "vector",
         }
     }
 }
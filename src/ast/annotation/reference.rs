@@ -0,0 +1,86 @@
+use derive_more::From;
+
+use crate::ast::{TypeAnnotation, TypePack};
+
+#[derive(Debug, Clone, PartialEq, From)]
+pub enum TypeReferenceArgument {
+    Type(TypeAnnotation),
+    Pack(TypePack),
+}
+
+impl TypeReferenceArgument {
+    /// Constructs a new `TypeReferenceArgument::Type` with the given [`TypeAnnotation`].
+    pub fn annotation<T: Into<TypeAnnotation>>(annotation: T) -> Self {
+        Self::Type(annotation.into())
+    }
+
+    /// Constructs a new `TypeReferenceArgument::Pack` with the given [`TypePack`].
+    pub fn pack(pack: TypePack) -> Self {
+        Self::Pack(pack)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeReference {
+    module: Option<String>,
+    name: String,
+    arguments: Vec<TypeReferenceArgument>,
+}
+
+impl TypeReference {
+    /// Constructs a new [`TypeReference`] with the given module, name and [`TypeReferenceArgument`]s.
+    pub fn new<T: AsRef<str>>(
+        module: Option<String>,
+        name: T,
+        arguments: Vec<TypeReferenceArgument>,
+    ) -> Self {
+        Self {
+            module,
+            name: name.as_ref().to_string(),
+            arguments,
+        }
+    }
+
+    /// Constructs a new [`TypeReference`] to the given name, such as `Foo`.
+    pub fn named<T: AsRef<str>>(name: T) -> Self {
+        Self::new(None, name, Vec::new())
+    }
+
+    /// Constructs a new [`TypeReference`] to the given name inside the given module, such as `Module.Foo`.
+    pub fn qualified<M: AsRef<str>, T: AsRef<str>>(module: M, name: T) -> Self {
+        Self::new(Some(module.as_ref().to_string()), name, Vec::new())
+    }
+
+    /// Constructs a new [`TypeReference`] to the given name instantiated with the given
+    /// [`TypeReferenceArgument`]s, such as `Map<string, number>`.
+    pub fn generic<T: AsRef<str>>(name: T, arguments: Vec<TypeReferenceArgument>) -> Self {
+        Self::new(None, name, arguments)
+    }
+}
+
+impl TypeReference {
+    /// Returns the module prefix of this [`TypeReference`].
+    pub fn module(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    /// Returns the name of this [`TypeReference`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`TypeReferenceArgument`]s of this [`TypeReference`].
+    pub fn arguments(&self) -> &Vec<TypeReferenceArgument> {
+        &self.arguments
+    }
+
+    /// Returns a mutable reference to the [`TypeReferenceArgument`]s of this [`TypeReference`].
+    pub fn arguments_mut(&mut self) -> &mut Vec<TypeReferenceArgument> {
+        &mut self.arguments
+    }
+
+    /// Consumes this [`TypeReference`], returning its module, name and [`TypeReferenceArgument`]s.
+    pub fn into_parts(self) -> (Option<String>, String, Vec<TypeReferenceArgument>) {
+        (self.module, self.name, self.arguments)
+    }
+}
@@ -17,22 +17,84 @@ impl TypeTableIndexer {
         &self.index
     }
 
+    /// Returns a mutable reference to the index of this `TypeTableIndexer`.
+    pub fn index_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.index
+    }
+
     /// Returns the value of this `TypeTableIndexer`.
     pub fn value(&self) -> &TypeAnnotation {
         &self.value
     }
+
+    /// Returns a mutable reference to the value of this `TypeTableIndexer`.
+    pub fn value_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.value
+    }
+
+    /// Consumes this `TypeTableIndexer`, returning its index and value.
+    pub fn into_parts(self) -> (TypeAnnotation, TypeAnnotation) {
+        (self.index, self.value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TypeTableAccess {
+    #[default]
+    ReadWrite,
+    Read,
+    Write,
+}
+
+impl TypeTableAccess {
+    /// Returns the textual format for the property modifier, which is empty for `TypeTableAccess::ReadWrite`.
+    pub fn text(&self) -> &'static str {
+        match self {
+            Self::ReadWrite => "",
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeTableEntry {
+    access: TypeTableAccess,
     index: String,
     value: TypeAnnotation,
 }
 
 impl TypeTableEntry {
     /// Construct a new `TypeTableEntry` with the given `index` and `value`.
+    ///
+    /// The `index` is the name of the property, and does not need to be a valid identifier.
+    /// Properties such as `["my-key"]: number` are constructed with an `index` of `my-key`.
     pub fn new(index: String, value: TypeAnnotation) -> Self {
-        Self { index, value }
+        Self::with_access(TypeTableAccess::ReadWrite, index, value)
+    }
+
+    /// Construct a new `TypeTableEntry` with the given `access`, `index` and `value`.
+    pub fn with_access(access: TypeTableAccess, index: String, value: TypeAnnotation) -> Self {
+        Self {
+            access,
+            index,
+            value,
+        }
+    }
+
+    /// Construct a new read-only `TypeTableEntry` with the given `index` and `value`.
+    pub fn read(index: String, value: TypeAnnotation) -> Self {
+        Self::with_access(TypeTableAccess::Read, index, value)
+    }
+
+    /// Construct a new write-only `TypeTableEntry` with the given `index` and `value`.
+    pub fn write(index: String, value: TypeAnnotation) -> Self {
+        Self::with_access(TypeTableAccess::Write, index, value)
+    }
+
+    /// Returns the property modifier of this `TypeTableEntry`.
+    pub fn access(&self) -> TypeTableAccess {
+        self.access
     }
 
     /// Returns the index of this `TypeTableEntry`.
@@ -44,6 +106,16 @@ impl TypeTableEntry {
     pub fn value(&self) -> &TypeAnnotation {
         &self.value
     }
+
+    /// Returns a mutable reference to the value of this `TypeTableEntry`.
+    pub fn value_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.value
+    }
+
+    /// Consumes this `TypeTableEntry`, returning its access, index and value.
+    pub fn into_parts(self) -> (TypeTableAccess, String, TypeAnnotation) {
+        (self.access, self.index, self.value)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -54,8 +126,32 @@ pub struct TypeTable {
 
 impl TypeTable {
     /// Construct a new `Table` with the given `entries` and `indexer`.
-    pub fn new(entries: Vec<TypeTableEntry>, indexer: Option<Box<TypeTableIndexer>>) -> Self {
-        Self { entries, indexer }
+    pub fn new(entries: Vec<TypeTableEntry>, indexer: Option<TypeTableIndexer>) -> Self {
+        Self {
+            entries,
+            indexer: indexer.map(Box::new),
+        }
+    }
+
+    /// Construct a new `TypeTableBuilder` for an empty table.
+    /// ```
+    /// use luna::ast::{TypePrimitive, TypeTable};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let table = TypeTable::builder()
+    ///     .field("name", TypePrimitive::string())
+    ///     .read_field("id", TypePrimitive::number())
+    ///     .indexer(TypePrimitive::string(), TypePrimitive::boolean())
+    ///     .build();
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(
+    ///     table.format_string(&settings),
+    ///     "{ name: string, read id: number, [string]: boolean }"
+    /// );
+    /// ```
+    pub fn builder() -> TypeTableBuilder {
+        TypeTableBuilder::default()
     }
 
     /// Returns the entries of this `Table`.
@@ -63,10 +159,88 @@ impl TypeTable {
         &self.entries
     }
 
+    /// Returns a mutable reference to the entries of this `Table`.
+    pub fn entries_mut(&mut self) -> &mut Vec<TypeTableEntry> {
+        &mut self.entries
+    }
+
     /// Returns the indexer of this `Table`.
     pub fn indexer(&self) -> &Option<Box<TypeTableIndexer>> {
         &self.indexer
     }
+
+    /// Returns a mutable reference to the indexer of this `Table`.
+    pub fn indexer_mut(&mut self) -> Option<&mut TypeTableIndexer> {
+        self.indexer.as_deref_mut()
+    }
+
+    /// Consumes this `Table`, returning its entries and indexer.
+    pub fn into_parts(self) -> (Vec<TypeTableEntry>, Option<TypeTableIndexer>) {
+        (self.entries, self.indexer.map(|indexer| *indexer))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TypeTableBuilder {
+    entries: Vec<TypeTableEntry>,
+    indexer: Option<TypeTableIndexer>,
+}
+
+impl TypeTableBuilder {
+    /// Adds an entry with the given `access`, `index` and `value`.
+    pub fn entry<I: AsRef<str>, V: Into<TypeAnnotation>>(
+        mut self,
+        access: TypeTableAccess,
+        index: I,
+        value: V,
+    ) -> Self {
+        let index = index.as_ref().to_string();
+        self.entries
+            .push(TypeTableEntry::with_access(access, index, value.into()));
+        self
+    }
+
+    /// Adds an entry with the given `index` and `value`.
+    pub fn field<I: AsRef<str>, V: Into<TypeAnnotation>>(self, index: I, value: V) -> Self {
+        self.entry(TypeTableAccess::ReadWrite, index, value)
+    }
+
+    /// Adds a read-only entry with the given `index` and `value`.
+    pub fn read_field<I: AsRef<str>, V: Into<TypeAnnotation>>(self, index: I, value: V) -> Self {
+        self.entry(TypeTableAccess::Read, index, value)
+    }
+
+    /// Adds a write-only entry with the given `index` and `value`.
+    pub fn write_field<I: AsRef<str>, V: Into<TypeAnnotation>>(self, index: I, value: V) -> Self {
+        self.entry(TypeTableAccess::Write, index, value)
+    }
+
+    /// Sets the indexer to the given `index` and `value`.
+    pub fn indexer<I: Into<TypeAnnotation>, V: Into<TypeAnnotation>>(
+        mut self,
+        index: I,
+        value: V,
+    ) -> Self {
+        self.indexer = Some(TypeTableIndexer::new(index.into(), value.into()));
+        self
+    }
+
+    /// Constructs the `TypeTable`.
+    pub fn build(self) -> TypeTable {
+        TypeTable::new(self.entries, self.indexer)
+    }
+}
+
+impl From<TypeTableBuilder> for TypeTable {
+    fn from(builder: TypeTableBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<TypeTableBuilder> for TypeAnnotation {
+    fn from(builder: TypeTableBuilder) -> Self {
+        builder.build().into()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -82,4 +256,14 @@ impl TypeArray {
     pub fn item(&self) -> &Option<Box<TypeAnnotation>> {
         &self.item
     }
+
+    /// Returns a mutable reference to the item of this `TypeArray`.
+    pub fn item_mut(&mut self) -> Option<&mut TypeAnnotation> {
+        self.item.as_deref_mut()
+    }
+
+    /// Consumes this `TypeArray`, returning its item.
+    pub fn into_item(self) -> Option<Box<TypeAnnotation>> {
+        self.item
+    }
 }
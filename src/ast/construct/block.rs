@@ -0,0 +1,89 @@
+use crate::ast::{Comment, Statement, Trivia};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Block {
+    statements: Vec<Statement>,
+    trivia: Vec<Trivia>,
+    dangling_comments: Vec<Comment>,
+}
+
+impl Block {
+    /// Constructs a new [`Block`] with the given [`Statement`]s.
+    pub fn new(statements: Vec<Statement>) -> Self {
+        Self {
+            statements,
+            trivia: Vec::new(),
+            dangling_comments: Vec::new(),
+        }
+    }
+
+    /// Constructs a new [`Block`] with the given [`Statement`]s, the [`Trivia`] of each of them,
+    /// by index, and the [`Comment`]s after the last of them.
+    pub fn with_comments(
+        statements: Vec<Statement>,
+        trivia: Vec<Trivia>,
+        dangling_comments: Vec<Comment>,
+    ) -> Self {
+        Self {
+            statements,
+            trivia,
+            dangling_comments,
+        }
+    }
+
+    /// Constructs a new empty [`Block`].
+    pub fn empty() -> Self {
+        Self::default()
+    }
+}
+
+impl Block {
+    /// Returns the [`Statement`]s of this [`Block`].
+    pub fn statements(&self) -> &Vec<Statement> {
+        &self.statements
+    }
+
+    /// Returns a mutable reference to the [`Statement`]s of this [`Block`].
+    pub fn statements_mut(&mut self) -> &mut Vec<Statement> {
+        &mut self.statements
+    }
+
+    /// Returns the [`Trivia`] of the [`Statement`]s of this [`Block`], by index. Statements past
+    /// its end have no comments.
+    pub fn trivia(&self) -> &Vec<Trivia> {
+        &self.trivia
+    }
+
+    /// Returns a mutable reference to the [`Trivia`] of the [`Statement`]s of this [`Block`].
+    pub fn trivia_mut(&mut self) -> &mut Vec<Trivia> {
+        &mut self.trivia
+    }
+
+    /// Returns the [`Comment`]s after the last [`Statement`] of this [`Block`], or within it when
+    /// it is empty.
+    pub fn dangling_comments(&self) -> &Vec<Comment> {
+        &self.dangling_comments
+    }
+
+    /// Consumes this [`Block`], returning its [`Statement`]s.
+    pub fn into_statements(self) -> Vec<Statement> {
+        self.statements
+    }
+
+    /// Consumes this [`Block`], returning its [`Statement`]s, their [`Trivia`] and its dangling
+    /// [`Comment`]s.
+    pub fn into_parts(self) -> (Vec<Statement>, Vec<Trivia>, Vec<Comment>) {
+        (self.statements, self.trivia, self.dangling_comments)
+    }
+
+    /// Returns whether this [`Block`] has no [`Statement`]s.
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+}
+
+impl<T: Into<Statement>> FromIterator<T> for Block {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,152 @@
+use crate::parser::Span;
+
+/// A comment, as written in the source, such as `-- note` or `--[[ note ]]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    text: String,
+    blank_lines: usize,
+}
+
+impl Comment {
+    /// Constructs a new [`Comment`] with the given text, including its leading `--`.
+    pub fn new<T: AsRef<str>>(text: T) -> Self {
+        Self::with_blank_lines(text, 0)
+    }
+
+    /// Constructs a new [`Comment`] with the given text, preceded by the given number of blank
+    /// lines when it is on its own line.
+    pub fn with_blank_lines<T: AsRef<str>>(text: T, blank_lines: usize) -> Self {
+        Self {
+            text: text.as_ref().to_string(),
+            blank_lines,
+        }
+    }
+}
+
+impl Comment {
+    /// Returns the text of this [`Comment`], including its leading `--`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the number of blank lines before this [`Comment`], when it is on its own line.
+    pub fn blank_lines(&self) -> usize {
+        self.blank_lines
+    }
+
+    /// Returns whether this [`Comment`] runs to the end of its line, rather than being closed by
+    /// a long bracket.
+    pub fn is_line(&self) -> bool {
+        let opening = self
+            .text
+            .strip_prefix("--[")
+            .map(|rest| rest.trim_start_matches('='));
+
+        !matches!(opening, Some(rest) if rest.starts_with('['))
+    }
+
+    /// Returns the formatter directive of this [`Comment`], such as `ignore` for
+    /// `-- luna: ignore`.
+    /// ```
+    /// use luna::ast::Comment;
+    ///
+    /// assert_eq!(Comment::new("-- luna: ignore-start").directive(), Some("ignore-start"));
+    /// assert_eq!(Comment::new("-- ignore").directive(), None);
+    /// ```
+    pub fn directive(&self) -> Option<&str> {
+        if !self.is_line() {
+            return None;
+        }
+
+        let text = self.text.strip_prefix("--")?.trim();
+        text.strip_prefix("luna:").map(str::trim)
+    }
+}
+
+/// The comments attached to a statement, a table field or an item of a list, such as an argument:
+/// those before it, and those after it on its last line. A statement the formatter is told to
+/// ignore also keeps its source text, and a parsed statement keeps its span.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Trivia {
+    leading: Vec<Comment>,
+    trailing: Vec<Comment>,
+    blank_lines: usize,
+    verbatim: Option<String>,
+    span: Option<Span>,
+}
+
+impl Trivia {
+    /// Constructs a new [`Trivia`] with the given leading and trailing [`Comment`]s.
+    pub fn new(leading: Vec<Comment>, trailing: Vec<Comment>) -> Self {
+        Self::with_blank_lines(leading, trailing, 0)
+    }
+
+    /// Constructs a new [`Trivia`] with the given leading and trailing [`Comment`]s, where its
+    /// node is preceded by the given number of blank lines, after its leading comments.
+    pub fn with_blank_lines(
+        leading: Vec<Comment>,
+        trailing: Vec<Comment>,
+        blank_lines: usize,
+    ) -> Self {
+        Self {
+            leading,
+            trailing,
+            blank_lines,
+            verbatim: None,
+            span: None,
+        }
+    }
+
+    /// Returns this [`Trivia`], keeping the given source text of its node to be formatted as
+    /// written.
+    pub fn with_verbatim<T: AsRef<str>>(mut self, text: T) -> Self {
+        self.verbatim = Some(text.as_ref().to_string());
+        self
+    }
+
+    /// Returns this [`Trivia`], recording the [`Span`] of its node in the source it was parsed from.
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Constructs a new [`Trivia`] without any [`Comment`].
+    pub fn empty() -> Self {
+        Self::default()
+    }
+}
+
+impl Trivia {
+    /// Returns the [`Comment`]s before the node of this [`Trivia`], on their own lines unless it is
+    /// an item of a list.
+    pub fn leading(&self) -> &Vec<Comment> {
+        &self.leading
+    }
+
+    /// Returns the [`Comment`]s after the node of this [`Trivia`], on its last line.
+    pub fn trailing(&self) -> &Vec<Comment> {
+        &self.trailing
+    }
+
+    /// Returns the number of blank lines before the node of this [`Trivia`], after its leading
+    /// comments.
+    pub fn blank_lines(&self) -> usize {
+        self.blank_lines
+    }
+
+    /// Returns the source text of the node of this [`Trivia`], when it is formatted as written.
+    pub fn verbatim(&self) -> Option<&str> {
+        self.verbatim.as_deref()
+    }
+
+    /// Returns the [`Span`] of the node of this [`Trivia`], from its first token to its last, when
+    /// it was parsed.
+    pub fn span(&self) -> Option<&Span> {
+        self.span.as_ref()
+    }
+
+    /// Returns whether this [`Trivia`] has no [`Comment`].
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty()
+    }
+}
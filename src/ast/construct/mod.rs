@@ -1,5 +1,9 @@
 pub mod binding;
+pub mod block;
+pub mod comment;
 pub mod operator;
 
 pub use binding::Binding;
-pub use operator::{BinaryOperator, TypeOperator, UnaryOperator};
+pub use block::Block;
+pub use comment::{Comment, Trivia};
+pub use operator::{BinaryOperator, CompoundAssignmentOperator, TypeOperator, UnaryOperator};
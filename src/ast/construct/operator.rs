@@ -47,9 +47,13 @@ pub enum BinaryOperator {
     Subtract,
     Multiply,
     Divide,
+    FloorDivide,
     Modulo,
     Power,
 
+    // Concatenation
+    Concat,
+
     // Logical
     And,
     Or,
@@ -86,6 +90,11 @@ impl BinaryOperator {
         Self::Divide
     }
 
+    /// Constructs a new `BinaryOperator::FloorDivide`.
+    pub fn floor_divide() -> Self {
+        Self::FloorDivide
+    }
+
     /// Constructs a new `BinaryOperator::Modulo`.
     pub fn modulo() -> Self {
         Self::Modulo
@@ -96,6 +105,11 @@ impl BinaryOperator {
         Self::Power
     }
 
+    /// Constructs a new `BinaryOperator::Concat`.
+    pub fn concat() -> Self {
+        Self::Concat
+    }
+
     /// Constructs a new `BinaryOperator::And`.
     pub fn and() -> Self {
         Self::And
@@ -154,9 +168,13 @@ impl BinaryOperator {
             Self::Subtract => "-",
             Self::Multiply => "*",
             Self::Divide => "/",
+            Self::FloorDivide => "//",
             Self::Modulo => "%",
             Self::Power => "^",
 
+            // Concatenation
+            Self::Concat => "..",
+
             // Logical
             Self::And => "and",
             Self::Or => "or",
@@ -174,13 +192,16 @@ impl BinaryOperator {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CompoundAssignmentOperator {
     Add,
     Subtract,
     Multiply,
     Divide,
+    FloorDivide,
     Modulo,
     Power,
+    Concat,
 }
 
 impl CompoundAssignmentOperator {
@@ -190,8 +211,29 @@ impl CompoundAssignmentOperator {
             Self::Subtract => "-=",
             Self::Multiply => "*=",
             Self::Divide => "/=",
+            Self::FloorDivide => "//=",
             Self::Modulo => "%=",
             Self::Power => "^=",
+            Self::Concat => "..=",
+        }
+    }
+
+    /// Returns the [`BinaryOperator`] that this operator applies before assigning.
+    /// ```
+    /// use luna::ast::{BinaryOperator, CompoundAssignmentOperator};
+    ///
+    /// assert_eq!(CompoundAssignmentOperator::Concat.binary_operator(), BinaryOperator::Concat);
+    /// ```
+    pub fn binary_operator(&self) -> BinaryOperator {
+        match &self {
+            Self::Add => BinaryOperator::Add,
+            Self::Subtract => BinaryOperator::Subtract,
+            Self::Multiply => BinaryOperator::Multiply,
+            Self::Divide => BinaryOperator::Divide,
+            Self::FloorDivide => BinaryOperator::FloorDivide,
+            Self::Modulo => BinaryOperator::Modulo,
+            Self::Power => BinaryOperator::Power,
+            Self::Concat => BinaryOperator::Concat,
         }
     }
 }
@@ -0,0 +1,119 @@
+use crate::ast::{Comment, Expression, Trivia};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionCall {
+    function: Box<Expression>,
+    method: Option<String>,
+    arguments: Vec<Expression>,
+    trivia: Vec<Trivia>,
+    dangling_comments: Vec<Comment>,
+}
+
+impl FunctionCall {
+    /// Constructs a new [`FunctionCall`] of the given [`Expression`], with an optional method name and arguments.
+    pub fn new<T: Into<Expression>>(
+        function: T,
+        method: Option<String>,
+        arguments: Vec<Expression>,
+    ) -> Self {
+        Self::with_comments(function, method, arguments, Vec::new(), Vec::new())
+    }
+
+    /// Constructs a new [`FunctionCall`] of the given [`Expression`], with an optional method name,
+    /// arguments, the [`Trivia`] of each of them, by index, and the [`Comment`]s after the last of
+    /// them.
+    pub fn with_comments<T: Into<Expression>>(
+        function: T,
+        method: Option<String>,
+        arguments: Vec<Expression>,
+        trivia: Vec<Trivia>,
+        dangling_comments: Vec<Comment>,
+    ) -> Self {
+        Self {
+            function: Box::new(function.into()),
+            method,
+            arguments,
+            trivia,
+            dangling_comments,
+        }
+    }
+
+    /// Constructs a new [`FunctionCall`], such as `f(a, b)`.
+    pub fn call<T: Into<Expression>>(function: T, arguments: Vec<Expression>) -> Self {
+        Self::new(function, None, arguments)
+    }
+
+    /// Constructs a new method [`FunctionCall`], such as `object:method(a, b)`.
+    pub fn method<T: Into<Expression>, N: AsRef<str>>(
+        object: T,
+        method: N,
+        arguments: Vec<Expression>,
+    ) -> Self {
+        Self::new(object, Some(method.as_ref().to_string()), arguments)
+    }
+}
+
+impl FunctionCall {
+    /// Returns the called [`Expression`] of this [`FunctionCall`], which is the object for method calls.
+    pub fn function(&self) -> &Expression {
+        &self.function
+    }
+
+    /// Returns a mutable reference to the called [`Expression`] of this [`FunctionCall`].
+    pub fn function_mut(&mut self) -> &mut Expression {
+        &mut self.function
+    }
+
+    /// Returns the method name of this [`FunctionCall`].
+    pub fn method_name(&self) -> Option<&str> {
+        self.method.as_deref()
+    }
+
+    /// Returns the argument [`Expression`]s of this [`FunctionCall`].
+    pub fn arguments(&self) -> &Vec<Expression> {
+        &self.arguments
+    }
+
+    /// Returns a mutable reference to the argument [`Expression`]s of this [`FunctionCall`].
+    pub fn arguments_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.arguments
+    }
+
+    /// Returns the [`Trivia`] of the arguments of this [`FunctionCall`], by index. Arguments past
+    /// its end have no comments.
+    pub fn trivia(&self) -> &Vec<Trivia> {
+        &self.trivia
+    }
+
+    /// Returns the [`Comment`]s after the last argument of this [`FunctionCall`], or within its
+    /// parentheses when it has none.
+    pub fn dangling_comments(&self) -> &Vec<Comment> {
+        &self.dangling_comments
+    }
+
+    /// Returns whether this [`FunctionCall`] has any [`Comment`] among its arguments.
+    pub fn has_comments(&self) -> bool {
+        !self.dangling_comments.is_empty() || self.trivia.iter().any(|trivia| !trivia.is_empty())
+    }
+
+    /// Consumes this [`FunctionCall`], returning its called [`Expression`], method name,
+    /// arguments, their [`Trivia`] and its dangling [`Comment`]s.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Expression,
+        Option<String>,
+        Vec<Expression>,
+        Vec<Trivia>,
+        Vec<Comment>,
+    ) {
+        (
+            *self.function,
+            self.method,
+            self.arguments,
+            self.trivia,
+            self.dangling_comments,
+        )
+    }
+}
@@ -0,0 +1,211 @@
+use crate::ast::{Binding, Block, Comment, Trivia, TypeGenericParameter, TypePack, TypePackTail};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionVariadic {
+    annotation: Option<TypePackTail>,
+}
+
+impl FunctionVariadic {
+    /// Constructs a new [`FunctionVariadic`] with the given optional [`TypePackTail`].
+    ///
+    /// `...: number` is annotated with `TypePackTail::Variadic`, and `...: T...` with `TypePackTail::Generic`.
+    pub fn new(annotation: Option<TypePackTail>) -> Self {
+        Self { annotation }
+    }
+
+    /// Constructs a new unannotated [`FunctionVariadic`].
+    pub fn unannotated() -> Self {
+        Self::new(None)
+    }
+}
+
+impl FunctionVariadic {
+    /// Returns the [`TypePackTail`] annotation of this [`FunctionVariadic`].
+    pub fn annotation(&self) -> Option<&TypePackTail> {
+        self.annotation.as_ref()
+    }
+
+    /// Returns a mutable reference to the [`TypePackTail`] annotation of this [`FunctionVariadic`].
+    pub fn annotation_mut(&mut self) -> Option<&mut TypePackTail> {
+        self.annotation.as_mut()
+    }
+
+    /// Consumes this [`FunctionVariadic`], returning its [`TypePackTail`] annotation.
+    pub fn into_annotation(self) -> Option<TypePackTail> {
+        self.annotation
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    generics: Vec<TypeGenericParameter>,
+    parameters: Vec<Binding>,
+    variadic: Option<FunctionVariadic>,
+    returns: Option<TypePack>,
+    block: Block,
+    /// The [`Trivia`] of the parameters and the [`Comment`]s after them, boxed as few functions
+    /// have any.
+    parameter_comments: Option<Box<(Vec<Trivia>, Vec<Comment>)>>,
+}
+
+impl Function {
+    /// Constructs a new [`Function`] with the given [`TypeGenericParameter`]s, parameter [`Binding`]s,
+    /// optional [`FunctionVariadic`], optional return [`TypePack`] and [`Block`].
+    pub fn new(
+        generics: Vec<TypeGenericParameter>,
+        parameters: Vec<Binding>,
+        variadic: Option<FunctionVariadic>,
+        returns: Option<TypePack>,
+        block: Block,
+    ) -> Self {
+        Self::with_comments(
+            generics,
+            parameters,
+            variadic,
+            returns,
+            block,
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    /// Constructs a new [`Function`] with the given [`TypeGenericParameter`]s, parameter
+    /// [`Binding`]s, optional [`FunctionVariadic`], optional return [`TypePack`] and [`Block`],
+    /// along with the [`Trivia`] of each parameter, by index with the variadic parameter last,
+    /// and the [`Comment`]s after the last parameter.
+    pub fn with_comments(
+        generics: Vec<TypeGenericParameter>,
+        parameters: Vec<Binding>,
+        variadic: Option<FunctionVariadic>,
+        returns: Option<TypePack>,
+        block: Block,
+        parameter_trivia: Vec<Trivia>,
+        parameter_comments: Vec<Comment>,
+    ) -> Self {
+        let has_comments =
+            !parameter_trivia.iter().all(Trivia::is_empty) || !parameter_comments.is_empty();
+
+        Self {
+            generics,
+            parameters,
+            variadic,
+            returns,
+            block,
+            parameter_comments: match has_comments {
+                true => Some(Box::new((parameter_trivia, parameter_comments))),
+                false => None,
+            },
+        }
+    }
+
+    /// Constructs a new [`Function`] with the given parameter [`Binding`]s and [`Block`].
+    pub fn simple(parameters: Vec<Binding>, block: Block) -> Self {
+        Self::new(Vec::new(), parameters, None, None, block)
+    }
+}
+
+impl Function {
+    /// Returns the [`TypeGenericParameter`]s of this [`Function`].
+    pub fn generics(&self) -> &Vec<TypeGenericParameter> {
+        &self.generics
+    }
+
+    /// Returns a mutable reference to the [`TypeGenericParameter`]s of this [`Function`].
+    pub fn generics_mut(&mut self) -> &mut Vec<TypeGenericParameter> {
+        &mut self.generics
+    }
+
+    /// Returns the parameter [`Binding`]s of this [`Function`].
+    pub fn parameters(&self) -> &Vec<Binding> {
+        &self.parameters
+    }
+
+    /// Returns a mutable reference to the parameter [`Binding`]s of this [`Function`].
+    pub fn parameters_mut(&mut self) -> &mut Vec<Binding> {
+        &mut self.parameters
+    }
+
+    /// Returns the [`FunctionVariadic`] of this [`Function`].
+    pub fn variadic(&self) -> Option<&FunctionVariadic> {
+        self.variadic.as_ref()
+    }
+
+    /// Returns a mutable reference to the [`FunctionVariadic`] of this [`Function`].
+    pub fn variadic_mut(&mut self) -> Option<&mut FunctionVariadic> {
+        self.variadic.as_mut()
+    }
+
+    /// Returns the return [`TypePack`] of this [`Function`].
+    pub fn returns(&self) -> Option<&TypePack> {
+        self.returns.as_ref()
+    }
+
+    /// Returns a mutable reference to the return [`TypePack`] of this [`Function`].
+    pub fn returns_mut(&mut self) -> Option<&mut TypePack> {
+        self.returns.as_mut()
+    }
+
+    /// Returns the [`Block`] of this [`Function`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`Function`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Returns the [`Trivia`] of the parameters of this [`Function`], by index with the variadic
+    /// parameter last. Parameters past its end have no comments.
+    pub fn parameter_trivia(&self) -> &[Trivia] {
+        match &self.parameter_comments {
+            Some(comments) => &comments.0,
+            None => &[],
+        }
+    }
+
+    /// Returns the [`Comment`]s after the last parameter of this [`Function`], or within its
+    /// parentheses when it has none.
+    pub fn parameter_comments(&self) -> &[Comment] {
+        match &self.parameter_comments {
+            Some(comments) => &comments.1,
+            None => &[],
+        }
+    }
+
+    /// Returns whether this [`Function`] has any [`Comment`] among its parameters.
+    pub fn has_parameter_comments(&self) -> bool {
+        self.parameter_comments.is_some()
+    }
+
+    /// Consumes this [`Function`], returning its [`TypeGenericParameter`]s, parameter [`Binding`]s,
+    /// [`FunctionVariadic`], return [`TypePack`], [`Block`], the [`Trivia`] of its parameters and
+    /// the [`Comment`]s after them.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<TypeGenericParameter>,
+        Vec<Binding>,
+        Option<FunctionVariadic>,
+        Option<TypePack>,
+        Block,
+        Vec<Trivia>,
+        Vec<Comment>,
+    ) {
+        let (parameter_trivia, parameter_comments) = self
+            .parameter_comments
+            .map(|comments| *comments)
+            .unwrap_or_default();
+
+        (
+            self.generics,
+            self.parameters,
+            self.variadic,
+            self.returns,
+            self.block,
+            parameter_trivia,
+            parameter_comments,
+        )
+    }
+}
@@ -0,0 +1,26 @@
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identifier {
+    name: String,
+}
+
+impl Identifier {
+    /// Constructs a new [`Identifier`] with the given name.
+    pub fn new<T: AsRef<str>>(name: T) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+        }
+    }
+}
+
+impl Identifier {
+    /// Returns the name of this [`Identifier`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T: AsRef<str>> From<T> for Identifier {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfExpression {
+    condition: Box<Expression>,
+    consequent: Box<Expression>,
+    else_ifs: Vec<(Expression, Expression)>,
+    alternative: Box<Expression>,
+}
+
+impl IfExpression {
+    /// Constructs a new [`IfExpression`], such as `if a then b elseif c then d else e`.
+    ///
+    /// Each `elseif` branch is a pair of its condition and its value.
+    pub fn new<C: Into<Expression>, T: Into<Expression>, E: Into<Expression>>(
+        condition: C,
+        consequent: T,
+        else_ifs: Vec<(Expression, Expression)>,
+        alternative: E,
+    ) -> Self {
+        Self {
+            condition: Box::new(condition.into()),
+            consequent: Box::new(consequent.into()),
+            else_ifs,
+            alternative: Box::new(alternative.into()),
+        }
+    }
+
+    /// Constructs a new [`IfExpression`] without `elseif` branches, such as `if a then b else c`.
+    pub fn simple<C: Into<Expression>, T: Into<Expression>, E: Into<Expression>>(
+        condition: C,
+        consequent: T,
+        alternative: E,
+    ) -> Self {
+        Self::new(condition, consequent, Vec::new(), alternative)
+    }
+}
+
+impl IfExpression {
+    /// Returns the condition of this [`IfExpression`].
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    /// Returns a mutable reference to the condition of this [`IfExpression`].
+    pub fn condition_mut(&mut self) -> &mut Expression {
+        &mut self.condition
+    }
+
+    /// Returns the value of this [`IfExpression`] when the condition is truthy.
+    pub fn consequent(&self) -> &Expression {
+        &self.consequent
+    }
+
+    /// Returns a mutable reference to the value of this [`IfExpression`] when the condition is truthy.
+    pub fn consequent_mut(&mut self) -> &mut Expression {
+        &mut self.consequent
+    }
+
+    /// Returns the `elseif` branches of this [`IfExpression`].
+    pub fn else_ifs(&self) -> &Vec<(Expression, Expression)> {
+        &self.else_ifs
+    }
+
+    /// Returns a mutable reference to the `elseif` branches of this [`IfExpression`].
+    pub fn else_ifs_mut(&mut self) -> &mut Vec<(Expression, Expression)> {
+        &mut self.else_ifs
+    }
+
+    /// Returns the value of this [`IfExpression`] when no condition is truthy.
+    pub fn alternative(&self) -> &Expression {
+        &self.alternative
+    }
+
+    /// Returns a mutable reference to the value of this [`IfExpression`] when no condition is truthy.
+    pub fn alternative_mut(&mut self) -> &mut Expression {
+        &mut self.alternative
+    }
+
+    /// Consumes this [`IfExpression`], returning its condition, consequent, `elseif` branches and alternative.
+    pub fn into_parts(
+        self,
+    ) -> (
+        Expression,
+        Expression,
+        Vec<(Expression, Expression)>,
+        Expression,
+    ) {
+        (
+            *self.condition,
+            *self.consequent,
+            self.else_ifs,
+            *self.alternative,
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexKey {
+    /// A key written as a name, such as `b` in `a.b`.
+    Field(String),
+
+    /// A key written as an expression, such as `b` in `a[b]`.
+    Computed(Box<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Index {
+    object: Box<Expression>,
+    key: IndexKey,
+}
+
+impl Index {
+    /// Constructs a new [`Index`] of the given [`Expression`] with the given [`IndexKey`].
+    pub fn new<T: Into<Expression>>(object: T, key: IndexKey) -> Self {
+        Self {
+            object: Box::new(object.into()),
+            key,
+        }
+    }
+
+    /// Constructs a new field [`Index`], such as `a.b`.
+    pub fn field<T: Into<Expression>, N: AsRef<str>>(object: T, name: N) -> Self {
+        Self::new(object, IndexKey::Field(name.as_ref().to_string()))
+    }
+
+    /// Constructs a new computed [`Index`], such as `a[b]`.
+    pub fn computed<T: Into<Expression>, K: Into<Expression>>(object: T, key: K) -> Self {
+        Self::new(object, IndexKey::Computed(Box::new(key.into())))
+    }
+}
+
+impl Index {
+    /// Returns the indexed [`Expression`] of this [`Index`].
+    pub fn object(&self) -> &Expression {
+        &self.object
+    }
+
+    /// Returns a mutable reference to the indexed [`Expression`] of this [`Index`].
+    pub fn object_mut(&mut self) -> &mut Expression {
+        &mut self.object
+    }
+
+    /// Returns the [`IndexKey`] of this [`Index`].
+    pub fn key(&self) -> &IndexKey {
+        &self.key
+    }
+
+    /// Returns a mutable reference to the [`IndexKey`] of this [`Index`].
+    pub fn key_mut(&mut self) -> &mut IndexKey {
+        &mut self.key
+    }
+
+    /// Consumes this [`Index`], returning its indexed [`Expression`] and [`IndexKey`].
+    pub fn into_parts(self) -> (Expression, IndexKey) {
+        (*self.object, self.key)
+    }
+}
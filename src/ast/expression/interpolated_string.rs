@@ -0,0 +1,47 @@
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpolatedString {
+    segments: Vec<String>,
+    expressions: Vec<Expression>,
+}
+
+impl InterpolatedString {
+    /// Constructs a new [`InterpolatedString`], such as `` `{count} items` ``, from the text of its
+    /// segments as written, escapes included, and the expressions between them.
+    ///
+    /// There is one more segment than there are expressions, as a segment starts and ends it.
+    pub fn new(segments: Vec<String>, expressions: Vec<Expression>) -> Self {
+        Self {
+            segments,
+            expressions,
+        }
+    }
+
+    /// Constructs a new [`InterpolatedString`] without expressions, such as `` `text` ``.
+    pub fn simple<T: AsRef<str>>(text: T) -> Self {
+        Self::new(vec![text.as_ref().to_string()], Vec::new())
+    }
+}
+
+impl InterpolatedString {
+    /// Returns the segments of this [`InterpolatedString`], as written between its expressions.
+    pub fn segments(&self) -> &Vec<String> {
+        &self.segments
+    }
+
+    /// Returns the expressions of this [`InterpolatedString`].
+    pub fn expressions(&self) -> &Vec<Expression> {
+        &self.expressions
+    }
+
+    /// Returns a mutable reference to the expressions of this [`InterpolatedString`].
+    pub fn expressions_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.expressions
+    }
+
+    /// Consumes this [`InterpolatedString`], returning its segments and expressions.
+    pub fn into_parts(self) -> (Vec<String>, Vec<Expression>) {
+        (self.segments, self.expressions)
+    }
+}
@@ -63,3 +63,6 @@ impl<T: AsRef<str>> From<T> for NumberLiteral {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct VarargLiteral;
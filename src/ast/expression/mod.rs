@@ -1,10 +1,28 @@
 use derive_more::From;
 
+pub mod call;
+pub mod function_expression;
+pub mod identifier;
+pub mod if_expression;
+pub mod index;
+pub mod interpolated_string;
 pub mod literal;
 pub mod operation;
+pub mod parenthesized;
+pub mod table_constructor;
+pub mod type_assertion;
 
-pub use literal::{BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral};
+pub use call::FunctionCall;
+pub use function_expression::{Function, FunctionVariadic};
+pub use identifier::Identifier;
+pub use if_expression::IfExpression;
+pub use index::{Index, IndexKey};
+pub use interpolated_string::InterpolatedString;
+pub use literal::{BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral, VarargLiteral};
 pub use operation::{BinaryOperation, UnaryOperation};
+pub use parenthesized::Parenthesized;
+pub use table_constructor::{TableConstructor, TableField};
+pub use type_assertion::TypeAssertion;
 
 #[derive(Debug, Clone, PartialEq, From)]
 pub enum Expression {
@@ -13,8 +31,26 @@ pub enum Expression {
     BooleanLiteral(BooleanLiteral),
     StringLiteral(StringLiteral),
     NumberLiteral(NumberLiteral),
+    VarargLiteral(VarargLiteral),
+    InterpolatedString(InterpolatedString),
+
+    // Variables
+    Identifier(Identifier),
+    Index(Index),
+
+    // Constructors
+    Function(Function),
+    TableConstructor(TableConstructor),
+
+    // Calls
+    FunctionCall(FunctionCall),
 
     // Operations
     UnaryOperation(UnaryOperation),
     BinaryOperation(BinaryOperation),
+
+    // Other
+    Parenthesized(Parenthesized),
+    IfExpression(IfExpression),
+    TypeAssertion(TypeAssertion),
 }
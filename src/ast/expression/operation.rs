@@ -41,6 +41,16 @@ impl UnaryOperation {
     pub fn operand(&self) -> &Expression {
         &self.operand
     }
+
+    /// Returns a mutable reference to the [`Expression`] of this [`UnaryOperation`].
+    pub fn operand_mut(&mut self) -> &mut Expression {
+        &mut self.operand
+    }
+
+    /// Consumes this [`UnaryOperation`], returning its [`UnaryOperator`] and [`Expression`].
+    pub fn into_parts(self) -> (UnaryOperator, Expression) {
+        (self.operator, *self.operand)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -84,6 +94,11 @@ impl BinaryOperation {
         Self::new(BinaryOperator::Divide, left, right)
     }
 
+    /// Constructs a new floor division [`BinaryOperation`] with the given left and right [`Expression`].
+    pub fn floor_divide<L: Into<Expression>, R: Into<Expression>>(left: L, right: R) -> Self {
+        Self::new(BinaryOperator::FloorDivide, left, right)
+    }
+
     /// Constructs a new modulo [`BinaryOperation`] with the given left and right [`Expression`].
     pub fn modulo<L: Into<Expression>, R: Into<Expression>>(left: L, right: R) -> Self {
         Self::new(BinaryOperator::Modulo, left, right)
@@ -94,6 +109,11 @@ impl BinaryOperation {
         Self::new(BinaryOperator::Power, left, right)
     }
 
+    /// Constructs a new concatenation [`BinaryOperation`] with the given left and right [`Expression`].
+    pub fn concat<L: Into<Expression>, R: Into<Expression>>(left: L, right: R) -> Self {
+        Self::new(BinaryOperator::Concat, left, right)
+    }
+
     /// Constructs a new and [`BinaryOperation`] with the given left and right [`Expression`].
     pub fn and<L: Into<Expression>, R: Into<Expression>>(left: L, right: R) -> Self {
         Self::new(BinaryOperator::And, left, right)
@@ -149,8 +169,23 @@ impl BinaryOperation {
         &self.left
     }
 
+    /// Returns a mutable reference to the left [`Expression`] of this [`BinaryOperation`].
+    pub fn left_mut(&mut self) -> &mut Expression {
+        &mut self.left
+    }
+
     /// Returns the right [`Expression`] of this [`BinaryOperation`].
     pub fn right(&self) -> &Expression {
         &self.right
     }
+
+    /// Returns a mutable reference to the right [`Expression`] of this [`BinaryOperation`].
+    pub fn right_mut(&mut self) -> &mut Expression {
+        &mut self.right
+    }
+
+    /// Consumes this [`BinaryOperation`], returning its [`BinaryOperator`] and left and right [`Expression`].
+    pub fn into_parts(self) -> (BinaryOperator, Expression, Expression) {
+        (self.operator, *self.left, *self.right)
+    }
 }
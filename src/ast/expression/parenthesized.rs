@@ -0,0 +1,34 @@
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parenthesized {
+    expression: Box<Expression>,
+}
+
+impl Parenthesized {
+    /// Constructs a new [`Parenthesized`] with the given [`Expression`].
+    ///
+    /// Parenthesis are significant in Luau, as they truncate multiple values to one, such as in `(f())`.
+    pub fn new<T: Into<Expression>>(expression: T) -> Self {
+        Self {
+            expression: Box::new(expression.into()),
+        }
+    }
+}
+
+impl Parenthesized {
+    /// Returns the [`Expression`] of this [`Parenthesized`].
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    /// Returns a mutable reference to the [`Expression`] of this [`Parenthesized`].
+    pub fn expression_mut(&mut self) -> &mut Expression {
+        &mut self.expression
+    }
+
+    /// Consumes this [`Parenthesized`], returning its [`Expression`].
+    pub fn into_expression(self) -> Expression {
+        *self.expression
+    }
+}
@@ -0,0 +1,127 @@
+use crate::ast::{Comment, Expression, Trivia};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableField {
+    /// A field with a name key, such as `a = 1`.
+    Named(String, Expression),
+
+    /// A field with an expression key, such as `[a] = 1`.
+    Keyed(Expression, Expression),
+
+    /// A field without a key, such as `1`.
+    Positional(Expression),
+}
+
+impl TableField {
+    /// Constructs a new `TableField::Named` with the given name and value.
+    pub fn named<N: AsRef<str>, V: Into<Expression>>(name: N, value: V) -> Self {
+        Self::Named(name.as_ref().to_string(), value.into())
+    }
+
+    /// Constructs a new `TableField::Keyed` with the given key and value.
+    pub fn keyed<K: Into<Expression>, V: Into<Expression>>(key: K, value: V) -> Self {
+        Self::Keyed(key.into(), value.into())
+    }
+
+    /// Constructs a new `TableField::Positional` with the given value.
+    pub fn positional<V: Into<Expression>>(value: V) -> Self {
+        Self::Positional(value.into())
+    }
+}
+
+impl TableField {
+    /// Returns the value of this [`TableField`].
+    pub fn value(&self) -> &Expression {
+        match self {
+            Self::Named(_, value) => value,
+            Self::Keyed(_, value) => value,
+            Self::Positional(value) => value,
+        }
+    }
+
+    /// Returns a mutable reference to the value of this [`TableField`].
+    pub fn value_mut(&mut self) -> &mut Expression {
+        match self {
+            Self::Named(_, value) => value,
+            Self::Keyed(_, value) => value,
+            Self::Positional(value) => value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableConstructor {
+    fields: Vec<TableField>,
+    trivia: Vec<Trivia>,
+    dangling_comments: Vec<Comment>,
+}
+
+impl TableConstructor {
+    /// Constructs a new [`TableConstructor`] with the given [`TableField`]s.
+    pub fn new(fields: Vec<TableField>) -> Self {
+        Self {
+            fields,
+            trivia: Vec::new(),
+            dangling_comments: Vec::new(),
+        }
+    }
+
+    /// Constructs a new [`TableConstructor`] with the given [`TableField`]s, the [`Trivia`] of
+    /// each of them, by index, and the [`Comment`]s after the last of them.
+    pub fn with_comments(
+        fields: Vec<TableField>,
+        trivia: Vec<Trivia>,
+        dangling_comments: Vec<Comment>,
+    ) -> Self {
+        Self {
+            fields,
+            trivia,
+            dangling_comments,
+        }
+    }
+
+    /// Constructs a new empty [`TableConstructor`].
+    pub fn empty() -> Self {
+        Self::default()
+    }
+}
+
+impl TableConstructor {
+    /// Returns the [`TableField`]s of this [`TableConstructor`].
+    pub fn fields(&self) -> &Vec<TableField> {
+        &self.fields
+    }
+
+    /// Returns a mutable reference to the [`TableField`]s of this [`TableConstructor`].
+    pub fn fields_mut(&mut self) -> &mut Vec<TableField> {
+        &mut self.fields
+    }
+
+    /// Returns the [`Trivia`] of the [`TableField`]s of this [`TableConstructor`], by index.
+    /// Fields past its end have no comments.
+    pub fn trivia(&self) -> &Vec<Trivia> {
+        &self.trivia
+    }
+
+    /// Returns the [`Comment`]s after the last [`TableField`] of this [`TableConstructor`], or
+    /// within it when it is empty.
+    pub fn dangling_comments(&self) -> &Vec<Comment> {
+        &self.dangling_comments
+    }
+
+    /// Returns whether this [`TableConstructor`] has any [`Comment`].
+    pub fn has_comments(&self) -> bool {
+        !self.dangling_comments.is_empty() || self.trivia.iter().any(|trivia| !trivia.is_empty())
+    }
+
+    /// Consumes this [`TableConstructor`], returning its [`TableField`]s.
+    pub fn into_fields(self) -> Vec<TableField> {
+        self.fields
+    }
+
+    /// Consumes this [`TableConstructor`], returning its [`TableField`]s, their [`Trivia`] and its
+    /// dangling [`Comment`]s.
+    pub fn into_parts(self) -> (Vec<TableField>, Vec<Trivia>, Vec<Comment>) {
+        (self.fields, self.trivia, self.dangling_comments)
+    }
+}
@@ -0,0 +1,44 @@
+use crate::ast::{Expression, TypeAnnotation};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeAssertion {
+    expression: Box<Expression>,
+    annotation: Box<TypeAnnotation>,
+}
+
+impl TypeAssertion {
+    /// Constructs a new [`TypeAssertion`] of the given [`Expression`] to the given [`TypeAnnotation`], such as `x :: number`.
+    pub fn new<E: Into<Expression>, T: Into<TypeAnnotation>>(expression: E, annotation: T) -> Self {
+        Self {
+            expression: Box::new(expression.into()),
+            annotation: Box::new(annotation.into()),
+        }
+    }
+}
+
+impl TypeAssertion {
+    /// Returns the [`Expression`] of this [`TypeAssertion`].
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    /// Returns a mutable reference to the [`Expression`] of this [`TypeAssertion`].
+    pub fn expression_mut(&mut self) -> &mut Expression {
+        &mut self.expression
+    }
+
+    /// Returns the [`TypeAnnotation`] of this [`TypeAssertion`].
+    pub fn annotation(&self) -> &TypeAnnotation {
+        &self.annotation
+    }
+
+    /// Returns a mutable reference to the [`TypeAnnotation`] of this [`TypeAssertion`].
+    pub fn annotation_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.annotation
+    }
+
+    /// Consumes this [`TypeAssertion`], returning its [`Expression`] and [`TypeAnnotation`].
+    pub fn into_parts(self) -> (Expression, TypeAnnotation) {
+        (*self.expression, *self.annotation)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::ast::Expression;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assign {
+    targets: Vec<Expression>,
+    values: Vec<Expression>,
+}
+
+impl Assign {
+    /// Constructs a new [`Assign`] of the given values to the given targets, such as `a, b.c = 1, 2`.
+    pub fn new(targets: Vec<Expression>, values: Vec<Expression>) -> Self {
+        Self { targets, values }
+    }
+
+    /// Constructs a new [`Assign`] of a single value to a single target.
+    pub fn one<T: Into<Expression>, V: Into<Expression>>(target: T, value: V) -> Self {
+        Self::new(vec![target.into()], vec![value.into()])
+    }
+}
+
+impl Assign {
+    /// Returns the target [`Expression`]s of this [`Assign`].
+    pub fn targets(&self) -> &Vec<Expression> {
+        &self.targets
+    }
+
+    /// Returns a mutable reference to the target [`Expression`]s of this [`Assign`].
+    pub fn targets_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.targets
+    }
+
+    /// Returns the value [`Expression`]s of this [`Assign`].
+    pub fn values(&self) -> &Vec<Expression> {
+        &self.values
+    }
+
+    /// Returns a mutable reference to the value [`Expression`]s of this [`Assign`].
+    pub fn values_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.values
+    }
+
+    /// Consumes this [`Assign`], returning its targets and values.
+    pub fn into_parts(self) -> (Vec<Expression>, Vec<Expression>) {
+        (self.targets, self.values)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ast::{CompoundAssignmentOperator, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompoundAssign {
+    operator: CompoundAssignmentOperator,
+    target: Expression,
+    value: Expression,
+}
+
+impl CompoundAssign {
+    /// Constructs a new [`CompoundAssign`] with the given [`CompoundAssignmentOperator`], target and value, such as `a += 1`.
+    pub fn new<T: Into<Expression>, V: Into<Expression>>(
+        operator: CompoundAssignmentOperator,
+        target: T,
+        value: V,
+    ) -> Self {
+        Self {
+            operator,
+            target: target.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl CompoundAssign {
+    /// Returns the [`CompoundAssignmentOperator`] of this [`CompoundAssign`].
+    pub fn operator(&self) -> CompoundAssignmentOperator {
+        self.operator
+    }
+
+    /// Returns the target [`Expression`] of this [`CompoundAssign`].
+    pub fn target(&self) -> &Expression {
+        &self.target
+    }
+
+    /// Returns a mutable reference to the target [`Expression`] of this [`CompoundAssign`].
+    pub fn target_mut(&mut self) -> &mut Expression {
+        &mut self.target
+    }
+
+    /// Returns the value [`Expression`] of this [`CompoundAssign`].
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value [`Expression`] of this [`CompoundAssign`].
+    pub fn value_mut(&mut self) -> &mut Expression {
+        &mut self.value
+    }
+
+    /// Consumes this [`CompoundAssign`], returning its [`CompoundAssignmentOperator`], target and value.
+    pub fn into_parts(self) -> (CompoundAssignmentOperator, Expression, Expression) {
+        (self.operator, self.target, self.value)
+    }
+}
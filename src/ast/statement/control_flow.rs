@@ -0,0 +1,62 @@
+use crate::ast::{Expression, Trivia};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Return {
+    values: Vec<Expression>,
+    trivia: Vec<Trivia>,
+}
+
+impl Return {
+    /// Constructs a new [`Return`] with the given [`Expression`]s.
+    pub fn new(values: Vec<Expression>) -> Self {
+        Self {
+            values,
+            trivia: Vec::new(),
+        }
+    }
+
+    /// Constructs a new [`Return`] with the given [`Expression`]s and the [`Trivia`] of each of
+    /// them, by index.
+    pub fn with_comments(values: Vec<Expression>, trivia: Vec<Trivia>) -> Self {
+        Self { values, trivia }
+    }
+
+    /// Constructs a new [`Return`] without values.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+}
+
+impl Return {
+    /// Returns the [`Expression`]s of this [`Return`].
+    pub fn values(&self) -> &Vec<Expression> {
+        &self.values
+    }
+
+    /// Returns a mutable reference to the [`Expression`]s of this [`Return`].
+    pub fn values_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.values
+    }
+
+    /// Returns the [`Trivia`] of the [`Expression`]s of this [`Return`], by index. Values past its
+    /// end have no comments.
+    pub fn trivia(&self) -> &Vec<Trivia> {
+        &self.trivia
+    }
+
+    /// Consumes this [`Return`], returning its [`Expression`]s.
+    pub fn into_values(self) -> Vec<Expression> {
+        self.values
+    }
+
+    /// Consumes this [`Return`], returning its [`Expression`]s and their [`Trivia`].
+    pub fn into_parts(self) -> (Vec<Expression>, Vec<Trivia>) {
+        (self.values, self.trivia)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Break;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Continue;
@@ -0,0 +1,30 @@
+use crate::ast::Block;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Do {
+    block: Block,
+}
+
+impl Do {
+    /// Constructs a new [`Do`] with the given [`Block`].
+    pub fn new(block: Block) -> Self {
+        Self { block }
+    }
+}
+
+impl Do {
+    /// Returns the [`Block`] of this [`Do`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`Do`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Consumes this [`Do`], returning its [`Block`].
+    pub fn into_block(self) -> Block {
+        self.block
+    }
+}
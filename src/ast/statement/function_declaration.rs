@@ -0,0 +1,80 @@
+use crate::ast::Function;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionName {
+    path: Vec<String>,
+    method: Option<String>,
+}
+
+impl FunctionName {
+    /// Constructs a new [`FunctionName`] with the given path and optional method name, such as `a.b.c:d`.
+    ///
+    /// The path must contain at least the name of the root variable.
+    pub fn new(path: Vec<String>, method: Option<String>) -> Self {
+        assert!(!path.is_empty(), "a function name requires a root variable");
+
+        Self { path, method }
+    }
+
+    /// Constructs a new [`FunctionName`] consisting of a single name, such as `f`.
+    pub fn named<T: AsRef<str>>(name: T) -> Self {
+        Self::new(vec![name.as_ref().to_string()], None)
+    }
+}
+
+impl FunctionName {
+    /// Returns the name of the root variable of this [`FunctionName`], such as `a` in `a.b:c`.
+    pub fn root(&self) -> &str {
+        &self.path[0]
+    }
+
+    /// Returns the path of this [`FunctionName`], including the root variable.
+    pub fn path(&self) -> &Vec<String> {
+        &self.path
+    }
+
+    /// Returns the method name of this [`FunctionName`].
+    pub fn method(&self) -> Option<&str> {
+        self.method.as_deref()
+    }
+
+    /// Returns whether this [`FunctionName`] declares a method, which receives an implicit `self` parameter.
+    pub fn is_method(&self) -> bool {
+        self.method.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionDeclaration {
+    name: FunctionName,
+    function: Function,
+}
+
+impl FunctionDeclaration {
+    /// Constructs a new [`FunctionDeclaration`] with the given [`FunctionName`] and [`Function`].
+    pub fn new(name: FunctionName, function: Function) -> Self {
+        Self { name, function }
+    }
+}
+
+impl FunctionDeclaration {
+    /// Returns the [`FunctionName`] of this [`FunctionDeclaration`].
+    pub fn name(&self) -> &FunctionName {
+        &self.name
+    }
+
+    /// Returns the [`Function`] of this [`FunctionDeclaration`].
+    pub fn function(&self) -> &Function {
+        &self.function
+    }
+
+    /// Returns a mutable reference to the [`Function`] of this [`FunctionDeclaration`].
+    pub fn function_mut(&mut self) -> &mut Function {
+        &mut self.function
+    }
+
+    /// Consumes this [`FunctionDeclaration`], returning its [`FunctionName`] and [`Function`].
+    pub fn into_parts(self) -> (FunctionName, Function) {
+        (self.name, self.function)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::ast::{Binding, Block, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericFor {
+    bindings: Vec<Binding>,
+    values: Vec<Expression>,
+    block: Block,
+}
+
+impl GenericFor {
+    /// Constructs a new [`GenericFor`], such as `for key, value in pairs(t) do`.
+    pub fn new(bindings: Vec<Binding>, values: Vec<Expression>, block: Block) -> Self {
+        Self {
+            bindings,
+            values,
+            block,
+        }
+    }
+}
+
+impl GenericFor {
+    /// Returns the loop variable [`Binding`]s of this [`GenericFor`].
+    pub fn bindings(&self) -> &Vec<Binding> {
+        &self.bindings
+    }
+
+    /// Returns a mutable reference to the loop variable [`Binding`]s of this [`GenericFor`].
+    pub fn bindings_mut(&mut self) -> &mut Vec<Binding> {
+        &mut self.bindings
+    }
+
+    /// Returns the iterated [`Expression`]s of this [`GenericFor`].
+    pub fn values(&self) -> &Vec<Expression> {
+        &self.values
+    }
+
+    /// Returns a mutable reference to the iterated [`Expression`]s of this [`GenericFor`].
+    pub fn values_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.values
+    }
+
+    /// Returns the [`Block`] of this [`GenericFor`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`GenericFor`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Consumes this [`GenericFor`], returning its [`Binding`]s, iterated [`Expression`]s and [`Block`].
+    pub fn into_parts(self) -> (Vec<Binding>, Vec<Expression>, Block) {
+        (self.bindings, self.values, self.block)
+    }
+}
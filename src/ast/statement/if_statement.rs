@@ -0,0 +1,121 @@
+use crate::ast::{Block, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElseIf {
+    condition: Expression,
+    block: Block,
+}
+
+impl ElseIf {
+    /// Constructs a new [`ElseIf`] with the given condition and [`Block`].
+    pub fn new<T: Into<Expression>>(condition: T, block: Block) -> Self {
+        Self {
+            condition: condition.into(),
+            block,
+        }
+    }
+}
+
+impl ElseIf {
+    /// Returns the condition of this [`ElseIf`].
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    /// Returns a mutable reference to the condition of this [`ElseIf`].
+    pub fn condition_mut(&mut self) -> &mut Expression {
+        &mut self.condition
+    }
+
+    /// Returns the [`Block`] of this [`ElseIf`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`ElseIf`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Consumes this [`ElseIf`], returning its condition and [`Block`].
+    pub fn into_parts(self) -> (Expression, Block) {
+        (self.condition, self.block)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct If {
+    condition: Expression,
+    block: Block,
+    else_ifs: Vec<ElseIf>,
+    else_block: Option<Block>,
+}
+
+impl If {
+    /// Constructs a new [`If`] with the given condition, [`Block`], [`ElseIf`]s and optional `else` [`Block`].
+    pub fn new<T: Into<Expression>>(
+        condition: T,
+        block: Block,
+        else_ifs: Vec<ElseIf>,
+        else_block: Option<Block>,
+    ) -> Self {
+        Self {
+            condition: condition.into(),
+            block,
+            else_ifs,
+            else_block,
+        }
+    }
+
+    /// Constructs a new [`If`] with the given condition and [`Block`], without other branches.
+    pub fn simple<T: Into<Expression>>(condition: T, block: Block) -> Self {
+        Self::new(condition, block, Vec::new(), None)
+    }
+}
+
+impl If {
+    /// Returns the condition of this [`If`].
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    /// Returns a mutable reference to the condition of this [`If`].
+    pub fn condition_mut(&mut self) -> &mut Expression {
+        &mut self.condition
+    }
+
+    /// Returns the [`Block`] of this [`If`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`If`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Returns the [`ElseIf`]s of this [`If`].
+    pub fn else_ifs(&self) -> &Vec<ElseIf> {
+        &self.else_ifs
+    }
+
+    /// Returns a mutable reference to the [`ElseIf`]s of this [`If`].
+    pub fn else_ifs_mut(&mut self) -> &mut Vec<ElseIf> {
+        &mut self.else_ifs
+    }
+
+    /// Returns the `else` [`Block`] of this [`If`].
+    pub fn else_block(&self) -> Option<&Block> {
+        self.else_block.as_ref()
+    }
+
+    /// Returns a mutable reference to the `else` [`Block`] of this [`If`].
+    pub fn else_block_mut(&mut self) -> Option<&mut Block> {
+        self.else_block.as_mut()
+    }
+
+    /// Consumes this [`If`], returning its condition, [`Block`], [`ElseIf`]s and `else` [`Block`].
+    pub fn into_parts(self) -> (Expression, Block, Vec<ElseIf>, Option<Block>) {
+        (self.condition, self.block, self.else_ifs, self.else_block)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::ast::{Binding, Expression};
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct LocalAssign {
     bindings: Vec<Binding>,
     values: Vec<Expression>,
@@ -14,14 +15,41 @@ impl LocalAssign {
     }
 
     pub fn declare_many(bindings: &[Binding]) -> Self {
-        self::assign_many(bindings, &[])
+        Self::assign_many(bindings, &[])
     }
 
-    pub fn assign_one(binding: Binding, value: Expression) -> Self {
-        self::assign_many(&[binding], &[value])
+    pub fn assign_one<T: Into<Expression>>(binding: Binding, value: T) -> Self {
+        Self::assign_many(&[binding], &[value.into()])
     }
 
     pub fn declare_one(binding: Binding) -> Self {
-        self::declare_many(&[binding])
+        Self::declare_many(&[binding])
     }
-}
\ No newline at end of file
+}
+
+impl LocalAssign {
+    /// Returns the [`Binding`]s of this [`LocalAssign`].
+    pub fn bindings(&self) -> &Vec<Binding> {
+        &self.bindings
+    }
+
+    /// Returns a mutable reference to the [`Binding`]s of this [`LocalAssign`].
+    pub fn bindings_mut(&mut self) -> &mut Vec<Binding> {
+        &mut self.bindings
+    }
+
+    /// Returns the [`Expression`]s assigned by this [`LocalAssign`].
+    pub fn values(&self) -> &Vec<Expression> {
+        &self.values
+    }
+
+    /// Returns a mutable reference to the [`Expression`]s assigned by this [`LocalAssign`].
+    pub fn values_mut(&mut self) -> &mut Vec<Expression> {
+        &mut self.values
+    }
+
+    /// Consumes this [`LocalAssign`], returning its [`Binding`]s and [`Expression`]s.
+    pub fn into_parts(self) -> (Vec<Binding>, Vec<Expression>) {
+        (self.bindings, self.values)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::ast::Function;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalFunction {
+    name: String,
+    function: Function,
+}
+
+impl LocalFunction {
+    /// Constructs a new [`LocalFunction`] with the given name and [`Function`].
+    ///
+    /// The name is in scope inside the function, so it can call itself recursively.
+    pub fn new<T: AsRef<str>>(name: T, function: Function) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            function,
+        }
+    }
+}
+
+impl LocalFunction {
+    /// Returns the name of this [`LocalFunction`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`Function`] of this [`LocalFunction`].
+    pub fn function(&self) -> &Function {
+        &self.function
+    }
+
+    /// Returns a mutable reference to the [`Function`] of this [`LocalFunction`].
+    pub fn function_mut(&mut self) -> &mut Function {
+        &mut self.function
+    }
+
+    /// Consumes this [`LocalFunction`], returning its name and [`Function`].
+    pub fn into_parts(self) -> (String, Function) {
+        (self.name, self.function)
+    }
+}
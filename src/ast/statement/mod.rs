@@ -1 +1,62 @@
+use derive_more::From;
 
+use crate::ast::FunctionCall;
+
+pub mod assign;
+pub mod compound_assign;
+pub mod control_flow;
+pub mod do_block;
+pub mod function_declaration;
+pub mod generic_for;
+pub mod if_statement;
+pub mod local_assign;
+pub mod local_function;
+pub mod numeric_for;
+pub mod repeat_loop;
+pub mod type_declaration;
+pub mod while_loop;
+
+pub use assign::Assign;
+pub use compound_assign::CompoundAssign;
+pub use control_flow::{Break, Continue, Return};
+pub use do_block::Do;
+pub use function_declaration::{FunctionDeclaration, FunctionName};
+pub use generic_for::GenericFor;
+pub use if_statement::{ElseIf, If};
+pub use local_assign::LocalAssign;
+pub use local_function::LocalFunction;
+pub use numeric_for::NumericFor;
+pub use repeat_loop::Repeat;
+pub use type_declaration::{TypeDeclaration, TypeDeclarationBuilder};
+pub use while_loop::While;
+
+#[derive(Debug, Clone, PartialEq, From)]
+pub enum Statement {
+    // Assignments
+    LocalAssign(LocalAssign),
+    Assign(Assign),
+    CompoundAssign(CompoundAssign),
+
+    // Calls
+    FunctionCall(FunctionCall),
+
+    // Blocks
+    Do(Do),
+    While(While),
+    Repeat(Repeat),
+    If(If),
+    NumericFor(NumericFor),
+    GenericFor(GenericFor),
+
+    // Functions
+    FunctionDeclaration(FunctionDeclaration),
+    LocalFunction(LocalFunction),
+
+    // Control flow
+    Return(Return),
+    Break(Break),
+    Continue(Continue),
+
+    // Types
+    TypeDeclaration(TypeDeclaration),
+}
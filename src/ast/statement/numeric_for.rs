@@ -0,0 +1,92 @@
+use crate::ast::{Binding, Block, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericFor {
+    binding: Binding,
+    start: Box<Expression>,
+    end: Box<Expression>,
+    step: Option<Box<Expression>>,
+    block: Block,
+}
+
+impl NumericFor {
+    /// Constructs a new [`NumericFor`], such as `for i = start, end, step do`.
+    pub fn new<S: Into<Expression>, E: Into<Expression>>(
+        binding: Binding,
+        start: S,
+        end: E,
+        step: Option<Expression>,
+        block: Block,
+    ) -> Self {
+        Self {
+            binding,
+            start: Box::new(start.into()),
+            end: Box::new(end.into()),
+            step: step.map(Box::new),
+            block,
+        }
+    }
+}
+
+impl NumericFor {
+    /// Returns the loop variable [`Binding`] of this [`NumericFor`].
+    pub fn binding(&self) -> &Binding {
+        &self.binding
+    }
+
+    /// Returns a mutable reference to the loop variable [`Binding`] of this [`NumericFor`].
+    pub fn binding_mut(&mut self) -> &mut Binding {
+        &mut self.binding
+    }
+
+    /// Returns the start [`Expression`] of this [`NumericFor`].
+    pub fn start(&self) -> &Expression {
+        &self.start
+    }
+
+    /// Returns a mutable reference to the start [`Expression`] of this [`NumericFor`].
+    pub fn start_mut(&mut self) -> &mut Expression {
+        &mut self.start
+    }
+
+    /// Returns the end [`Expression`] of this [`NumericFor`].
+    pub fn end(&self) -> &Expression {
+        &self.end
+    }
+
+    /// Returns a mutable reference to the end [`Expression`] of this [`NumericFor`].
+    pub fn end_mut(&mut self) -> &mut Expression {
+        &mut self.end
+    }
+
+    /// Returns the step [`Expression`] of this [`NumericFor`].
+    pub fn step(&self) -> Option<&Expression> {
+        self.step.as_deref()
+    }
+
+    /// Returns a mutable reference to the step [`Expression`] of this [`NumericFor`].
+    pub fn step_mut(&mut self) -> Option<&mut Expression> {
+        self.step.as_deref_mut()
+    }
+
+    /// Returns the [`Block`] of this [`NumericFor`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`NumericFor`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Consumes this [`NumericFor`], returning its [`Binding`], start, end, step and [`Block`].
+    pub fn into_parts(self) -> (Binding, Expression, Expression, Option<Expression>, Block) {
+        (
+            self.binding,
+            *self.start,
+            *self.end,
+            self.step.map(|step| *step),
+            self.block,
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use crate::ast::{Block, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Repeat {
+    block: Block,
+    condition: Expression,
+}
+
+impl Repeat {
+    /// Constructs a new [`Repeat`] with the given [`Block`] and condition.
+    ///
+    /// The condition is evaluated inside the scope of the block, so it can refer to its locals.
+    pub fn new<T: Into<Expression>>(block: Block, condition: T) -> Self {
+        Self {
+            block,
+            condition: condition.into(),
+        }
+    }
+}
+
+impl Repeat {
+    /// Returns the [`Block`] of this [`Repeat`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`Repeat`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Returns the condition of this [`Repeat`].
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    /// Returns a mutable reference to the condition of this [`Repeat`].
+    pub fn condition_mut(&mut self) -> &mut Expression {
+        &mut self.condition
+    }
+
+    /// Consumes this [`Repeat`], returning its [`Block`] and condition.
+    pub fn into_parts(self) -> (Block, Expression) {
+        (self.block, self.condition)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::ast::{TypeAnnotation, TypeGeneric, TypeGenericPack, TypeGenericParameter};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDeclaration {
+    exported: bool,
+    name: String,
+    generics: Vec<TypeGenericParameter>,
+    annotation: TypeAnnotation,
+}
+
+impl TypeDeclaration {
+    /// Constructs a new [`TypeDeclaration`] with the given name, [`TypeGenericParameter`]s and [`TypeAnnotation`].
+    pub fn new<T: AsRef<str>, A: Into<TypeAnnotation>>(
+        name: T,
+        generics: Vec<TypeGenericParameter>,
+        annotation: A,
+    ) -> Self {
+        Self {
+            exported: false,
+            name: name.as_ref().to_string(),
+            generics,
+            annotation: annotation.into(),
+        }
+    }
+
+    /// Constructs a new exported [`TypeDeclaration`] with the given name, [`TypeGenericParameter`]s and [`TypeAnnotation`].
+    pub fn export<T: AsRef<str>, A: Into<TypeAnnotation>>(
+        name: T,
+        generics: Vec<TypeGenericParameter>,
+        annotation: A,
+    ) -> Self {
+        Self {
+            exported: true,
+            ..Self::new(name, generics, annotation)
+        }
+    }
+
+    /// Constructs a new [`TypeDeclaration`] with the given name and [`TypeAnnotation`], and no generics.
+    pub fn alias<T: AsRef<str>, A: Into<TypeAnnotation>>(name: T, annotation: A) -> Self {
+        Self::new(name, Vec::new(), annotation)
+    }
+
+    /// Constructs a new [`TypeDeclarationBuilder`] for a declaration with the given name.
+    /// ```
+    /// use luna::ast::{TypeDeclaration, TypePrimitive, TypeReference, TypeTable};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let declaration = TypeDeclaration::builder("Response")
+    ///     .export()
+    ///     .generic("T")
+    ///     .generic_with_default("E", TypePrimitive::string())
+    ///     .build(
+    ///         TypeTable::builder()
+    ///             .field("value", TypeReference::named("T"))
+    ///             .field("error", TypeReference::named("E")),
+    ///     );
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(
+    ///     declaration.format_string(&settings),
+    ///     "export type Response<T, E = string> = { value: T, error: E }"
+    /// );
+    /// ```
+    pub fn builder<T: AsRef<str>>(name: T) -> TypeDeclarationBuilder {
+        TypeDeclarationBuilder {
+            exported: false,
+            name: name.as_ref().to_string(),
+            generics: Vec::new(),
+        }
+    }
+}
+
+impl TypeDeclaration {
+    /// Returns whether this [`TypeDeclaration`] is exported.
+    pub fn is_exported(&self) -> bool {
+        self.exported
+    }
+
+    /// Returns the name of this [`TypeDeclaration`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [`TypeGenericParameter`]s of this [`TypeDeclaration`].
+    pub fn generics(&self) -> &Vec<TypeGenericParameter> {
+        &self.generics
+    }
+
+    /// Returns a mutable reference to the [`TypeGenericParameter`]s of this [`TypeDeclaration`].
+    pub fn generics_mut(&mut self) -> &mut Vec<TypeGenericParameter> {
+        &mut self.generics
+    }
+
+    /// Returns the [`TypeAnnotation`] of this [`TypeDeclaration`].
+    pub fn annotation(&self) -> &TypeAnnotation {
+        &self.annotation
+    }
+
+    /// Returns a mutable reference to the [`TypeAnnotation`] of this [`TypeDeclaration`].
+    pub fn annotation_mut(&mut self) -> &mut TypeAnnotation {
+        &mut self.annotation
+    }
+
+    /// Consumes this [`TypeDeclaration`], returning whether it is exported, its name, [`TypeGenericParameter`]s and [`TypeAnnotation`].
+    pub fn into_parts(self) -> (bool, String, Vec<TypeGenericParameter>, TypeAnnotation) {
+        (self.exported, self.name, self.generics, self.annotation)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TypeDeclarationBuilder {
+    exported: bool,
+    name: String,
+    generics: Vec<TypeGenericParameter>,
+}
+
+impl TypeDeclarationBuilder {
+    /// Marks the declaration as exported.
+    pub fn export(mut self) -> Self {
+        self.exported = true;
+        self
+    }
+
+    /// Adds a generic type parameter with the given name.
+    pub fn generic<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.generics.push(TypeGeneric::named(name).into());
+        self
+    }
+
+    /// Adds a generic type parameter with the given name and default [`TypeAnnotation`].
+    pub fn generic_with_default<T: AsRef<str>, D: Into<TypeAnnotation>>(
+        mut self,
+        name: T,
+        default: D,
+    ) -> Self {
+        self.generics
+            .push(TypeGeneric::defaulted(name, default).into());
+        self
+    }
+
+    /// Adds a generic type pack parameter with the given name.
+    pub fn generic_pack<T: AsRef<str>>(mut self, name: T) -> Self {
+        self.generics.push(TypeGenericPack::named(name).into());
+        self
+    }
+
+    /// Constructs the [`TypeDeclaration`] with the given [`TypeAnnotation`].
+    pub fn build<A: Into<TypeAnnotation>>(self, annotation: A) -> TypeDeclaration {
+        TypeDeclaration {
+            exported: self.exported,
+            ..TypeDeclaration::new(self.name, self.generics, annotation)
+        }
+    }
+}
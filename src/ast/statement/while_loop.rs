@@ -0,0 +1,44 @@
+use crate::ast::{Block, Expression};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct While {
+    condition: Expression,
+    block: Block,
+}
+
+impl While {
+    /// Constructs a new [`While`] with the given condition and [`Block`].
+    pub fn new<T: Into<Expression>>(condition: T, block: Block) -> Self {
+        Self {
+            condition: condition.into(),
+            block,
+        }
+    }
+}
+
+impl While {
+    /// Returns the condition of this [`While`].
+    pub fn condition(&self) -> &Expression {
+        &self.condition
+    }
+
+    /// Returns a mutable reference to the condition of this [`While`].
+    pub fn condition_mut(&mut self) -> &mut Expression {
+        &mut self.condition
+    }
+
+    /// Returns the [`Block`] of this [`While`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns a mutable reference to the [`Block`] of this [`While`].
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
+    /// Consumes this [`While`], returning its condition and [`Block`].
+    pub fn into_parts(self) -> (Expression, Block) {
+        (self.condition, self.block)
+    }
+}
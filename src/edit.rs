@@ -0,0 +1,53 @@
+//! Edits to source text, as produced by refactorings and range formatting.
+use crate::parser::Span;
+
+/// A replacement of a [`Span`] of the source with new text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    span: Span,
+    text: String,
+}
+
+impl TextEdit {
+    /// Constructs a new [`TextEdit`] replacing the given [`Span`] with the given text.
+    pub fn new<T: AsRef<str>>(span: Span, text: T) -> Self {
+        Self {
+            span,
+            text: text.as_ref().to_string(),
+        }
+    }
+
+    /// Returns the [`Span`] replaced by this [`TextEdit`].
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Returns the replacement text of this [`TextEdit`].
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Applies the given edits to the source, which must not overlap.
+/// ```
+/// use luna::edit::{apply_edits, TextEdit};
+///
+/// let edits = vec![TextEdit::new(6..7, "y"), TextEdit::new(0..5, "print")];
+/// assert_eq!(apply_edits("local x", &edits), "print y");
+/// ```
+pub fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+    let mut edits = edits.iter().collect::<Vec<_>>();
+    edits.sort_by_key(|edit| edit.span().start);
+
+    let mut result = String::with_capacity(source.len());
+    let mut last = 0;
+
+    for edit in edits {
+        result.push_str(&source[last..edit.span().start]);
+        result.push_str(edit.text());
+        last = edit.span().end;
+    }
+
+    result.push_str(&source[last..]);
+    result
+}
@@ -9,10 +9,28 @@ impl Associativity for Expression {
             Self::BooleanLiteral(expr) => expr.associativity(),
             Self::NumberLiteral(expr) => expr.associativity(),
             Self::StringLiteral(expr) => expr.associativity(),
+            Self::VarargLiteral(expr) => expr.associativity(),
+            Self::InterpolatedString(expr) => expr.associativity(),
+
+            // Variable
+            Self::Identifier(_) => AssociativityValue::none(),
+            Self::Index(_) => AssociativityValue::none(),
+
+            // Constructor
+            Self::Function(_) => AssociativityValue::none(),
+            Self::TableConstructor(_) => AssociativityValue::none(),
+
+            // Call
+            Self::FunctionCall(_) => AssociativityValue::none(),
 
             // Operation
             Self::UnaryOperation(expr) => expr.associativity(),
             Self::BinaryOperation(expr) => expr.associativity(),
+
+            // Other
+            Self::Parenthesized(_) => AssociativityValue::none(),
+            Self::IfExpression(expr) => expr.associativity(),
+            Self::TypeAssertion(expr) => expr.associativity(),
         }
     }
 }
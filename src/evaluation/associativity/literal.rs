@@ -1,5 +1,7 @@
 use super::{Associativity, AssociativityValue};
-use crate::ast::{BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral};
+use crate::ast::{
+    BooleanLiteral, InterpolatedString, NilLiteral, NumberLiteral, StringLiteral, VarargLiteral,
+};
 
 impl Associativity for NilLiteral {
     fn associativity(&self) -> AssociativityValue {
@@ -24,3 +26,15 @@ impl Associativity for StringLiteral {
         AssociativityValue::none()
     }
 }
+
+impl Associativity for VarargLiteral {
+    fn associativity(&self) -> AssociativityValue {
+        AssociativityValue::none()
+    }
+}
+
+impl Associativity for InterpolatedString {
+    fn associativity(&self) -> AssociativityValue {
+        AssociativityValue::none()
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Associativity, AssociativityValue};
-use crate::ast::{BinaryOperation, UnaryOperation};
+use crate::ast::{BinaryOperation, IfExpression, TypeAssertion, UnaryOperation};
 
 impl Associativity for UnaryOperation {
     fn associativity(&self) -> AssociativityValue {
@@ -12,3 +12,15 @@ impl Associativity for BinaryOperation {
         self.operator().associativity()
     }
 }
+
+impl Associativity for TypeAssertion {
+    fn associativity(&self) -> AssociativityValue {
+        AssociativityValue::none()
+    }
+}
+
+impl Associativity for IfExpression {
+    fn associativity(&self) -> AssociativityValue {
+        AssociativityValue::none()
+    }
+}
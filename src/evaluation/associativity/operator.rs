@@ -30,8 +30,10 @@ impl Associativity for BinaryOperator {
             Self::Subtract => AssociativityValue::left(),
             Self::Multiply => AssociativityValue::full(),
             Self::Divide => AssociativityValue::left(),
+            Self::FloorDivide => AssociativityValue::left(),
             Self::Modulo => AssociativityValue::left(),
             Self::Power => AssociativityValue::right(),
+            Self::Concat => AssociativityValue::right(),
             Self::And => AssociativityValue::full(),
             Self::Or => AssociativityValue::full(),
             Self::Equal => AssociativityValue::left(),
@@ -0,0 +1,23 @@
+use crate::ast::Expression;
+use crate::evaluation::{Constant, ConstantValue};
+
+impl Constant for Expression {
+    fn constant(&self) -> Option<ConstantValue> {
+        match self {
+            // Literal
+            Self::NilLiteral(expr) => expr.constant(),
+            Self::BooleanLiteral(expr) => expr.constant(),
+            Self::NumberLiteral(expr) => expr.constant(),
+            Self::StringLiteral(expr) => expr.constant(),
+
+            // Operation
+            Self::UnaryOperation(expr) => expr.constant(),
+            Self::BinaryOperation(expr) => expr.constant(),
+
+            // Parenthesis only truncate multiple values, and a constant is always one value.
+            Self::Parenthesized(expr) => expr.expression().constant(),
+
+            _ => None,
+        }
+    }
+}
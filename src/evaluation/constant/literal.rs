@@ -0,0 +1,26 @@
+use super::{parse_number, unquote_string, Constant, ConstantValue};
+use crate::ast::{BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral};
+
+impl Constant for NilLiteral {
+    fn constant(&self) -> Option<ConstantValue> {
+        Some(ConstantValue::Nil)
+    }
+}
+
+impl Constant for BooleanLiteral {
+    fn constant(&self) -> Option<ConstantValue> {
+        Some(ConstantValue::Boolean(self.value()))
+    }
+}
+
+impl Constant for NumberLiteral {
+    fn constant(&self) -> Option<ConstantValue> {
+        parse_number(self.value()).map(ConstantValue::Number)
+    }
+}
+
+impl Constant for StringLiteral {
+    fn constant(&self) -> Option<ConstantValue> {
+        unquote_string(self.value()).map(ConstantValue::String)
+    }
+}
@@ -0,0 +1,115 @@
+//! Folding of expressions made only of literals, following the runtime semantics of Luau.
+//! ```
+//! use luna::ast::{BinaryOperation, NilLiteral, NumberLiteral, StringLiteral, UnaryOperation};
+//! use luna::evaluation::{Constant, ConstantValue};
+//!
+//! let expression = BinaryOperation::concat(
+//!     StringLiteral::from("\"count: \""),
+//!     BinaryOperation::modulo(
+//!         UnaryOperation::negate(NumberLiteral::from("7")),
+//!         NumberLiteral::from("3"),
+//!     ),
+//! );
+//!
+//! let value = expression.constant().unwrap();
+//! assert_eq!(value, ConstantValue::from("count: 2"));
+//! assert_eq!(value.into_expression(), StringLiteral::from("\"count: 2\"").into());
+//!
+//! // Concatenating `nil` is an error at runtime, so it is not constant.
+//! let expression = BinaryOperation::concat(StringLiteral::from("'a'"), NilLiteral);
+//! assert_eq!(expression.constant(), None);
+//! ```
+mod expression;
+mod literal;
+mod number;
+mod operation;
+mod string;
+
+use crate::ast::{
+    BinaryOperation, BooleanLiteral, Expression, NilLiteral, NumberLiteral, StringLiteral,
+    UnaryOperation,
+};
+
+pub(crate) use number::{arithmetic, format_number, parse_number, string_to_number};
+pub(crate) use string::{quote_string, unescape_segment, unquote_string};
+
+/// The value of an expression that is known without running it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Nil,
+    Boolean(bool),
+    Number(f64),
+    /// The bytes of a string, which need not be valid UTF-8.
+    String(Vec<u8>),
+}
+
+impl ConstantValue {
+    /// Returns whether this [`ConstantValue`] is truthy, which is every value but `nil` and `false`.
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Self::Nil | Self::Boolean(false))
+    }
+
+    /// Returns this [`ConstantValue`] as a number, converting strings as arithmetic does.
+    pub fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            Self::String(bytes) => string_to_number(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns this [`ConstantValue`] as a string, converting numbers as concatenation does.
+    pub fn to_concat_string(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Number(number) => Some(format_number(*number).into_bytes()),
+            Self::String(bytes) => Some(bytes.clone()),
+            _ => None,
+        }
+    }
+
+    /// Consumes this [`ConstantValue`], returning the simplest [`Expression`] that evaluates to it.
+    ///
+    /// Negative numbers are negations of a literal, and numbers that have no literal, such as
+    /// infinity, are divisions by zero.
+    pub fn into_expression(self) -> Expression {
+        match self {
+            Self::Nil => NilLiteral.into(),
+            Self::Boolean(value) => BooleanLiteral::from(value).into(),
+            Self::String(bytes) => StringLiteral::from(quote_string(&bytes)).into(),
+            Self::Number(number) if number.is_nan() => {
+                BinaryOperation::divide(NumberLiteral::from("0"), NumberLiteral::from("0")).into()
+            }
+            Self::Number(number) if number.is_sign_negative() => {
+                let magnitude = Self::Number(-number).into_expression();
+                UnaryOperation::negate(magnitude).into()
+            }
+            Self::Number(number) if number.is_infinite() => {
+                BinaryOperation::divide(NumberLiteral::from("1"), NumberLiteral::from("0")).into()
+            }
+            Self::Number(number) => NumberLiteral::from(format_number(number)).into(),
+        }
+    }
+}
+
+impl From<bool> for ConstantValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<f64> for ConstantValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for ConstantValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.as_bytes().to_vec())
+    }
+}
+
+pub trait Constant {
+    /// Returns the value of this expression, or `None` if it is not constant or would error.
+    fn constant(&self) -> Option<ConstantValue>;
+}
@@ -0,0 +1,122 @@
+use crate::ast::BinaryOperator;
+
+/// Parses the text of a number literal, which may be hexadecimal, binary or contain `_` separators.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let digits = text.replace('_', "");
+
+    match digits.get(..2) {
+        Some("0x" | "0X") => parse_integer(&digits[2..], 16),
+        Some("0b" | "0B") => parse_integer(&digits[2..], 2),
+        _ => parse_decimal(&digits),
+    }
+}
+
+/// Converts a string to a number as arithmetic does, allowing surrounding whitespace, a sign and
+/// hexadecimal, but not binary or `_` separators.
+pub(crate) fn string_to_number(bytes: &[u8]) -> Option<f64> {
+    let text = std::str::from_utf8(bytes).ok()?.trim_matches(is_space);
+
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+
+    let number = match unsigned.get(..2) {
+        Some("0x" | "0X") => parse_integer(&unsigned[2..], 16)?,
+        _ if unsigned.contains(['+', '-']) && !unsigned.contains(['e', 'E']) => return None,
+        _ => parse_decimal(unsigned)?,
+    };
+
+    Some(if negative { -number } else { number })
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r' | '\x0b' | '\x0c')
+}
+
+/// Parses an unsigned integer, which wraps around like Luau does for literals larger than 64 bits.
+fn parse_integer(digits: &str, radix: u32) -> Option<f64> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits
+        .chars()
+        .try_fold(0u64, |value, c| {
+            let digit = c.to_digit(radix)?;
+            Some(value.wrapping_mul(radix as u64).wrapping_add(digit as u64))
+        })
+        .map(|value| value as f64)
+}
+
+fn parse_decimal(digits: &str) -> Option<f64> {
+    let valid = digits.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && digits.contains(|c: char| c.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'));
+
+    match valid {
+        // Rust does not accept a fraction without digits before an exponent, such as `1.e5`.
+        true => digits.replace(".e", "e").replace(".E", "E").parse().ok(),
+        false => None,
+    }
+}
+
+/// Formats a number as Luau converts it to a string, with the fewest digits that read back as the
+/// same number, in scientific notation when its exponent is below -5 or above 20.
+pub(crate) fn format_number(number: f64) -> String {
+    if number.is_nan() {
+        return String::from("nan");
+    }
+
+    if number.is_infinite() {
+        return String::from(if number < 0.0 { "-inf" } else { "inf" });
+    }
+
+    if number == 0.0 {
+        return String::from(if number.is_sign_negative() { "-0" } else { "0" });
+    }
+
+    let sign = if number < 0.0 { "-" } else { "" };
+
+    // Rust formats the shortest digits that round trip, such as `1.5e-7`.
+    let scientific = format!("{:e}", number.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    let digits = mantissa.replace('.', "");
+
+    if !(-5..=20).contains(&exponent) {
+        let exponent_sign = if exponent < 0 { '-' } else { '+' };
+        return format!("{sign}{mantissa}e{exponent_sign}{:02}", exponent.abs());
+    }
+
+    let point = exponent + 1;
+
+    if point <= 0 {
+        format!("{sign}0.{}{digits}", "0".repeat(-point as usize))
+    } else if point as usize >= digits.len() {
+        format!(
+            "{sign}{digits}{}",
+            "0".repeat(point as usize - digits.len())
+        )
+    } else {
+        let (whole, fraction) = digits.split_at(point as usize);
+        format!("{sign}{whole}.{fraction}")
+    }
+}
+
+/// Applies an arithmetic operator to two numbers, as Luau does with doubles.
+pub(crate) fn arithmetic(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+    match operator {
+        BinaryOperator::Add => Some(left + right),
+        BinaryOperator::Subtract => Some(left - right),
+        BinaryOperator::Multiply => Some(left * right),
+        BinaryOperator::Divide => Some(left / right),
+        BinaryOperator::FloorDivide => Some((left / right).floor()),
+        // The result takes the sign of the divisor, so `-7 % 3` is `2`.
+        BinaryOperator::Modulo => Some(left - (left / right).floor() * right),
+        BinaryOperator::Power => Some(left.powf(right)),
+        _ => None,
+    }
+}
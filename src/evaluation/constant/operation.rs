@@ -0,0 +1,77 @@
+use std::cmp::Ordering;
+
+use super::{arithmetic, Constant, ConstantValue};
+use crate::ast::{BinaryOperation, BinaryOperator, UnaryOperation, UnaryOperator};
+
+impl Constant for UnaryOperation {
+    fn constant(&self) -> Option<ConstantValue> {
+        let operand = self.operand().constant()?;
+
+        match self.operator() {
+            UnaryOperator::Not => Some(ConstantValue::Boolean(!operand.is_truthy())),
+            UnaryOperator::Negate => operand.to_number().map(|n| ConstantValue::Number(-n)),
+            UnaryOperator::Length => match operand {
+                ConstantValue::String(bytes) => Some(ConstantValue::Number(bytes.len() as f64)),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl Constant for BinaryOperation {
+    fn constant(&self) -> Option<ConstantValue> {
+        let left = self.left().constant()?;
+
+        // The right operand is never evaluated when `and` and `or` short circuit.
+        match self.operator() {
+            BinaryOperator::And if !left.is_truthy() => return Some(left),
+            BinaryOperator::Or if left.is_truthy() => return Some(left),
+            BinaryOperator::And | BinaryOperator::Or => return self.right().constant(),
+            _ => {}
+        }
+
+        let right = self.right().constant()?;
+
+        let ordering = || compare(&left, &right);
+
+        match self.operator() {
+            BinaryOperator::Equal => Some(ConstantValue::Boolean(left == right)),
+            BinaryOperator::NotEqual => Some(ConstantValue::Boolean(left != right)),
+
+            BinaryOperator::LessThan => ordering().map(|o| (o == Some(Ordering::Less)).into()),
+            BinaryOperator::GreaterThan => {
+                ordering().map(|o| (o == Some(Ordering::Greater)).into())
+            }
+            BinaryOperator::LessThanOrEqual => {
+                ordering().map(|o| matches!(o, Some(Ordering::Less | Ordering::Equal)).into())
+            }
+            BinaryOperator::GreaterThanOrEqual => {
+                ordering().map(|o| matches!(o, Some(Ordering::Greater | Ordering::Equal)).into())
+            }
+
+            BinaryOperator::Concat => {
+                let mut bytes = left.to_concat_string()?;
+                bytes.extend(right.to_concat_string()?);
+
+                Some(ConstantValue::String(bytes))
+            }
+
+            operator => {
+                let (left, right) = (left.to_number()?, right.to_number()?);
+                arithmetic(operator, left, right).map(ConstantValue::Number)
+            }
+        }
+    }
+}
+
+/// Compares two values as the ordering operators do, where only two numbers or two strings can be
+/// compared, and `NaN` is unordered.
+fn compare(left: &ConstantValue, right: &ConstantValue) -> Option<Option<Ordering>> {
+    match (left, right) {
+        (ConstantValue::Number(left), ConstantValue::Number(right)) => {
+            Some(left.partial_cmp(right))
+        }
+        (ConstantValue::String(left), ConstantValue::String(right)) => Some(Some(left.cmp(right))),
+        _ => None,
+    }
+}
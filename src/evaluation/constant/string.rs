@@ -0,0 +1,146 @@
+/// Decodes the source text of a string literal into its bytes, or `None` if an escape is invalid.
+pub(crate) fn unquote_string(text: &str) -> Option<Vec<u8>> {
+    match text.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let inner = text.strip_prefix(quote)?.strip_suffix(quote)?;
+            unescape(inner.as_bytes(), &[])
+        }
+        '[' => {
+            let level = text[1..].find('[')?;
+            let inner = text.get(level + 2..text.len().checked_sub(level + 2)?)?;
+
+            // A newline directly after the opening bracket is not part of the string.
+            let inner = inner
+                .strip_prefix("\r\n")
+                .or_else(|| inner.strip_prefix('\n'))
+                .unwrap_or(inner);
+
+            Some(inner.as_bytes().to_vec())
+        }
+        _ => None,
+    }
+}
+
+/// Decodes a segment of an interpolated string, as written between its backticks and braces, into
+/// its bytes, or `None` if an escape is invalid.
+pub(crate) fn unescape_segment(text: &str) -> Option<Vec<u8>> {
+    unescape(text.as_bytes(), b"`{")
+}
+
+/// Decodes the escapes of the given bytes, where the given delimiters may also be escaped as
+/// themselves.
+fn unescape(bytes: &[u8], delimiters: &[u8]) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] != b'\\' {
+            output.push(bytes[index]);
+            index += 1;
+            continue;
+        }
+
+        index += 1;
+        let escape = *bytes.get(index)?;
+        index += 1;
+
+        match escape {
+            b'a' => output.push(0x07),
+            b'b' => output.push(0x08),
+            b'f' => output.push(0x0c),
+            b'n' => output.push(b'\n'),
+            b'r' => output.push(b'\r'),
+            b't' => output.push(b'\t'),
+            b'v' => output.push(0x0b),
+            b'\\' | b'"' | b'\'' | b'\n' => output.push(escape),
+            escape if delimiters.contains(&escape) => output.push(escape),
+            b'\r' => {
+                output.push(b'\n');
+
+                if bytes.get(index) == Some(&b'\n') {
+                    index += 1;
+                }
+            }
+            b'z' => {
+                while bytes.get(index).is_some_and(u8::is_ascii_whitespace) {
+                    index += 1;
+                }
+            }
+            b'x' => {
+                let digits = bytes.get(index..index + 2)?;
+
+                if !digits.iter().all(u8::is_ascii_hexdigit) {
+                    return None;
+                }
+
+                output.push(u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?);
+                index += 2;
+            }
+            b'u' => {
+                let close = index + bytes[index..].iter().position(|b| *b == b'}')?;
+                let digits = std::str::from_utf8(bytes.get(index + 1..close)?).ok()?;
+
+                if bytes[index] != b'{'
+                    || digits.is_empty()
+                    || !digits.chars().all(|c| c.is_ascii_hexdigit())
+                {
+                    return None;
+                }
+
+                let code = char::from_u32(u32::from_str_radix(digits, 16).ok()?)?;
+                output.extend(code.to_string().as_bytes());
+                index = close + 1;
+            }
+            b'0'..=b'9' => {
+                let mut value = (escape - b'0') as u32;
+
+                for _ in 0..2 {
+                    match bytes.get(index) {
+                        Some(digit @ b'0'..=b'9') => {
+                            value = value * 10 + (digit - b'0') as u32;
+                            index += 1;
+                        }
+                        _ => break,
+                    }
+                }
+
+                output.push(u8::try_from(value).ok()?);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(output)
+}
+
+/// Encodes bytes as a double quoted string literal, escaping anything that is not printable.
+pub(crate) fn quote_string(bytes: &[u8]) -> String {
+    let mut output = String::from("\"");
+
+    for chunk in bytes.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            match c {
+                '"' => output.push_str("\\\""),
+                '\\' => output.push_str("\\\\"),
+                '\n' => output.push_str("\\n"),
+                '\r' => output.push_str("\\r"),
+                '\t' => output.push_str("\\t"),
+                c if c.is_control() => {
+                    let mut buffer = [0; 4];
+
+                    for byte in c.encode_utf8(&mut buffer).bytes() {
+                        output.push_str(&format!("\\{:03}", byte));
+                    }
+                }
+                c => output.push(c),
+            }
+        }
+
+        for byte in chunk.invalid() {
+            output.push_str(&format!("\\{:03}", byte));
+        }
+    }
+
+    output.push('"');
+    output
+}
@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::Value;
+
+/// A variable, which is shared with the closures that capture it.
+pub(crate) type Variable<'a> = Rc<RefCell<Value<'a>>>;
+
+/// The locals of a scope, which are looked up from the innermost scope outwards.
+pub(crate) struct Environment<'a> {
+    variables: RefCell<Vec<(&'a str, Variable<'a>)>>,
+    /// The extra arguments of the function this scope starts, or `None` for a nested scope.
+    varargs: Option<Vec<Value<'a>>>,
+    parent: Option<Rc<Environment<'a>>>,
+}
+
+impl<'a> Environment<'a> {
+    /// Constructs a new [`Environment`] for the body of a function, with the given varargs.
+    pub(crate) fn function(parent: Option<Rc<Self>>, varargs: Vec<Value<'a>>) -> Rc<Self> {
+        Rc::new(Self {
+            variables: RefCell::new(Vec::new()),
+            varargs: Some(varargs),
+            parent,
+        })
+    }
+
+    /// Constructs a new [`Environment`] for a block nested in the given one.
+    pub(crate) fn block(parent: &Rc<Self>) -> Rc<Self> {
+        Rc::new(Self {
+            variables: RefCell::new(Vec::new()),
+            varargs: None,
+            parent: Some(parent.clone()),
+        })
+    }
+
+    /// Declares a new local, shadowing any other local of the same name.
+    pub(crate) fn declare(&self, name: &'a str, value: Value<'a>) {
+        self.variables
+            .borrow_mut()
+            .push((name, Rc::new(RefCell::new(value))));
+    }
+
+    /// Returns the variable of the innermost local of the given name.
+    pub(crate) fn lookup(&self, name: &str) -> Option<Variable<'a>> {
+        let variable = self
+            .variables
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(local, _)| *local == name)
+            .map(|(_, variable)| variable.clone());
+
+        match variable {
+            Some(variable) => Some(variable),
+            None => self.parent.as_ref()?.lookup(name),
+        }
+    }
+
+    /// Returns the varargs of the innermost function.
+    pub(crate) fn varargs(&self) -> Vec<Value<'a>> {
+        match (&self.varargs, &self.parent) {
+            (Some(varargs), _) => varargs.clone(),
+            (None, Some(parent)) => parent.varargs(),
+            (None, None) => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use std::rc::Rc;
+
+use super::{Environment, Interpreter, RuntimeError, TableRef, Value};
+use crate::ast::{
+    BinaryOperator, Expression, FunctionCall, IndexKey, TableConstructor, TableField, UnaryOperator,
+};
+use crate::evaluation::constant::{
+    arithmetic, format_number, parse_number, unescape_segment, unquote_string,
+};
+
+impl<'a> Interpreter<'a> {
+    /// Evaluates an expression to a single value, truncating calls and varargs to their first value.
+    pub(crate) fn evaluate(
+        &mut self,
+        expression: &'a Expression,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Value<'a>, RuntimeError> {
+        match expression {
+            Expression::NilLiteral(_) => Ok(Value::Nil),
+            Expression::BooleanLiteral(literal) => Ok(Value::Boolean(literal.value())),
+            Expression::NumberLiteral(literal) => match parse_number(literal.value()) {
+                Some(number) => Ok(Value::Number(number)),
+                None => Err(RuntimeError::new("malformed number")),
+            },
+            Expression::StringLiteral(literal) => match unquote_string(literal.value()) {
+                Some(bytes) => Ok(Value::from(bytes)),
+                None => Err(RuntimeError::new("malformed string")),
+            },
+            Expression::InterpolatedString(string) => {
+                let malformed = || RuntimeError::new("malformed string");
+
+                let (first, segments) = string.segments().split_first().ok_or_else(malformed)?;
+                let mut bytes = unescape_segment(first).ok_or_else(malformed)?;
+
+                // Each value is converted as `tostring` does.
+                for (expression, segment) in string.expressions().iter().zip(segments) {
+                    let value = self.evaluate(expression, environment)?;
+                    bytes.extend(self.to_string(&value)?);
+                    bytes.extend(unescape_segment(segment).ok_or_else(malformed)?);
+                }
+
+                Ok(Value::from(bytes))
+            }
+            Expression::VarargLiteral(_) | Expression::FunctionCall(_) => Ok(self
+                .evaluate_multiple(expression, environment)?
+                .into_iter()
+                .next()
+                .unwrap_or_default()),
+
+            Expression::Identifier(identifier) => Ok(match environment.lookup(identifier.name()) {
+                Some(variable) => variable.borrow().clone(),
+                None => self.global(identifier.name()),
+            }),
+            Expression::Index(index) => {
+                let object = self.evaluate(index.object(), environment)?;
+                let key = self.evaluate_key(index.key(), environment)?;
+
+                self.index(&object, key)
+            }
+
+            Expression::Function(function) => {
+                Ok(Value::closure(function, environment.clone(), false))
+            }
+            Expression::TableConstructor(table) => self.construct_table(table, environment),
+
+            Expression::UnaryOperation(operation) => {
+                let operand = self.evaluate(operation.operand(), environment)?;
+
+                match operation.operator() {
+                    UnaryOperator::Not => Ok(Value::Boolean(!operand.is_truthy())),
+                    UnaryOperator::Negate => self.negate(operand),
+                    UnaryOperator::Length => self.length(operand),
+                }
+            }
+            Expression::BinaryOperation(operation) => {
+                let left = self.evaluate(operation.left(), environment)?;
+
+                match operation.operator() {
+                    BinaryOperator::And if !left.is_truthy() => Ok(left),
+                    BinaryOperator::Or if left.is_truthy() => Ok(left),
+                    BinaryOperator::And | BinaryOperator::Or => {
+                        self.evaluate(operation.right(), environment)
+                    }
+                    operator => {
+                        let right = self.evaluate(operation.right(), environment)?;
+                        self.binary(operator, left, right)
+                    }
+                }
+            }
+
+            Expression::Parenthesized(parenthesized) => {
+                self.evaluate(parenthesized.expression(), environment)
+            }
+            Expression::IfExpression(expression) => {
+                if self
+                    .evaluate(expression.condition(), environment)?
+                    .is_truthy()
+                {
+                    return self.evaluate(expression.consequent(), environment);
+                }
+
+                for (condition, consequent) in expression.else_ifs() {
+                    if self.evaluate(condition, environment)?.is_truthy() {
+                        return self.evaluate(consequent, environment);
+                    }
+                }
+
+                self.evaluate(expression.alternative(), environment)
+            }
+            Expression::TypeAssertion(assertion) => {
+                self.evaluate(assertion.expression(), environment)
+            }
+        }
+    }
+
+    /// Evaluates an expression to all of its values, which is more than one only for calls and varargs.
+    pub(crate) fn evaluate_multiple(
+        &mut self,
+        expression: &'a Expression,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Vec<Value<'a>>, RuntimeError> {
+        match expression {
+            Expression::VarargLiteral(_) => Ok(environment.varargs()),
+            Expression::FunctionCall(call) => self.evaluate_call(call, environment),
+            _ => Ok(vec![self.evaluate(expression, environment)?]),
+        }
+    }
+
+    /// Evaluates a list of expressions, where only the last expands to all of its values.
+    pub(crate) fn evaluate_list(
+        &mut self,
+        expressions: &'a [Expression],
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Vec<Value<'a>>, RuntimeError> {
+        let mut values = Vec::with_capacity(expressions.len());
+
+        if let Some((last, rest)) = expressions.split_last() {
+            for expression in rest {
+                values.push(self.evaluate(expression, environment)?);
+            }
+
+            values.extend(self.evaluate_multiple(last, environment)?);
+        }
+
+        Ok(values)
+    }
+
+    pub(crate) fn evaluate_key(
+        &mut self,
+        key: &'a IndexKey,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Value<'a>, RuntimeError> {
+        match key {
+            IndexKey::Field(name) => Ok(Value::from(name.as_str())),
+            IndexKey::Computed(key) => self.evaluate(key, environment),
+        }
+    }
+
+    pub(crate) fn evaluate_call(
+        &mut self,
+        call: &'a FunctionCall,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Vec<Value<'a>>, RuntimeError> {
+        let object = self.evaluate(call.function(), environment)?;
+
+        let (function, mut arguments) = match call.method_name() {
+            Some(method) => {
+                let function = self.index(&object, Value::from(method))?;
+                (function, vec![object])
+            }
+            None => (object, Vec::new()),
+        };
+
+        arguments.extend(self.evaluate_list(call.arguments(), environment)?);
+        self.call(&function, arguments)
+    }
+
+    fn construct_table(
+        &mut self,
+        constructor: &'a TableConstructor,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Value<'a>, RuntimeError> {
+        let table = TableRef::default();
+        let mut position = 1.0;
+
+        for (index, field) in constructor.fields().iter().enumerate() {
+            let is_last = index + 1 == constructor.fields().len();
+
+            match field {
+                TableField::Named(name, value) => {
+                    let value = self.evaluate(value, environment)?;
+                    table.borrow_mut().set(Value::from(name.as_str()), value)?;
+                }
+                TableField::Keyed(key, value) => {
+                    let key = self.evaluate(key, environment)?;
+                    let value = self.evaluate(value, environment)?;
+                    table.borrow_mut().set(key, value)?;
+                }
+                TableField::Positional(value) => {
+                    let values = match is_last {
+                        true => self.evaluate_multiple(value, environment)?,
+                        false => vec![self.evaluate(value, environment)?],
+                    };
+
+                    for value in values {
+                        table.borrow_mut().set(Value::Number(position), value)?;
+                        position += 1.0;
+                    }
+                }
+            }
+        }
+
+        Ok(table.into())
+    }
+}
+
+impl<'a> Interpreter<'a> {
+    /// Indexes a value, following `__index` metamethods.
+    pub fn index(&mut self, object: &Value<'a>, key: Value<'a>) -> Result<Value<'a>, RuntimeError> {
+        if let Value::Table(table) = object {
+            let value = table.borrow().get(&key);
+
+            if !value.is_nil() {
+                return Ok(value);
+            }
+        }
+
+        match self.metamethod(object, "__index") {
+            Some(Value::Function(function)) => {
+                let function = Value::Function(function);
+                let results = self.call(&function, vec![object.clone(), key])?;
+
+                Ok(results.into_iter().next().unwrap_or_default())
+            }
+            Some(metamethod) => self.index(&metamethod, key),
+            None if object.as_table().is_some() => Ok(Value::Nil),
+            None => Err(RuntimeError::new(format!(
+                "attempt to index a {} value",
+                object.type_name()
+            ))),
+        }
+    }
+
+    /// Sets a key of a value, following `__newindex` metamethods when the key is absent.
+    pub fn set_index(
+        &mut self,
+        object: &Value<'a>,
+        key: Value<'a>,
+        value: Value<'a>,
+    ) -> Result<(), RuntimeError> {
+        if let Value::Table(table) = object {
+            let present = !table.borrow().get(&key).is_nil();
+
+            if present || self.metamethod(object, "__newindex").is_none() {
+                return table.borrow_mut().set(key, value);
+            }
+        }
+
+        match self.metamethod(object, "__newindex") {
+            Some(Value::Function(function)) => {
+                let function = Value::Function(function);
+                self.call(&function, vec![object.clone(), key, value])?;
+
+                Ok(())
+            }
+            Some(metamethod) => self.set_index(&metamethod, key, value),
+            None => Err(RuntimeError::new(format!(
+                "attempt to index a {} value",
+                object.type_name()
+            ))),
+        }
+    }
+
+    /// Applies a binary operator other than `and` and `or` to two values.
+    pub(crate) fn binary(
+        &mut self,
+        operator: BinaryOperator,
+        left: Value<'a>,
+        right: Value<'a>,
+    ) -> Result<Value<'a>, RuntimeError> {
+        match operator {
+            BinaryOperator::Equal => self.equals(left, right).map(Value::Boolean),
+            BinaryOperator::NotEqual => {
+                self.equals(left, right).map(|equal| Value::Boolean(!equal))
+            }
+            BinaryOperator::LessThan => self.less_than(left, right).map(Value::Boolean),
+            BinaryOperator::LessThanOrEqual => self.less_or_equal(left, right).map(Value::Boolean),
+            BinaryOperator::GreaterThan => self.less_than(right, left).map(Value::Boolean),
+            BinaryOperator::GreaterThanOrEqual => {
+                self.less_or_equal(right, left).map(Value::Boolean)
+            }
+            BinaryOperator::Concat => match (left.to_concat_bytes(), right.to_concat_bytes()) {
+                (Some(mut bytes), Some(right)) => {
+                    bytes.extend(right);
+                    Ok(Value::from(bytes))
+                }
+                _ => self.binary_metamethod("__concat", "concatenate", left, right),
+            },
+            operator => match (to_number(&left), to_number(&right)) {
+                (Some(l), Some(r)) => Ok(Value::Number(arithmetic(operator, l, r).unwrap())),
+                _ => {
+                    let event = match operator {
+                        BinaryOperator::Add => "__add",
+                        BinaryOperator::Subtract => "__sub",
+                        BinaryOperator::Multiply => "__mul",
+                        BinaryOperator::Divide => "__div",
+                        BinaryOperator::FloorDivide => "__idiv",
+                        BinaryOperator::Modulo => "__mod",
+                        _ => "__pow",
+                    };
+
+                    self.binary_metamethod(event, "perform arithmetic on", left, right)
+                }
+            },
+        }
+    }
+
+    fn binary_metamethod(
+        &mut self,
+        event: &str,
+        action: &str,
+        left: Value<'a>,
+        right: Value<'a>,
+    ) -> Result<Value<'a>, RuntimeError> {
+        let metamethod = self
+            .metamethod(&left, event)
+            .or_else(|| self.metamethod(&right, event));
+
+        match metamethod {
+            Some(metamethod) => Ok(first(self.call(&metamethod, vec![left, right])?)),
+            None => {
+                // Blame the operand that cannot take part in the operation.
+                let blamed = match event {
+                    "__concat" if left.to_concat_bytes().is_some() => &right,
+                    "__concat" => &left,
+                    _ if to_number(&left).is_some() => &right,
+                    _ => &left,
+                };
+
+                Err(RuntimeError::new(format!(
+                    "attempt to {} a {} value",
+                    action,
+                    blamed.type_name()
+                )))
+            }
+        }
+    }
+
+    /// Compares two values for equality, calling `__eq` when both are different tables.
+    pub(crate) fn equals(
+        &mut self,
+        left: Value<'a>,
+        right: Value<'a>,
+    ) -> Result<bool, RuntimeError> {
+        if left == right {
+            return Ok(true);
+        }
+
+        if let (Value::Table(_), Value::Table(_)) = (&left, &right) {
+            let metamethod = self
+                .metamethod(&left, "__eq")
+                .or_else(|| self.metamethod(&right, "__eq"));
+
+            if let Some(metamethod) = metamethod {
+                return Ok(first(self.call(&metamethod, vec![left, right])?).is_truthy());
+            }
+        }
+
+        Ok(false)
+    }
+
+    pub(crate) fn less_than(
+        &mut self,
+        left: Value<'a>,
+        right: Value<'a>,
+    ) -> Result<bool, RuntimeError> {
+        match (&left, &right) {
+            (Value::Number(l), Value::Number(r)) => Ok(l < r),
+            (Value::String(l), Value::String(r)) => Ok(l < r),
+            _ => self.compare_metamethod("__lt", left, right),
+        }
+    }
+
+    fn less_or_equal(&mut self, left: Value<'a>, right: Value<'a>) -> Result<bool, RuntimeError> {
+        match (&left, &right) {
+            (Value::Number(l), Value::Number(r)) => Ok(l <= r),
+            (Value::String(l), Value::String(r)) => Ok(l <= r),
+            _ => self.compare_metamethod("__le", left, right),
+        }
+    }
+
+    fn compare_metamethod(
+        &mut self,
+        event: &str,
+        left: Value<'a>,
+        right: Value<'a>,
+    ) -> Result<bool, RuntimeError> {
+        let metamethod = self
+            .metamethod(&left, event)
+            .or_else(|| self.metamethod(&right, event));
+
+        match metamethod {
+            Some(metamethod) => Ok(first(self.call(&metamethod, vec![left, right])?).is_truthy()),
+            None if left.type_name() == right.type_name() => Err(RuntimeError::new(format!(
+                "attempt to compare two {} values",
+                left.type_name()
+            ))),
+            None => Err(RuntimeError::new(format!(
+                "attempt to compare {} with {}",
+                left.type_name(),
+                right.type_name()
+            ))),
+        }
+    }
+
+    fn negate(&mut self, operand: Value<'a>) -> Result<Value<'a>, RuntimeError> {
+        match to_number(&operand) {
+            Some(number) => Ok(Value::Number(-number)),
+            None => match self.metamethod(&operand, "__unm") {
+                Some(metamethod) => Ok(first(self.call(&metamethod, vec![operand])?)),
+                None => Err(RuntimeError::new(format!(
+                    "attempt to perform arithmetic on a {} value",
+                    operand.type_name()
+                ))),
+            },
+        }
+    }
+
+    /// Returns the length of a value, as `#` does.
+    pub(crate) fn length(&mut self, operand: Value<'a>) -> Result<Value<'a>, RuntimeError> {
+        if let Some(metamethod) = self.metamethod(&operand, "__len") {
+            return Ok(first(self.call(&metamethod, vec![operand])?));
+        }
+
+        match &operand {
+            Value::String(bytes) => Ok(Value::Number(bytes.len() as f64)),
+            Value::Table(table) => Ok(Value::Number(table.borrow().length() as f64)),
+            _ => Err(RuntimeError::new(format!(
+                "attempt to get length of a {} value",
+                operand.type_name()
+            ))),
+        }
+    }
+
+    /// Converts a value to a string, as `tostring` does.
+    pub fn to_string(&mut self, value: &Value<'a>) -> Result<Vec<u8>, RuntimeError> {
+        if let Some(metamethod) = self.metamethod(value, "__tostring") {
+            return match first(self.call(&metamethod, vec![value.clone()])?) {
+                Value::String(bytes) => Ok(bytes.to_vec()),
+                _ => Err(RuntimeError::new("'__tostring' must return a string")),
+            };
+        }
+
+        Ok(match value {
+            Value::Number(number) => format_number(*number).into_bytes(),
+            Value::String(bytes) => bytes.to_vec(),
+            value => format!("{:?}", value).into_bytes(),
+        })
+    }
+}
+
+/// Converts a value to a number as arithmetic does, including strings of numbers.
+pub(crate) fn to_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => Some(*number),
+        Value::String(bytes) => crate::evaluation::constant::string_to_number(bytes),
+        _ => None,
+    }
+}
+
+/// Returns the first of the values a call returned, or `nil` if it returned none.
+pub(crate) fn first<'a>(values: Vec<Value<'a>>) -> Value<'a> {
+    values.into_iter().next().unwrap_or_default()
+}
@@ -0,0 +1,197 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use super::{expect_number, library, optional_number, Results, RuntimeError};
+use crate::evaluation::interpreter::{Interpreter, TableRef, Value};
+
+pub(super) fn open<'a>() -> TableRef<'a> {
+    let math = library(&[
+        ("abs", |_, a| unary(&a, "abs", f64::abs)),
+        ("acos", |_, a| unary(&a, "acos", f64::acos)),
+        ("asin", |_, a| unary(&a, "asin", f64::asin)),
+        ("atan", |_, a| unary(&a, "atan", f64::atan)),
+        ("atan2", atan2),
+        ("ceil", |_, a| unary(&a, "ceil", f64::ceil)),
+        ("clamp", clamp),
+        ("cos", |_, a| unary(&a, "cos", f64::cos)),
+        ("cosh", |_, a| unary(&a, "cosh", f64::cosh)),
+        ("deg", |_, a| unary(&a, "deg", f64::to_degrees)),
+        ("exp", |_, a| unary(&a, "exp", f64::exp)),
+        ("floor", |_, a| unary(&a, "floor", f64::floor)),
+        ("fmod", fmod),
+        ("log", log),
+        ("log10", |_, a| unary(&a, "log10", f64::log10)),
+        ("max", max),
+        ("min", min),
+        ("modf", modf),
+        ("pow", pow),
+        ("rad", |_, a| unary(&a, "rad", f64::to_radians)),
+        ("round", |_, a| unary(&a, "round", f64::round)),
+        ("sign", |_, a| unary(&a, "sign", sign)),
+        ("sin", |_, a| unary(&a, "sin", f64::sin)),
+        ("sinh", |_, a| unary(&a, "sinh", f64::sinh)),
+        ("sqrt", |_, a| unary(&a, "sqrt", f64::sqrt)),
+        ("tan", |_, a| unary(&a, "tan", f64::tan)),
+        ("tanh", |_, a| unary(&a, "tanh", f64::tanh)),
+    ]);
+
+    {
+        let mut table = math.borrow_mut();
+        let _ = table.set(Value::from("huge"), Value::Number(f64::INFINITY));
+        let _ = table.set(Value::from("pi"), Value::Number(std::f64::consts::PI));
+
+        // The generator is deterministic, starting from the same seed in every interpreter.
+        let state = Rc::new(Cell::new(0x2545_f491_4f6c_dd1d_u64));
+        let seed = state.clone();
+
+        let _ = table.set(
+            Value::from("random"),
+            Value::native(move |_, arguments| random(&state, &arguments)),
+        );
+        let _ = table.set(
+            Value::from("randomseed"),
+            Value::native(move |_, arguments| {
+                let number = expect_number(&arguments, 0, "randomseed")?;
+                seed.set((number as i64 as u64) | 1);
+
+                Ok(Vec::new())
+            }),
+        );
+    }
+
+    math
+}
+
+fn unary<'a>(arguments: &[Value<'a>], name: &str, function: fn(f64) -> f64) -> Results<'a> {
+    Ok(vec![Value::Number(function(expect_number(
+        arguments, 0, name,
+    )?))])
+}
+
+/// Returns the sign of a number, which is `0` for zero rather than keeping its sign.
+fn sign(number: f64) -> f64 {
+    match number {
+        number if number > 0.0 => 1.0,
+        number if number < 0.0 => -1.0,
+        _ => 0.0,
+    }
+}
+
+fn atan2<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let y = expect_number(&arguments, 0, "atan2")?;
+    let x = expect_number(&arguments, 1, "atan2")?;
+
+    Ok(vec![Value::Number(y.atan2(x))])
+}
+
+fn clamp<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let number = expect_number(&arguments, 0, "clamp")?;
+    let min = expect_number(&arguments, 1, "clamp")?;
+    let max = expect_number(&arguments, 2, "clamp")?;
+
+    if min > max {
+        return Err(RuntimeError::new(
+            "invalid argument #3 to 'clamp' (max must be greater than or equal to min)",
+        ));
+    }
+
+    Ok(vec![Value::Number(number.max(min).min(max))])
+}
+
+/// Returns the remainder of a division rounded towards zero, unlike `%`.
+fn fmod<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let left = expect_number(&arguments, 0, "fmod")?;
+    let right = expect_number(&arguments, 1, "fmod")?;
+
+    Ok(vec![Value::Number(left % right)])
+}
+
+fn log<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let number = expect_number(&arguments, 0, "log")?;
+
+    let result = match optional_number(&arguments, 1, "log", std::f64::consts::E)? {
+        2.0 => number.log2(),
+        10.0 => number.log10(),
+        base if base == std::f64::consts::E => number.ln(),
+        base => number.ln() / base.ln(),
+    };
+
+    Ok(vec![Value::Number(result)])
+}
+
+fn max<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let mut result = expect_number(&arguments, 0, "max")?;
+
+    for position in 1..arguments.len() {
+        let number = expect_number(&arguments, position, "max")?;
+
+        if number > result {
+            result = number;
+        }
+    }
+
+    Ok(vec![Value::Number(result)])
+}
+
+fn min<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let mut result = expect_number(&arguments, 0, "min")?;
+
+    for position in 1..arguments.len() {
+        let number = expect_number(&arguments, position, "min")?;
+
+        if number < result {
+            result = number;
+        }
+    }
+
+    Ok(vec![Value::Number(result)])
+}
+
+fn modf<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let number = expect_number(&arguments, 0, "modf")?;
+
+    let fraction = match number.is_infinite() {
+        true => 0.0,
+        false => number.fract(),
+    };
+
+    Ok(vec![Value::Number(number.trunc()), Value::Number(fraction)])
+}
+
+fn pow<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let base = expect_number(&arguments, 0, "pow")?;
+    let exponent = expect_number(&arguments, 1, "pow")?;
+
+    Ok(vec![Value::Number(base.powf(exponent))])
+}
+
+/// Returns a number in `[0, 1)`, or an integer in `[1, m]` or `[m, n]`.
+fn random<'a>(state: &Cell<u64>, arguments: &[Value<'a>]) -> Results<'a> {
+    // xorshift64*
+    let mut x = state.get();
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    state.set(x);
+
+    let fraction = (x.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11) as f64 / (1u64 << 53) as f64;
+
+    let (low, high) = match arguments.len() {
+        0 => return Ok(vec![Value::Number(fraction)]),
+        1 => (1.0, expect_number(arguments, 0, "random")?.floor()),
+        _ => (
+            expect_number(arguments, 0, "random")?.floor(),
+            expect_number(arguments, 1, "random")?.floor(),
+        ),
+    };
+
+    if low > high {
+        return Err(RuntimeError::new(
+            "invalid argument to 'random' (interval is empty)",
+        ));
+    }
+
+    Ok(vec![Value::Number(
+        low + (fraction * (high - low + 1.0)).floor(),
+    )])
+}
@@ -0,0 +1,390 @@
+mod math;
+mod pattern;
+mod string;
+mod table;
+
+use super::expression::to_number;
+use super::{Interpreter, RuntimeError, Table, TableRef, Value};
+
+/// The results of a function of the standard library.
+type Results<'a> = Result<Vec<Value<'a>>, RuntimeError>;
+
+/// A function of the standard library.
+type Library<'a> = fn(&mut Interpreter<'a>, Vec<Value<'a>>) -> Results<'a>;
+
+/// Opens the standard library in the globals of the interpreter.
+pub(crate) fn open(interpreter: &mut Interpreter) {
+    let globals = interpreter.globals().clone();
+
+    register(
+        &globals,
+        &[
+            ("assert", assert),
+            ("error", error),
+            ("getmetatable", getmetatable),
+            ("ipairs", ipairs),
+            ("next", next),
+            ("pairs", pairs),
+            ("pcall", pcall),
+            ("print", print),
+            ("rawequal", rawequal),
+            ("rawget", rawget),
+            ("rawlen", rawlen),
+            ("rawset", rawset),
+            ("select", select),
+            ("setmetatable", setmetatable),
+            ("tonumber", tonumber),
+            ("tostring", tostring),
+            ("type", type_),
+            ("unpack", table::unpack),
+        ],
+    );
+
+    interpreter.set_global("_G", globals);
+    interpreter.set_global("_VERSION", "Luau");
+
+    let strings = interpreter.strings().clone();
+    string::open(&strings);
+    interpreter.set_global("string", strings);
+
+    interpreter.set_global("math", math::open());
+    interpreter.set_global("table", table::open());
+}
+
+fn register<'a>(table: &TableRef<'a>, functions: &[(&'static str, Library<'a>)]) {
+    let mut table = table.borrow_mut();
+
+    for (name, function) in functions {
+        // String keys are never nil or NaN, so setting them cannot fail.
+        let _ = table.set(Value::from(*name), Value::native(*function));
+    }
+}
+
+fn argument<'a>(arguments: &[Value<'a>], position: usize) -> Value<'a> {
+    arguments.get(position).cloned().unwrap_or_default()
+}
+
+fn bad_argument(position: usize, function: &str, expected: &str, value: &Value) -> RuntimeError {
+    let got = match value {
+        Value::Nil => "no value",
+        value => value.type_name(),
+    };
+
+    RuntimeError::new(format!(
+        "invalid argument #{} to '{}' ({} expected, got {})",
+        position + 1,
+        function,
+        expected,
+        got
+    ))
+}
+
+fn expect_any<'a>(
+    arguments: &[Value<'a>],
+    position: usize,
+    function: &str,
+) -> Result<Value<'a>, RuntimeError> {
+    match arguments.get(position) {
+        Some(value) => Ok(value.clone()),
+        None => Err(bad_argument(position, function, "value", &Value::Nil)),
+    }
+}
+
+fn expect_number(
+    arguments: &[Value],
+    position: usize,
+    function: &str,
+) -> Result<f64, RuntimeError> {
+    let value = argument(arguments, position);
+    to_number(&value).ok_or_else(|| bad_argument(position, function, "number", &value))
+}
+
+fn optional_number(
+    arguments: &[Value],
+    position: usize,
+    function: &str,
+    default: f64,
+) -> Result<f64, RuntimeError> {
+    match argument(arguments, position) {
+        Value::Nil => Ok(default),
+        _ => expect_number(arguments, position, function),
+    }
+}
+
+/// Expects an integer argument, truncating any fraction as Luau does.
+fn expect_integer(
+    arguments: &[Value],
+    position: usize,
+    function: &str,
+) -> Result<i64, RuntimeError> {
+    expect_number(arguments, position, function).map(|number| number as i64)
+}
+
+fn optional_integer(
+    arguments: &[Value],
+    position: usize,
+    function: &str,
+    default: i64,
+) -> Result<i64, RuntimeError> {
+    match argument(arguments, position) {
+        Value::Nil => Ok(default),
+        _ => expect_integer(arguments, position, function),
+    }
+}
+
+/// Expects a string argument, converting numbers to strings.
+fn expect_string(
+    arguments: &[Value],
+    position: usize,
+    function: &str,
+) -> Result<Vec<u8>, RuntimeError> {
+    let value = argument(arguments, position);
+    value
+        .to_concat_bytes()
+        .ok_or_else(|| bad_argument(position, function, "string", &value))
+}
+
+fn expect_table<'a>(
+    arguments: &[Value<'a>],
+    position: usize,
+    function: &str,
+) -> Result<TableRef<'a>, RuntimeError> {
+    match argument(arguments, position) {
+        Value::Table(table) => Ok(table),
+        value => Err(bad_argument(position, function, "table", &value)),
+    }
+}
+
+fn assert<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    if expect_any(&arguments, 0, "assert")?.is_truthy() {
+        return Ok(arguments);
+    }
+
+    match arguments.get(1) {
+        Some(Value::String(message)) => Err(RuntimeError::new(String::from_utf8_lossy(message))),
+        _ => Err(RuntimeError::new("assertion failed!")),
+    }
+}
+
+/// Raises an error, where a value that is not a string is converted to one.
+fn error<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let message = interpreter.to_string(&argument(&arguments, 0))?;
+    Err(RuntimeError::new(String::from_utf8_lossy(&message)))
+}
+
+fn getmetatable<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let value = argument(&arguments, 0);
+
+    let metatable = match &value {
+        Value::Table(table) => table.borrow().metatable().cloned(),
+        _ => None,
+    };
+
+    Ok(match metatable {
+        Some(metatable) => match interpreter.metamethod(&value, "__metatable") {
+            Some(protected) => vec![protected],
+            None => vec![metatable.into()],
+        },
+        None => vec![Value::Nil],
+    })
+}
+
+fn setmetatable<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "setmetatable")?;
+
+    let metatable = match argument(&arguments, 1) {
+        Value::Nil => None,
+        Value::Table(metatable) => Some(metatable),
+        value => return Err(bad_argument(1, "setmetatable", "nil or table", &value)),
+    };
+
+    if interpreter
+        .metamethod(&arguments[0], "__metatable")
+        .is_some()
+    {
+        return Err(RuntimeError::new("cannot change a protected metatable"));
+    }
+
+    table.borrow_mut().set_metatable(metatable);
+    Ok(vec![table.into()])
+}
+
+fn ipairs<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "ipairs")?;
+
+    Ok(vec![
+        Value::native(ipairs_next),
+        table.into(),
+        Value::Number(0.0),
+    ])
+}
+
+fn ipairs_next<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "ipairs")?;
+    let key = Value::Number(expect_number(&arguments, 1, "ipairs")? + 1.0);
+    let value = table.borrow().get(&key);
+
+    Ok(match value {
+        Value::Nil => vec![Value::Nil],
+        value => vec![key, value],
+    })
+}
+
+pub(crate) fn next<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "next")?;
+    let entry = table.borrow().next(&argument(&arguments, 1))?;
+
+    Ok(match entry {
+        Some((key, value)) => vec![key, value],
+        None => vec![Value::Nil],
+    })
+}
+
+fn pairs<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "pairs")?;
+    Ok(vec![Value::native(next), table.into(), Value::Nil])
+}
+
+/// Calls a function, returning `false` and the message of any error it raises instead of raising it.
+fn pcall<'a>(interpreter: &mut Interpreter<'a>, mut arguments: Vec<Value<'a>>) -> Results<'a> {
+    let function = expect_any(&arguments, 0, "pcall")?;
+    arguments.remove(0);
+
+    match interpreter.call(&function, arguments) {
+        Ok(mut results) => {
+            results.insert(0, Value::Boolean(true));
+            Ok(results)
+        }
+        Err(error) => Ok(vec![Value::Boolean(false), Value::from(error.message())]),
+    }
+}
+
+fn print<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let mut line = Vec::new();
+
+    for (position, value) in arguments.iter().enumerate() {
+        if position > 0 {
+            line.push(b'\t');
+        }
+
+        line.extend(interpreter.to_string(value)?);
+    }
+
+    line.push(b'\n');
+    interpreter.write(&String::from_utf8_lossy(&line));
+
+    Ok(Vec::new())
+}
+
+fn rawequal<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let left = expect_any(&arguments, 0, "rawequal")?;
+    let right = expect_any(&arguments, 1, "rawequal")?;
+
+    Ok(vec![Value::Boolean(left == right)])
+}
+
+fn rawget<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "rawget")?;
+    let value = table.borrow().get(&argument(&arguments, 1));
+
+    Ok(vec![value])
+}
+
+fn rawlen<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let length = match argument(&arguments, 0) {
+        Value::Table(table) => table.borrow().length(),
+        Value::String(bytes) => bytes.len(),
+        value => return Err(bad_argument(0, "rawlen", "table or string", &value)),
+    };
+
+    Ok(vec![Value::Number(length as f64)])
+}
+
+fn rawset<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "rawset")?;
+    let key = argument(&arguments, 1);
+    let value = argument(&arguments, 2);
+
+    table.borrow_mut().set(key, value)?;
+    Ok(vec![table.into()])
+}
+
+fn select<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let count = arguments.len().saturating_sub(1) as i64;
+
+    if argument(&arguments, 0).as_str() == Some("#") {
+        return Ok(vec![Value::Number(count as f64)]);
+    }
+
+    let index = expect_integer(&arguments, 0, "select")?;
+
+    let start = match index {
+        index if index < 0 && -index <= count => count + index,
+        index if index > 0 => (index - 1).min(count),
+        _ => {
+            return Err(RuntimeError::new(
+                "invalid argument #1 to 'select' (index out of range)",
+            ))
+        }
+    };
+
+    Ok(arguments.into_iter().skip(start as usize + 1).collect())
+}
+
+fn tonumber<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let value = expect_any(&arguments, 0, "tonumber")?;
+
+    let base = match argument(&arguments, 1) {
+        Value::Nil => {
+            return Ok(vec![to_number(&value)
+                .map(Value::Number)
+                .unwrap_or_default()])
+        }
+        _ => expect_integer(&arguments, 1, "tonumber")?,
+    };
+
+    if !(2..=36).contains(&base) {
+        return Err(RuntimeError::new(
+            "invalid argument #2 to 'tonumber' (base out of range)",
+        ));
+    }
+
+    let text = expect_string(&arguments, 0, "tonumber")?;
+    let text = String::from_utf8_lossy(&text);
+    let text = text.trim();
+
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+
+    let number = match digits.is_empty() {
+        true => None,
+        false => digits.chars().try_fold(0.0, |number, c| {
+            c.to_digit(base as u32)
+                .map(|digit| number * base as f64 + digit as f64)
+        }),
+    };
+
+    Ok(vec![number
+        .map(|number| Value::Number(if negative { -number } else { number }))
+        .unwrap_or_default()])
+}
+
+fn tostring<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let value = expect_any(&arguments, 0, "tostring")?;
+    Ok(vec![Value::from(interpreter.to_string(&value)?)])
+}
+
+fn type_<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let value = expect_any(&arguments, 0, "type")?;
+    Ok(vec![Value::from(value.type_name())])
+}
+
+/// Constructs a new library table with the given functions.
+fn library<'a>(functions: &[(&'static str, Library<'a>)]) -> TableRef<'a> {
+    let table = TableRef::new(Table::default());
+    register(&table, functions);
+
+    table
+}
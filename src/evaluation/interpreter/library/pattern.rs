@@ -0,0 +1,561 @@
+//! Lua patterns, as used by `string.find`, `string.match`, `string.gmatch` and `string.gsub`,
+//! matched over bytes the way the reference implementation does.
+//! ```
+//! use luna::evaluation::{Interpreter, Value};
+//! use luna::parser::parse;
+//!
+//! let chunk = parse(
+//!     r#"local key, value = string.match("  name = luna ", "^%s*(%w+)%s*=%s*(%w+)")
+//!     local words = {}
+//!     for word in string.gmatch("one two  three", "%a+") do
+//!         table.insert(words, word)
+//!     end
+//!     local swapped, count = string.gsub("a=1, b=2", "(%w+)=(%w+)", "%2=%1")
+//!     local start, finish, inner = string.find("f(a(b)c)", "(%b())")
+//!     return key, value, #words, swapped, count, start, finish, inner, ("x"):rep(3):gsub("x", { x = "y" })"#,
+//! )
+//! .unwrap();
+//!
+//! let mut interpreter = Interpreter::new();
+//! let results = interpreter.execute(chunk.block(), Vec::new()).unwrap();
+//!
+//! assert_eq!(
+//!     results,
+//!     vec![
+//!         Value::from("name"),
+//!         Value::from("luna"),
+//!         Value::from(3.0),
+//!         Value::from("1=a, 2=b"),
+//!         Value::from(2.0),
+//!         Value::from(2.0),
+//!         Value::from(8.0),
+//!         Value::from("(a(b)c)"),
+//!         Value::from("yyy"),
+//!         Value::from(3.0),
+//!     ]
+//! );
+//! ```
+use std::ops::Range;
+
+use crate::evaluation::interpreter::{RuntimeError, Value};
+
+/// The deepest the matcher can recurse, through captures and optional items, before the pattern
+/// is rejected as too complex.
+const MAX_DEPTH: usize = 200;
+
+/// The characters that make a pattern more than plain text.
+const SPECIALS: &[u8] = b"^$*+?.([%-";
+
+/// Returns whether a pattern contains no special characters, so it matches as plain text.
+pub(super) fn is_plain(pattern: &[u8]) -> bool {
+    !pattern.iter().any(|byte| SPECIALS.contains(byte))
+}
+
+/// A successful match of a pattern, along with its captures.
+pub(super) struct Match {
+    range: Range<usize>,
+    captures: Vec<Captured>,
+}
+
+impl Match {
+    /// Returns the range of the source this [`Match`] covers.
+    pub(super) fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the values of the captures of this [`Match`], or of the whole match when the
+    /// pattern has no captures and `whole` is set.
+    pub(super) fn captures<'a>(&self, source: &[u8], whole: bool) -> Vec<Value<'a>> {
+        if self.captures.is_empty() && whole {
+            return vec![Value::from(source[self.range()].to_vec())];
+        }
+
+        self.captures
+            .iter()
+            .map(|capture| capture.value(source))
+            .collect()
+    }
+
+    /// Returns the value of the capture with the given index, where `0` is the whole match, as
+    /// referred to by `%0` to `%9` in the replacement of `string.gsub`.
+    pub(super) fn capture<'a>(
+        &self,
+        source: &[u8],
+        index: usize,
+    ) -> Result<Value<'a>, RuntimeError> {
+        match index {
+            0 => Ok(Value::from(source[self.range()].to_vec())),
+            index if index == 1 && self.captures.is_empty() => {
+                Ok(Value::from(source[self.range()].to_vec()))
+            }
+            index => match self.captures.get(index - 1) {
+                Some(capture) => Ok(capture.value(source)),
+                None => Err(RuntimeError::new(format!(
+                    "invalid capture index %{} in replacement string",
+                    index
+                ))),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Captured {
+    Text(Range<usize>),
+    /// A position capture, `()`, holding the offset it matched at.
+    Position(usize),
+}
+
+impl Captured {
+    fn value<'a>(&self, source: &[u8]) -> Value<'a> {
+        match self {
+            Self::Text(range) => Value::from(source[range.clone()].to_vec()),
+            Self::Position(position) => Value::Number((position + 1) as f64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Length {
+    Unclosed,
+    Position,
+    Closed(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Capture {
+    start: usize,
+    length: Length,
+}
+
+/// Matches a pattern against a source.
+pub(super) struct Matcher<'s> {
+    source: &'s [u8],
+    pattern: &'s [u8],
+    captures: Vec<Capture>,
+    depth: usize,
+}
+
+impl<'s> Matcher<'s> {
+    /// Constructs a new [`Matcher`] of the given pattern against the given source.
+    pub(super) fn new(source: &'s [u8], pattern: &'s [u8]) -> Self {
+        Self {
+            source,
+            pattern,
+            captures: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    /// Finds the first match starting at or after the given offset, where a pattern starting with
+    /// `^` only matches at the offset itself.
+    pub(super) fn find(&mut self, start: usize) -> Result<Option<Match>, RuntimeError> {
+        let anchored = self.pattern.first() == Some(&b'^');
+        let pattern_start = anchored as usize;
+
+        for position in start..=self.source.len() {
+            if let Some(found) = self.match_at(position, pattern_start)? {
+                return Ok(Some(found));
+            }
+
+            if anchored {
+                break;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Matches the pattern from the given offset of the pattern at exactly the given offset of
+    /// the source.
+    pub(super) fn match_at(
+        &mut self,
+        position: usize,
+        pattern_start: usize,
+    ) -> Result<Option<Match>, RuntimeError> {
+        self.captures.clear();
+        self.depth = 0;
+
+        let Some(end) = self.do_match(position, pattern_start)? else {
+            return Ok(None);
+        };
+
+        let captures = self
+            .captures
+            .iter()
+            .map(|capture| match capture.length {
+                Length::Closed(length) => Ok(Captured::Text(capture.start..capture.start + length)),
+                Length::Position => Ok(Captured::Position(capture.start)),
+                Length::Unclosed => Err(RuntimeError::new("unfinished capture")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Some(Match {
+            range: position..end,
+            captures,
+        }))
+    }
+
+    fn do_match(&mut self, source: usize, pattern: usize) -> Result<Option<usize>, RuntimeError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(RuntimeError::new("pattern too complex"));
+        }
+
+        self.depth += 1;
+        let result = self.match_items(source, pattern);
+        self.depth -= 1;
+
+        result
+    }
+
+    /// Matches the items of the pattern one after the other, recursing only where an item can
+    /// match in more than one way.
+    fn match_items(
+        &mut self,
+        mut source: usize,
+        mut pattern: usize,
+    ) -> Result<Option<usize>, RuntimeError> {
+        loop {
+            let Some(&item) = self.pattern.get(pattern) else {
+                return Ok(Some(source));
+            };
+
+            let next = self.pattern.get(pattern + 1).copied();
+
+            match (item, next) {
+                (b'(', Some(b')')) => {
+                    return self.start_capture(source, pattern + 2, Length::Position)
+                }
+                (b'(', _) => return self.start_capture(source, pattern + 1, Length::Unclosed),
+                (b')', _) => return self.end_capture(source, pattern + 1),
+                (b'$', None) => {
+                    return Ok((source == self.source.len()).then_some(source));
+                }
+                (b'%', Some(b'b')) => match self.match_balance(source, pattern + 2)? {
+                    Some(end) => {
+                        source = end;
+                        pattern += 4;
+                        continue;
+                    }
+                    None => return Ok(None),
+                },
+                (b'%', Some(b'f')) => {
+                    pattern += 2;
+
+                    if self.pattern.get(pattern) != Some(&b'[') {
+                        return Err(RuntimeError::new("missing '[' after '%f' in pattern"));
+                    }
+
+                    let end = self.class_end(pattern)?;
+                    let previous = source.checked_sub(1).map_or(0, |index| self.source[index]);
+                    let current = self.source.get(source).copied().unwrap_or(0);
+
+                    if self.matches_set(previous, pattern, end - 1)
+                        || !self.matches_set(current, pattern, end - 1)
+                    {
+                        return Ok(None);
+                    }
+
+                    pattern = end;
+                    continue;
+                }
+                (b'%', Some(digit)) if digit.is_ascii_digit() => {
+                    match self.match_capture(source, digit)? {
+                        Some(end) => {
+                            source = end;
+                            pattern += 2;
+                            continue;
+                        }
+                        None => return Ok(None),
+                    }
+                }
+                _ => {}
+            }
+
+            let end = self.class_end(pattern)?;
+            let matches = self
+                .source
+                .get(source)
+                .is_some_and(|byte| self.matches_single(*byte, pattern, end));
+
+            match self.pattern.get(end) {
+                Some(b'?') => {
+                    if matches {
+                        if let Some(found) = self.do_match(source + 1, end + 1)? {
+                            return Ok(Some(found));
+                        }
+                    }
+
+                    pattern = end + 1;
+                }
+                Some(b'*') => return self.max_expand(source, pattern, end),
+                Some(b'+') => {
+                    return match matches {
+                        true => self.max_expand(source + 1, pattern, end),
+                        false => Ok(None),
+                    }
+                }
+                Some(b'-') => return self.min_expand(source, pattern, end),
+                _ => {
+                    if !matches {
+                        return Ok(None);
+                    }
+
+                    source += 1;
+                    pattern = end;
+                }
+            }
+        }
+    }
+
+    /// Matches as many repetitions of the single item as possible, backing off until the rest of
+    /// the pattern matches.
+    fn max_expand(
+        &mut self,
+        source: usize,
+        pattern: usize,
+        end: usize,
+    ) -> Result<Option<usize>, RuntimeError> {
+        let mut count = 0;
+
+        while self
+            .source
+            .get(source + count)
+            .is_some_and(|byte| self.matches_single(*byte, pattern, end))
+        {
+            count += 1;
+        }
+
+        loop {
+            if let Some(found) = self.do_match(source + count, end + 1)? {
+                return Ok(Some(found));
+            }
+
+            match count.checked_sub(1) {
+                Some(fewer) => count = fewer,
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Matches as few repetitions of the single item as possible, adding them until the rest of
+    /// the pattern matches.
+    fn min_expand(
+        &mut self,
+        mut source: usize,
+        pattern: usize,
+        end: usize,
+    ) -> Result<Option<usize>, RuntimeError> {
+        loop {
+            if let Some(found) = self.do_match(source, end + 1)? {
+                return Ok(Some(found));
+            }
+
+            match self.source.get(source) {
+                Some(byte) if self.matches_single(*byte, pattern, end) => source += 1,
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    fn start_capture(
+        &mut self,
+        source: usize,
+        pattern: usize,
+        length: Length,
+    ) -> Result<Option<usize>, RuntimeError> {
+        self.captures.push(Capture {
+            start: source,
+            length,
+        });
+
+        let found = self.do_match(source, pattern)?;
+
+        if found.is_none() {
+            self.captures.pop();
+        }
+
+        Ok(found)
+    }
+
+    fn end_capture(
+        &mut self,
+        source: usize,
+        pattern: usize,
+    ) -> Result<Option<usize>, RuntimeError> {
+        let index = self
+            .captures
+            .iter()
+            .rposition(|capture| matches!(capture.length, Length::Unclosed))
+            .ok_or_else(|| RuntimeError::new("invalid pattern capture"))?;
+
+        let start = self.captures[index].start;
+        self.captures[index].length = Length::Closed(source - start);
+
+        let found = self.do_match(source, pattern)?;
+
+        if found.is_none() {
+            self.captures[index].length = Length::Unclosed;
+        }
+
+        Ok(found)
+    }
+
+    /// Matches `%bxy`, a balanced run between `x` and `y`, given the offset of `x`.
+    fn match_balance(&self, source: usize, pattern: usize) -> Result<Option<usize>, RuntimeError> {
+        let (Some(&open), Some(&close)) =
+            (self.pattern.get(pattern), self.pattern.get(pattern + 1))
+        else {
+            return Err(RuntimeError::new(
+                "malformed pattern (missing arguments to '%b')",
+            ));
+        };
+
+        if self.source.get(source) != Some(&open) {
+            return Ok(None);
+        }
+
+        let mut depth = 1;
+
+        for (offset, byte) in self.source[source + 1..].iter().enumerate() {
+            if *byte == close {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Ok(Some(source + 1 + offset + 1));
+                }
+            } else if *byte == open {
+                depth += 1;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Matches `%1` to `%9`, the text of an earlier capture.
+    fn match_capture(&self, source: usize, digit: u8) -> Result<Option<usize>, RuntimeError> {
+        let capture = (digit as usize)
+            .checked_sub(b'1' as usize)
+            .and_then(|index| self.captures.get(index));
+
+        let length = match capture.map(|capture| capture.length) {
+            Some(Length::Closed(length)) => length,
+            Some(Length::Position) => 0,
+            _ => {
+                return Err(RuntimeError::new(format!(
+                    "invalid capture index %{}",
+                    digit as char
+                )))
+            }
+        };
+
+        let start = capture.map_or(0, |capture| capture.start);
+        let text = &self.source[start..start + length];
+
+        Ok(self.source[source..]
+            .starts_with(text)
+            .then_some(source + length))
+    }
+
+    /// Returns the offset after the single character class at the given offset of the pattern.
+    fn class_end(&self, pattern: usize) -> Result<usize, RuntimeError> {
+        let mut end = pattern + 1;
+
+        match self.pattern[pattern] {
+            b'%' => match end < self.pattern.len() {
+                true => Ok(end + 1),
+                false => Err(RuntimeError::new("malformed pattern (ends with '%')")),
+            },
+            b'[' => {
+                if self.pattern.get(end) == Some(&b'^') {
+                    end += 1;
+                }
+
+                // The first character of a set is part of it, even if it is `]`.
+                loop {
+                    let Some(&byte) = self.pattern.get(end) else {
+                        return Err(RuntimeError::new("malformed pattern (missing ']')"));
+                    };
+
+                    end += 1;
+
+                    if byte == b'%' && end < self.pattern.len() {
+                        end += 1;
+                    }
+
+                    if self.pattern.get(end) == Some(&b']') {
+                        return Ok(end + 1);
+                    }
+                }
+            }
+            _ => Ok(end),
+        }
+    }
+
+    /// Returns whether a byte matches the single character class between the given offsets.
+    fn matches_single(&self, byte: u8, pattern: usize, end: usize) -> bool {
+        match self.pattern[pattern] {
+            b'.' => true,
+            b'%' => matches_class(byte, self.pattern[pattern + 1]),
+            b'[' => self.matches_set(byte, pattern, end - 1),
+            literal => literal == byte,
+        }
+    }
+
+    /// Returns whether a byte matches the set between the `[` and `]` at the given offsets.
+    fn matches_set(&self, byte: u8, open: usize, close: usize) -> bool {
+        let mut position = open + 1;
+        let negated = self.pattern.get(position) == Some(&b'^');
+
+        if negated {
+            position += 1;
+        }
+
+        while position < close {
+            let item = self.pattern[position];
+
+            if item == b'%' && position + 1 < close {
+                position += 1;
+
+                if matches_class(byte, self.pattern[position]) {
+                    return !negated;
+                }
+            } else if self.pattern.get(position + 1) == Some(&b'-') && position + 2 < close {
+                if (item..=self.pattern[position + 2]).contains(&byte) {
+                    return !negated;
+                }
+
+                position += 2;
+            } else if item == byte {
+                return !negated;
+            }
+
+            position += 1;
+        }
+
+        negated
+    }
+}
+
+/// Returns whether a byte matches a class such as `%a`, where an uppercase class matches its
+/// complement and any other character matches itself.
+fn matches_class(byte: u8, class: u8) -> bool {
+    let matches = match class.to_ascii_lowercase() {
+        b'a' => byte.is_ascii_alphabetic(),
+        b'c' => byte.is_ascii_control(),
+        b'd' => byte.is_ascii_digit(),
+        b'g' => byte.is_ascii_graphic(),
+        b'l' => byte.is_ascii_lowercase(),
+        b'p' => byte.is_ascii_punctuation(),
+        b's' => matches!(byte, b' ' | b'\t'..=b'\r'),
+        b'u' => byte.is_ascii_uppercase(),
+        b'w' => byte.is_ascii_alphanumeric(),
+        b'x' => byte.is_ascii_hexdigit(),
+        _ => return class == byte,
+    };
+
+    match class.is_ascii_uppercase() {
+        true => !matches,
+        false => matches,
+    }
+}
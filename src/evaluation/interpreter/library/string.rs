@@ -0,0 +1,611 @@
+use std::cell::Cell;
+
+use super::pattern::{self, Match, Matcher};
+use super::{
+    argument, bad_argument, expect_integer, expect_number, expect_string, optional_integer,
+    register, Results, RuntimeError,
+};
+use crate::evaluation::constant::quote_string;
+use crate::evaluation::interpreter::expression::first;
+use crate::evaluation::interpreter::{Interpreter, TableRef, Value};
+
+pub(super) fn open(strings: &TableRef) {
+    register(
+        strings,
+        &[
+            ("byte", byte),
+            ("char", char),
+            ("find", find),
+            ("format", format),
+            ("gmatch", gmatch),
+            ("gsub", gsub),
+            ("len", len),
+            ("lower", lower),
+            ("match", match_),
+            ("rep", rep),
+            ("reverse", reverse),
+            ("split", split),
+            ("sub", sub),
+            ("upper", upper),
+        ],
+    );
+}
+
+/// Converts the `i` and `j` arguments of a string function to a range of bytes, where negative
+/// positions count from the end.
+fn byte_range(length: usize, start: i64, end: i64) -> std::ops::Range<usize> {
+    let length = length as i64;
+    let position = |index: i64| match index {
+        index if index < 0 => (length + index + 1).max(0),
+        index => index,
+    };
+
+    let start = position(start).max(1);
+    let end = position(end).min(length);
+
+    match start > end {
+        true => 0..0,
+        false => (start - 1) as usize..end as usize,
+    }
+}
+
+fn byte<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "byte")?;
+    let start = optional_integer(&arguments, 1, "byte", 1)?;
+    let end = optional_integer(&arguments, 2, "byte", start)?;
+
+    Ok(text[byte_range(text.len(), start, end)]
+        .iter()
+        .map(|byte| Value::Number(*byte as f64))
+        .collect())
+}
+
+fn char<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let mut bytes = Vec::with_capacity(arguments.len());
+
+    for position in 0..arguments.len() {
+        match u8::try_from(expect_integer(&arguments, position, "char")?) {
+            Ok(byte) => bytes.push(byte),
+            Err(_) => {
+                return Err(RuntimeError::new(format!(
+                    "invalid argument #{} to 'char' (value out of range)",
+                    position + 1
+                )))
+            }
+        }
+    }
+
+    Ok(vec![Value::from(bytes)])
+}
+
+/// Converts the `init` argument of a pattern function to an offset, or `None` when it is past the
+/// end of the string, where nothing can match.
+fn pattern_start(length: usize, init: i64) -> Option<usize> {
+    let start = byte_range(length + 1, init, -1).start;
+    (init <= length as i64 + 1).then_some(start)
+}
+
+/// Finds the first match of a pattern, returning its positions and captures, or searches for the
+/// pattern as plain text when `plain` is set or it has no special characters.
+fn find<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "find")?;
+    let pattern = expect_string(&arguments, 1, "find")?;
+    let init = optional_integer(&arguments, 2, "find", 1)?;
+    let plain = argument(&arguments, 3).is_truthy();
+
+    let Some(start) = pattern_start(text.len(), init) else {
+        return Ok(vec![Value::Nil]);
+    };
+
+    if plain || pattern::is_plain(&pattern) {
+        let found = (start..=text.len().saturating_sub(pattern.len()))
+            .find(|position| text[*position..].starts_with(&pattern));
+
+        return Ok(match found {
+            Some(position) => vec![
+                Value::Number((position + 1) as f64),
+                Value::Number((position + pattern.len()) as f64),
+            ],
+            None => vec![Value::Nil],
+        });
+    }
+
+    Ok(match Matcher::new(&text, &pattern).find(start)? {
+        Some(found) => {
+            let range = found.range();
+            let mut results = vec![
+                Value::Number((range.start + 1) as f64),
+                Value::Number(range.end as f64),
+            ];
+
+            results.extend(found.captures(&text, false));
+            results
+        }
+        None => vec![Value::Nil],
+    })
+}
+
+/// Returns the captures of the first match of a pattern, or the whole match without captures.
+fn match_<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "match")?;
+    let pattern = expect_string(&arguments, 1, "match")?;
+    let init = optional_integer(&arguments, 2, "match", 1)?;
+
+    let Some(start) = pattern_start(text.len(), init) else {
+        return Ok(vec![Value::Nil]);
+    };
+
+    Ok(match Matcher::new(&text, &pattern).find(start)? {
+        Some(found) => found.captures(&text, true),
+        None => vec![Value::Nil],
+    })
+}
+
+/// Returns an iterator over the captures of each match of a pattern, where `^` is not an anchor.
+fn gmatch<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "gmatch")?;
+    let pattern = expect_string(&arguments, 1, "gmatch")?;
+    let position = Cell::new(0);
+
+    let iterator = Value::native(move |_, _| {
+        let mut matcher = Matcher::new(&text, &pattern);
+
+        for start in position.get()..=text.len() {
+            if let Some(found) = matcher.match_at(start, 0)? {
+                // An empty match moves on, so that the same position is not matched again.
+                let end = found.range().end;
+                position.set(if end == start { end + 1 } else { end });
+
+                return Ok(found.captures(&text, true));
+            }
+        }
+
+        position.set(text.len() + 1);
+        Ok(vec![Value::Nil])
+    });
+
+    Ok(vec![iterator])
+}
+
+/// Replaces the matches of a pattern, up to an optional count, with a string in which `%0` to
+/// `%9` refer to captures, the value of a table at the first capture, or the result of a function
+/// called with the captures. A replacement of `nil` or `false` keeps the match.
+fn gsub<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "gsub")?;
+    let pattern = expect_string(&arguments, 1, "gsub")?;
+    let replacement = argument(&arguments, 2);
+    let limit = match argument(&arguments, 3) {
+        Value::Nil => None,
+        _ => Some(expect_integer(&arguments, 3, "gsub")?.max(0) as usize),
+    };
+
+    if !matches!(
+        replacement,
+        Value::String(_) | Value::Number(_) | Value::Table(_) | Value::Function(_)
+    ) {
+        return Err(bad_argument(
+            2,
+            "gsub",
+            "string/function/table",
+            &replacement,
+        ));
+    }
+
+    let anchored = pattern.first() == Some(&b'^');
+    let mut matcher = Matcher::new(&text, &pattern);
+    let mut result = Vec::with_capacity(text.len());
+    let mut position = 0;
+    let mut count = 0;
+
+    while limit.is_none_or(|limit| count < limit) {
+        let found = matcher.match_at(position, anchored as usize)?;
+
+        if let Some(found) = &found {
+            count += 1;
+            result.extend(substitute(interpreter, &text, found, &replacement)?);
+        }
+
+        match found.map(|found| found.range().end) {
+            Some(end) if end > position => position = end,
+            _ if position < text.len() => {
+                result.push(text[position]);
+                position += 1;
+            }
+            _ => break,
+        }
+
+        if anchored {
+            break;
+        }
+    }
+
+    result.extend(&text[position..]);
+    Ok(vec![Value::from(result), Value::Number(count as f64)])
+}
+
+/// Returns the text replacing a match in `string.gsub`.
+fn substitute<'a>(
+    interpreter: &mut Interpreter<'a>,
+    text: &[u8],
+    found: &Match,
+    replacement: &Value<'a>,
+) -> Result<Vec<u8>, RuntimeError> {
+    let value = match replacement {
+        Value::Table(table) => {
+            let key = found.capture(text, 1)?;
+            interpreter.index(&Value::Table(table.clone()), key)?
+        }
+        Value::Function(_) => {
+            let captures = found.captures(text, true);
+            first(interpreter.call(replacement, captures)?)
+        }
+        replacement => {
+            let replacement = replacement.to_concat_bytes().unwrap_or_default();
+            let mut result = Vec::new();
+            let mut index = 0;
+
+            while let Some(&byte) = replacement.get(index) {
+                if byte != b'%' {
+                    result.push(byte);
+                    index += 1;
+                    continue;
+                }
+
+                match replacement.get(index + 1) {
+                    Some(b'%') => result.push(b'%'),
+                    Some(digit) if digit.is_ascii_digit() => {
+                        let capture = found.capture(text, (digit - b'0') as usize)?;
+                        result.extend(capture.to_concat_bytes().unwrap_or_default());
+                    }
+                    _ => {
+                        return Err(RuntimeError::new(
+                            "invalid use of '%' in replacement string",
+                        ))
+                    }
+                }
+
+                index += 2;
+            }
+
+            return Ok(result);
+        }
+    };
+
+    match value {
+        Value::Nil | Value::Boolean(false) => Ok(text[found.range()].to_vec()),
+        value => value.to_concat_bytes().ok_or_else(|| {
+            RuntimeError::new(format!(
+                "invalid replacement value (a {})",
+                value.type_name()
+            ))
+        }),
+    }
+}
+
+fn len<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "len")?;
+    Ok(vec![Value::Number(text.len() as f64)])
+}
+
+fn lower<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "lower")?;
+    Ok(vec![Value::from(text.to_ascii_lowercase())])
+}
+
+fn upper<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "upper")?;
+    Ok(vec![Value::from(text.to_ascii_uppercase())])
+}
+
+fn rep<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "rep")?;
+    let count = expect_integer(&arguments, 1, "rep")?.max(0) as usize;
+
+    let separator = match argument(&arguments, 2) {
+        Value::Nil => Vec::new(),
+        _ => expect_string(&arguments, 2, "rep")?,
+    };
+
+    let mut bytes = Vec::new();
+
+    for index in 0..count {
+        if index > 0 {
+            bytes.extend(&separator);
+        }
+
+        bytes.extend(&text);
+    }
+
+    Ok(vec![Value::from(bytes)])
+}
+
+fn reverse<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let mut text = expect_string(&arguments, 0, "reverse")?;
+    text.reverse();
+
+    Ok(vec![Value::from(text)])
+}
+
+fn split<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "split")?;
+
+    let separator = match argument(&arguments, 1) {
+        Value::Nil => b",".to_vec(),
+        _ => expect_string(&arguments, 1, "split")?,
+    };
+
+    let pieces = match separator.is_empty() {
+        true => text.iter().map(|byte| Value::from(vec![*byte])).collect(),
+        false => {
+            let mut pieces = Vec::new();
+            let mut start = 0;
+            let mut position = 0;
+
+            while position + separator.len() <= text.len() {
+                if text[position..].starts_with(&separator) {
+                    pieces.push(Value::from(text[start..position].to_vec()));
+                    position += separator.len();
+                    start = position;
+                } else {
+                    position += 1;
+                }
+            }
+
+            pieces.push(Value::from(text[start..].to_vec()));
+            pieces
+        }
+    };
+
+    Ok(vec![Value::array(pieces)])
+}
+
+fn sub<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let text = expect_string(&arguments, 0, "sub")?;
+    let start = optional_integer(&arguments, 1, "sub", 1)?;
+    let end = optional_integer(&arguments, 2, "sub", -1)?;
+
+    Ok(vec![Value::from(
+        text[byte_range(text.len(), start, end)].to_vec(),
+    )])
+}
+
+/// The flags, width and precision of a conversion of `string.format`, such as `-5.2` in `%-5.2f`.
+#[derive(Default)]
+struct Specification {
+    left: bool,
+    zero: bool,
+    plus: bool,
+    space: bool,
+    alternate: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+impl Specification {
+    fn parse(text: &[u8]) -> Self {
+        let mut specification = Self::default();
+        let mut index = 0;
+
+        while let Some(flag) = text.get(index) {
+            match flag {
+                b'-' => specification.left = true,
+                b'0' => specification.zero = true,
+                b'+' => specification.plus = true,
+                b' ' => specification.space = true,
+                b'#' => specification.alternate = true,
+                _ => break,
+            }
+
+            index += 1;
+        }
+
+        let digits = |index: &mut usize| {
+            let mut value = 0;
+
+            while let Some(digit @ b'0'..=b'9') = text.get(*index) {
+                value = value * 10 + (digit - b'0') as usize;
+                *index += 1;
+            }
+
+            value
+        };
+
+        specification.width = digits(&mut index);
+
+        if text.get(index) == Some(&b'.') {
+            index += 1;
+            specification.precision = Some(digits(&mut index));
+        }
+
+        specification
+    }
+
+    /// Pads formatted text to the width, adding the sign flags to numbers.
+    fn pad(&self, mut text: Vec<u8>, numeric: bool) -> Vec<u8> {
+        if numeric && text.first() != Some(&b'-') {
+            if self.plus {
+                text.insert(0, b'+');
+            } else if self.space {
+                text.insert(0, b' ');
+            }
+        }
+
+        let padding = self.width.saturating_sub(text.len());
+
+        if self.left {
+            text.extend(std::iter::repeat_n(b' ', padding));
+        } else if self.zero && numeric && text.last().is_some_and(u8::is_ascii_digit) {
+            let sign = usize::from(matches!(text.first(), Some(b'-' | b'+' | b' ')));
+            text.splice(sign..sign, std::iter::repeat_n(b'0', padding));
+        } else {
+            text.splice(0..0, std::iter::repeat_n(b' ', padding));
+        }
+
+        text
+    }
+}
+
+fn format<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let pattern = expect_string(&arguments, 0, "format")?;
+    let mut output = Vec::with_capacity(pattern.len());
+    let mut next = 1;
+    let mut index = 0;
+
+    while index < pattern.len() {
+        let byte = pattern[index];
+        index += 1;
+
+        if byte != b'%' {
+            output.push(byte);
+            continue;
+        }
+
+        if pattern.get(index) == Some(&b'%') {
+            output.push(b'%');
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+
+        while pattern
+            .get(index)
+            .is_some_and(|byte| b"-+ #0.".contains(byte) || byte.is_ascii_digit())
+        {
+            index += 1;
+        }
+
+        let conversion = *pattern
+            .get(index)
+            .ok_or_else(|| RuntimeError::new("invalid conversion '%' to 'format'"))?;
+        let specification = Specification::parse(&pattern[start..index]);
+        index += 1;
+
+        let position = next;
+        next += 1;
+
+        let text = match conversion {
+            b'd' | b'i' => {
+                let number = expect_number(&arguments, position, "format")?;
+
+                if number.fract() != 0.0 {
+                    return Err(RuntimeError::new(format!(
+                        "invalid argument #{} to 'format' (number has no integer representation)",
+                        position + 1
+                    )));
+                }
+
+                specification.pad(format!("{}", number as i64).into_bytes(), true)
+            }
+            b'x' | b'X' | b'o' => {
+                let number = expect_number(&arguments, position, "format")? as i64 as u64;
+
+                let text = match conversion {
+                    b'x' => format!("{:x}", number),
+                    b'X' => format!("{:X}", number),
+                    _ => format!("{:o}", number),
+                };
+
+                specification.pad(text.into_bytes(), true)
+            }
+            b'c' => vec![expect_integer(&arguments, position, "format")? as u8],
+            b'e' | b'E' | b'f' | b'F' | b'g' | b'G' => {
+                let number = expect_number(&arguments, position, "format")?;
+                let precision = specification.precision.unwrap_or(6);
+
+                let text = format_float(number, conversion, precision, specification.alternate);
+                specification.pad(text.into_bytes(), true)
+            }
+            b'q' => quote_string(&expect_string(&arguments, position, "format")?).into_bytes(),
+            b's' => {
+                let mut text = interpreter.to_string(&argument(&arguments, position))?;
+
+                if let Some(precision) = specification.precision {
+                    text.truncate(precision);
+                }
+
+                specification.pad(text, false)
+            }
+            _ => {
+                return Err(RuntimeError::new(format!(
+                    "invalid conversion '%{}' to 'format'",
+                    conversion as char
+                )))
+            }
+        };
+
+        output.extend(text);
+    }
+
+    Ok(vec![Value::from(output)])
+}
+
+/// Formats a float as the `%e`, `%f` and `%g` conversions of C do.
+fn format_float(number: f64, conversion: u8, precision: usize, alternate: bool) -> String {
+    let uppercase = conversion.is_ascii_uppercase();
+
+    let text = if number.is_nan() {
+        String::from("nan")
+    } else if number.is_infinite() {
+        String::from(if number < 0.0 { "-inf" } else { "inf" })
+    } else {
+        match conversion.to_ascii_lowercase() {
+            b'e' => format_exponent(number, precision),
+            b'f' => format!("{:.*}", precision, number),
+            _ => {
+                let precision = precision.max(1);
+                let exponent = match number == 0.0 {
+                    true => 0,
+                    false => exponent_of(&format!("{:.*e}", precision - 1, number)),
+                };
+
+                let text = match exponent < -4 || exponent >= precision as i32 {
+                    true => format_exponent(number, precision - 1),
+                    false => format!("{:.*}", (precision as i32 - 1 - exponent) as usize, number),
+                };
+
+                match alternate {
+                    true => text,
+                    false => strip_zeros(&text),
+                }
+            }
+        }
+    };
+
+    match uppercase {
+        true => text.to_ascii_uppercase(),
+        false => text,
+    }
+}
+
+fn exponent_of(scientific: &str) -> i32 {
+    scientific
+        .split_once('e')
+        .and_then(|(_, exponent)| exponent.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Formats a number in scientific notation with at least two exponent digits, such as `1.5e+02`.
+fn format_exponent(number: f64, precision: usize) -> String {
+    let scientific = format!("{:.*e}", precision, number);
+    let (mantissa, _) = scientific.split_once('e').unwrap();
+    let exponent = exponent_of(&scientific);
+
+    let sign = if exponent < 0 { '-' } else { '+' };
+    format!("{}e{}{:02}", mantissa, sign, exponent.abs())
+}
+
+/// Removes the trailing zeros of the fraction of a number, as `%g` does.
+fn strip_zeros(text: &str) -> String {
+    let (mantissa, exponent) = match text.find('e') {
+        Some(index) => text.split_at(index),
+        None => (text, ""),
+    };
+
+    let mantissa = match mantissa.contains('.') {
+        true => mantissa.trim_end_matches('0').trim_end_matches('.'),
+        false => mantissa,
+    };
+
+    format!("{}{}", mantissa, exponent)
+}
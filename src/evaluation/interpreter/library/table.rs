@@ -0,0 +1,227 @@
+use super::{
+    argument, expect_any, expect_integer, expect_table, library, optional_integer, Results,
+    RuntimeError,
+};
+use crate::evaluation::interpreter::{Interpreter, Table, TableRef, Value};
+
+pub(super) fn open<'a>() -> TableRef<'a> {
+    library(&[
+        ("clear", clear),
+        ("concat", concat),
+        ("create", create),
+        ("find", find),
+        ("insert", insert),
+        ("pack", pack),
+        ("remove", remove),
+        ("sort", sort),
+        ("unpack", unpack),
+    ])
+}
+
+fn clear<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "clear")?;
+    let mut table = table.borrow_mut();
+
+    let metatable = table.metatable().cloned();
+    *table = Table::default();
+    table.set_metatable(metatable);
+
+    Ok(Vec::new())
+}
+
+fn concat<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "concat")?;
+
+    let separator = match argument(&arguments, 1) {
+        Value::Nil => Vec::new(),
+        _ => super::expect_string(&arguments, 1, "concat")?,
+    };
+
+    let start = optional_integer(&arguments, 2, "concat", 1)?;
+    let end = optional_integer(&arguments, 3, "concat", table.borrow().length() as i64)?;
+    let mut bytes = Vec::new();
+
+    for index in start..=end {
+        if index > start {
+            bytes.extend(&separator);
+        }
+
+        let value = table.borrow().get(&Value::Number(index as f64));
+
+        match value.to_concat_bytes() {
+            Some(text) => bytes.extend(text),
+            None => {
+                return Err(RuntimeError::new(format!(
+                    "invalid value (at index {}) in table for 'concat'",
+                    index
+                )))
+            }
+        }
+    }
+
+    Ok(vec![Value::from(bytes)])
+}
+
+fn create<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let count = expect_integer(&arguments, 0, "create")?;
+
+    if count < 0 {
+        return Err(RuntimeError::new(
+            "invalid argument #1 to 'create' (size out of range)",
+        ));
+    }
+
+    let value = argument(&arguments, 1);
+    Ok(vec![Value::array(vec![value; count as usize])])
+}
+
+fn find<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "find")?;
+    let needle = expect_any(&arguments, 1, "find")?;
+    let start = optional_integer(&arguments, 2, "find", 1)?.max(1) as usize;
+
+    let table = table.borrow();
+    let position = table
+        .array()
+        .iter()
+        .skip(start - 1)
+        .position(|value| *value == needle);
+
+    Ok(vec![position
+        .map(|position| Value::Number((position + start) as f64))
+        .unwrap_or_default()])
+}
+
+/// Inserts a value at the end of a table, or at a position, moving the values after it up.
+fn insert<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "insert")?;
+    let length = table.borrow().length() as i64;
+
+    let (position, value) = match arguments.len() {
+        0..=2 => (length + 1, argument(&arguments, 1)),
+        3 => (
+            expect_integer(&arguments, 1, "insert")?,
+            argument(&arguments, 2),
+        ),
+        _ => return Err(RuntimeError::new("wrong number of arguments to 'insert'")),
+    };
+
+    if position < 1 || position > length + 1 {
+        return Err(RuntimeError::new(
+            "invalid argument #2 to 'insert' (position out of bounds)",
+        ));
+    }
+
+    let mut table = table.borrow_mut();
+
+    for index in (position..=length).rev() {
+        let moved = table.get(&Value::Number(index as f64));
+        table.set(Value::Number((index + 1) as f64), moved)?;
+    }
+
+    table.set(Value::Number(position as f64), value)?;
+    Ok(Vec::new())
+}
+
+fn pack<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let count = arguments.len();
+    let table = TableRef::new(Table::from_array(arguments));
+    table
+        .borrow_mut()
+        .set(Value::from("n"), Value::Number(count as f64))?;
+
+    Ok(vec![table.into()])
+}
+
+/// Removes the value at the end of a table, or at a position, moving the values after it down.
+fn remove<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "remove")?;
+    let length = table.borrow().length() as i64;
+    let position = optional_integer(&arguments, 1, "remove", length)?;
+
+    if length == 0 && argument(&arguments, 1).is_nil() {
+        return Ok(vec![Value::Nil]);
+    }
+
+    if position < 1 || position > length + 1 {
+        return Err(RuntimeError::new(
+            "invalid argument #2 to 'remove' (position out of bounds)",
+        ));
+    }
+
+    let mut table = table.borrow_mut();
+    let removed = table.get(&Value::Number(position as f64));
+
+    for index in position..length {
+        let moved = table.get(&Value::Number((index + 1) as f64));
+        table.set(Value::Number(index as f64), moved)?;
+    }
+
+    if position <= length {
+        table.set(Value::Number(length as f64), Value::Nil)?;
+    }
+
+    Ok(vec![removed])
+}
+
+/// Sorts the array of a table in place, with `<` or the given comparison function.
+fn sort<'a>(interpreter: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "sort")?;
+    let comparator = argument(&arguments, 1);
+    let mut values = table.borrow().array().to_vec();
+
+    let mut less = |left: &Value<'a>, right: &Value<'a>| match &comparator {
+        Value::Nil => interpreter.less_than(left.clone(), right.clone()),
+        comparator => interpreter
+            .call(comparator, vec![left.clone(), right.clone()])
+            .map(|results| results.first().is_some_and(Value::is_truthy)),
+    };
+
+    merge_sort(&mut values, &mut less)?;
+    *table.borrow_mut().array_mut() = values;
+
+    Ok(Vec::new())
+}
+
+/// Sorts values stably with a comparison that can fail.
+fn merge_sort<'a, F>(values: &mut Vec<Value<'a>>, less: &mut F) -> Result<(), RuntimeError>
+where
+    F: FnMut(&Value<'a>, &Value<'a>) -> Result<bool, RuntimeError>,
+{
+    if values.len() <= 1 {
+        return Ok(());
+    }
+
+    let mut right = values.split_off(values.len() / 2);
+    let mut left = std::mem::take(values);
+
+    merge_sort(&mut left, less)?;
+    merge_sort(&mut right, less)?;
+
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        match less(r, l)? {
+            true => values.push(right.next().unwrap()),
+            false => values.push(left.next().unwrap()),
+        }
+    }
+
+    values.extend(left);
+    values.extend(right);
+
+    Ok(())
+}
+
+pub(super) fn unpack<'a>(_: &mut Interpreter<'a>, arguments: Vec<Value<'a>>) -> Results<'a> {
+    let table = expect_table(&arguments, 0, "unpack")?;
+    let start = optional_integer(&arguments, 1, "unpack", 1)?;
+    let end = optional_integer(&arguments, 2, "unpack", table.borrow().length() as i64)?;
+
+    let table = table.borrow();
+
+    Ok((start..=end)
+        .map(|index| table.get(&Value::Number(index as f64)))
+        .collect())
+}
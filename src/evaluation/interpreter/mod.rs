@@ -0,0 +1,278 @@
+//! A tree-walking interpreter for Luau, with a minimal standard library.
+//! ```
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! use luna::evaluation::{Interpreter, Value};
+//! use luna::parser::parse;
+//!
+//! let chunk = parse(
+//!     "local counts = setmetatable({}, { __index = function() return 0 end })
+//!     for _, word in ipairs({ 'a', 'b', 'a' }) do
+//!         counts[word] += 1
+//!     end
+//!     print(counts.a, counts.b, counts.c)
+//!     return string.format('%d words', #counts), ...",
+//! )
+//! .unwrap();
+//!
+//! let output = Rc::new(RefCell::new(String::new()));
+//! let printed = output.clone();
+//! let mut interpreter = Interpreter::with_output(move |line| printed.borrow_mut().push_str(line));
+//!
+//! let results = interpreter.execute(chunk.block(), vec![Value::from(true)]).unwrap();
+//!
+//! assert_eq!(results, vec![Value::from("0 words"), Value::from(true)]);
+//! assert_eq!(output.borrow().as_str(), "2\t1\t0\n");
+//! ```
+mod environment;
+mod expression;
+mod library;
+mod statement;
+mod table;
+mod value;
+
+use std::fmt;
+
+use crate::ast::Block;
+
+use environment::Environment;
+use statement::Flow;
+
+pub use table::Table;
+pub use value::{FunctionRef, NativeFunction, TableRef, Value};
+
+/// The deepest calls can nest before raising a stack overflow, as in Luau.
+const MAX_CALL_DEPTH: usize = 20000;
+
+/// The Rust stack left before a call grows it, and the size of each segment it grows by, as the
+/// tree-walker recurses far deeper per call than the native stack allows for.
+const STACK_RED_ZONE: usize = 256 * 1024;
+const STACK_SEGMENT: usize = 4 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeError {
+    message: String,
+}
+
+impl RuntimeError {
+    /// Constructs a new [`RuntimeError`] with the given message.
+    pub fn new<T: AsRef<str>>(message: T) -> Self {
+        Self {
+            message: message.as_ref().to_string(),
+        }
+    }
+
+    /// Returns the message of this [`RuntimeError`].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RuntimeError {}
+
+/// Executes blocks of the syntax tree, which must outlive the interpreter and its values.
+pub struct Interpreter<'a> {
+    globals: TableRef<'a>,
+    /// The `string` library, which is also the `__index` of every string.
+    strings: TableRef<'a>,
+    output: Box<dyn FnMut(&str) + 'a>,
+    depth: usize,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Constructs a new [`Interpreter`] with the standard library, where `print` writes to stdout.
+    pub fn new() -> Self {
+        Self::with_output(|text| print!("{}", text))
+    }
+
+    /// Constructs a new [`Interpreter`] with the standard library, where `print` calls the given
+    /// function with each line it prints, including the newline.
+    pub fn with_output<F: FnMut(&str) + 'a>(output: F) -> Self {
+        let mut interpreter = Self {
+            globals: TableRef::default(),
+            strings: TableRef::default(),
+            output: Box::new(output),
+            depth: 0,
+        };
+
+        library::open(&mut interpreter);
+        interpreter
+    }
+}
+
+impl Default for Interpreter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns the table of global variables of this [`Interpreter`].
+    pub fn globals(&self) -> &TableRef<'a> {
+        &self.globals
+    }
+
+    /// Returns the value of the given global variable.
+    pub fn global(&self, name: &str) -> Value<'a> {
+        self.globals.borrow().get(&Value::from(name))
+    }
+
+    /// Sets the value of the given global variable.
+    pub fn set_global<T: Into<Value<'a>>>(&mut self, name: &str, value: T) {
+        // A string key is never nil or NaN, so this cannot fail.
+        let _ = self
+            .globals
+            .borrow_mut()
+            .set(Value::from(name), value.into());
+    }
+
+    /// Executes a chunk with the given arguments as its varargs, returning the values it returns.
+    pub fn execute(
+        &mut self,
+        block: &'a Block,
+        arguments: Vec<Value<'a>>,
+    ) -> Result<Vec<Value<'a>>, RuntimeError> {
+        let environment = Environment::function(None, arguments);
+
+        match self.execute_block(block, &environment)? {
+            Flow::Return(values) => Ok(values),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Calls a value with the given arguments, including through a `__call` metamethod.
+    /// ```
+    /// use luna::evaluation::{Interpreter, Value};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse(
+    ///     "local function depth(n) if n == 0 then return 0 end return 1 + depth(n - 1) end
+    ///     return depth(5000), pcall(function() local function loop() return 1 + loop() end return loop() end)",
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut interpreter = Interpreter::new();
+    /// let results = interpreter.execute(chunk.block(), Vec::new()).unwrap();
+    ///
+    /// assert_eq!(results[0], Value::from(5000.0));
+    /// assert_eq!(results[1], Value::from(false));
+    /// ```
+    pub fn call(
+        &mut self,
+        function: &Value<'a>,
+        arguments: Vec<Value<'a>>,
+    ) -> Result<Vec<Value<'a>>, RuntimeError> {
+        if self.depth >= MAX_CALL_DEPTH {
+            return Err(RuntimeError::new("stack overflow"));
+        }
+
+        self.depth += 1;
+        let results = stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT, || {
+            self.call_unchecked(function, arguments)
+        });
+        self.depth -= 1;
+
+        results
+    }
+
+    /// Writes text through the output of this [`Interpreter`].
+    pub(crate) fn write(&mut self, text: &str) {
+        (self.output)(text);
+    }
+
+    pub(crate) fn strings(&self) -> &TableRef<'a> {
+        &self.strings
+    }
+
+    fn call_unchecked(
+        &mut self,
+        function: &Value<'a>,
+        mut arguments: Vec<Value<'a>>,
+    ) -> Result<Vec<Value<'a>>, RuntimeError> {
+        let callable = match function {
+            Value::Function(function) => function.0.clone(),
+            _ => match self.metamethod(function, "__call") {
+                Some(metamethod) => {
+                    arguments.insert(0, function.clone());
+                    return self.call(&metamethod, arguments);
+                }
+                None => {
+                    return Err(RuntimeError::new(format!(
+                        "attempt to call a {} value",
+                        function.type_name()
+                    )))
+                }
+            },
+        };
+
+        match &*callable {
+            value::Callable::Native(function) => function(self, arguments),
+            value::Callable::Closure {
+                function,
+                environment,
+                method,
+            } => {
+                let mut arguments = arguments.into_iter();
+
+                let receiver = match method {
+                    true => Some(arguments.next().unwrap_or_default()),
+                    false => None,
+                };
+
+                let parameters = function
+                    .parameters()
+                    .iter()
+                    .map(|parameter| {
+                        (
+                            parameter.name.as_str(),
+                            arguments.next().unwrap_or_default(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let varargs = match function.variadic() {
+                    Some(_) => arguments.collect(),
+                    None => Vec::new(),
+                };
+
+                let scope = Environment::function(Some(environment.clone()), varargs);
+
+                if let Some(receiver) = receiver {
+                    scope.declare("self", receiver);
+                }
+
+                for (name, value) in parameters {
+                    scope.declare(name, value);
+                }
+
+                match self.execute_block(function.block(), &scope)? {
+                    Flow::Return(values) => Ok(values),
+                    _ => Ok(Vec::new()),
+                }
+            }
+        }
+    }
+
+    /// Returns the metamethod of the given name of a value, if it has one.
+    pub(crate) fn metamethod(&self, value: &Value<'a>, name: &str) -> Option<Value<'a>> {
+        let metatable = match value {
+            Value::Table(table) => table.borrow().metatable()?.clone(),
+            Value::String(_) if name == "__index" => return Some(self.strings.clone().into()),
+            _ => return None,
+        };
+
+        let metamethod = metatable.borrow().get(&Value::from(name));
+
+        match metamethod {
+            Value::Nil => None,
+            metamethod => Some(metamethod),
+        }
+    }
+}
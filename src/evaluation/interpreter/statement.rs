@@ -0,0 +1,337 @@
+use std::rc::Rc;
+
+use super::environment::Variable;
+use super::expression::to_number;
+use super::library;
+use super::{Environment, Interpreter, RuntimeError, Value};
+use crate::ast::{Block, Expression, GenericFor, NumericFor, Statement};
+
+/// How execution continues after a statement.
+pub(crate) enum Flow<'a> {
+    Normal,
+    Break,
+    Continue,
+    Return(Vec<Value<'a>>),
+}
+
+/// A location that can be assigned to.
+enum Place<'a> {
+    Local(Variable<'a>),
+    Global(&'a str),
+    Index(Value<'a>, Value<'a>),
+}
+
+impl<'a> Interpreter<'a> {
+    /// Executes the statements of a block in a new scope nested in the given one.
+    pub(crate) fn execute_block(
+        &mut self,
+        block: &'a Block,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Flow<'a>, RuntimeError> {
+        let scope = Environment::block(environment);
+        self.execute_statements(block, &scope)
+    }
+
+    fn execute_statements(
+        &mut self,
+        block: &'a Block,
+        scope: &Rc<Environment<'a>>,
+    ) -> Result<Flow<'a>, RuntimeError> {
+        for statement in block.statements() {
+            match self.execute_statement(statement, scope)? {
+                Flow::Normal => {}
+                flow => return Ok(flow),
+            }
+        }
+
+        Ok(Flow::Normal)
+    }
+
+    fn execute_statement(
+        &mut self,
+        statement: &'a Statement,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Flow<'a>, RuntimeError> {
+        match statement {
+            Statement::LocalAssign(assign) => {
+                let mut values = self
+                    .evaluate_list(assign.values(), environment)?
+                    .into_iter();
+
+                for binding in assign.bindings() {
+                    environment.declare(&binding.name, values.next().unwrap_or_default());
+                }
+            }
+            Statement::Assign(assign) => {
+                let places = assign
+                    .targets()
+                    .iter()
+                    .map(|target| self.place(target, environment))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let mut values = self
+                    .evaluate_list(assign.values(), environment)?
+                    .into_iter();
+
+                for place in places {
+                    self.assign(place, values.next().unwrap_or_default())?;
+                }
+            }
+            Statement::CompoundAssign(assign) => {
+                let place = self.place(assign.target(), environment)?;
+                let current = self.read(&place)?;
+                let value = self.evaluate(assign.value(), environment)?;
+                let result = self.binary(assign.operator().binary_operator(), current, value)?;
+
+                self.assign(place, result)?;
+            }
+
+            Statement::FunctionCall(call) => {
+                self.evaluate_call(call, environment)?;
+            }
+
+            Statement::Do(block) => return self.execute_block(block.block(), environment),
+            Statement::While(loop_) => {
+                while self.evaluate(loop_.condition(), environment)?.is_truthy() {
+                    match self.execute_block(loop_.block(), environment)? {
+                        Flow::Break => break,
+                        Flow::Return(values) => return Ok(Flow::Return(values)),
+                        _ => {}
+                    }
+                }
+            }
+            Statement::Repeat(loop_) => loop {
+                // The condition can refer to the locals of the block.
+                let scope = Environment::block(environment);
+
+                match self.execute_statements(loop_.block(), &scope)? {
+                    Flow::Break => break,
+                    Flow::Return(values) => return Ok(Flow::Return(values)),
+                    _ => {}
+                }
+
+                if self.evaluate(loop_.condition(), &scope)?.is_truthy() {
+                    break;
+                }
+            },
+            Statement::If(statement) => {
+                if self
+                    .evaluate(statement.condition(), environment)?
+                    .is_truthy()
+                {
+                    return self.execute_block(statement.block(), environment);
+                }
+
+                for else_if in statement.else_ifs() {
+                    if self.evaluate(else_if.condition(), environment)?.is_truthy() {
+                        return self.execute_block(else_if.block(), environment);
+                    }
+                }
+
+                if let Some(block) = statement.else_block() {
+                    return self.execute_block(block, environment);
+                }
+            }
+            Statement::NumericFor(loop_) => return self.numeric_for(loop_, environment),
+            Statement::GenericFor(loop_) => return self.generic_for(loop_, environment),
+
+            Statement::FunctionDeclaration(declaration) => {
+                let name = declaration.name();
+                let function = Value::closure(
+                    declaration.function(),
+                    environment.clone(),
+                    name.is_method(),
+                );
+
+                let mut keys = name.path()[1..]
+                    .iter()
+                    .map(String::as_str)
+                    .chain(name.method())
+                    .collect::<Vec<_>>();
+
+                match keys.pop() {
+                    None => {
+                        let place = match environment.lookup(name.root()) {
+                            Some(variable) => Place::Local(variable),
+                            None => Place::Global(name.root()),
+                        };
+
+                        self.assign(place, function)?;
+                    }
+                    Some(last) => {
+                        let mut object = match environment.lookup(name.root()) {
+                            Some(variable) => variable.borrow().clone(),
+                            None => self.global(name.root()),
+                        };
+
+                        for key in keys {
+                            object = self.index(&object, Value::from(key))?;
+                        }
+
+                        self.set_index(&object, Value::from(last), function)?;
+                    }
+                }
+            }
+            Statement::LocalFunction(declaration) => {
+                // The local is declared first, so that the function can call itself.
+                environment.declare(declaration.name(), Value::Nil);
+                let function = Value::closure(declaration.function(), environment.clone(), false);
+
+                if let Some(variable) = environment.lookup(declaration.name()) {
+                    *variable.borrow_mut() = function;
+                }
+            }
+
+            Statement::Return(statement) => {
+                return Ok(Flow::Return(
+                    self.evaluate_list(statement.values(), environment)?,
+                ))
+            }
+            Statement::Break(_) => return Ok(Flow::Break),
+            Statement::Continue(_) => return Ok(Flow::Continue),
+
+            Statement::TypeDeclaration(_) => {}
+        }
+
+        Ok(Flow::Normal)
+    }
+
+    fn place(
+        &mut self,
+        target: &'a Expression,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Place<'a>, RuntimeError> {
+        match target {
+            Expression::Identifier(identifier) => Ok(match environment.lookup(identifier.name()) {
+                Some(variable) => Place::Local(variable),
+                None => Place::Global(identifier.name()),
+            }),
+            Expression::Index(index) => {
+                let object = self.evaluate(index.object(), environment)?;
+                let key = self.evaluate_key(index.key(), environment)?;
+
+                Ok(Place::Index(object, key))
+            }
+            _ => Err(RuntimeError::new("cannot assign to this expression")),
+        }
+    }
+
+    fn read(&mut self, place: &Place<'a>) -> Result<Value<'a>, RuntimeError> {
+        match place {
+            Place::Local(variable) => Ok(variable.borrow().clone()),
+            Place::Global(name) => Ok(self.global(name)),
+            Place::Index(object, key) => self.index(object, key.clone()),
+        }
+    }
+
+    fn assign(&mut self, place: Place<'a>, value: Value<'a>) -> Result<(), RuntimeError> {
+        match place {
+            Place::Local(variable) => *variable.borrow_mut() = value,
+            Place::Global(name) => self.set_global(name, value),
+            Place::Index(object, key) => self.set_index(&object, key, value)?,
+        }
+
+        Ok(())
+    }
+
+    fn numeric_for(
+        &mut self,
+        loop_: &'a NumericFor,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Flow<'a>, RuntimeError> {
+        let mut bound = |expression: Option<&'a Expression>, name: &str| {
+            let value = match expression {
+                Some(expression) => self.evaluate(expression, environment)?,
+                None => Value::Number(1.0),
+            };
+
+            to_number(&value)
+                .ok_or_else(|| RuntimeError::new(format!("'for' {} must be a number", name)))
+        };
+
+        let start = bound(Some(loop_.start()), "initial value")?;
+        let end = bound(Some(loop_.end()), "limit")?;
+        let step = bound(loop_.step(), "step")?;
+
+        if step == 0.0 {
+            return Err(RuntimeError::new("'for' step is zero"));
+        }
+
+        let mut counter = start;
+
+        while (step > 0.0 && counter <= end) || (step < 0.0 && counter >= end) {
+            let scope = Environment::block(environment);
+            scope.declare(&loop_.binding().name, Value::Number(counter));
+
+            match self.execute_block(loop_.block(), &scope)? {
+                Flow::Break => break,
+                Flow::Return(values) => return Ok(Flow::Return(values)),
+                _ => {}
+            }
+
+            counter += step;
+        }
+
+        Ok(Flow::Normal)
+    }
+
+    fn generic_for(
+        &mut self,
+        loop_: &'a GenericFor,
+        environment: &Rc<Environment<'a>>,
+    ) -> Result<Flow<'a>, RuntimeError> {
+        let mut values = self.evaluate_list(loop_.values(), environment)?.into_iter();
+        let mut iterator = values.next().unwrap_or_default();
+        let mut state = values.next().unwrap_or_default();
+        let mut control = values.next().unwrap_or_default();
+
+        // Tables are iterated directly, through `__iter` or as `next` does.
+        if let Value::Table(_) = iterator {
+            match self.metamethod(&iterator, "__iter") {
+                Some(metamethod) => {
+                    let mut values = self.call(&metamethod, vec![iterator])?.into_iter();
+                    iterator = values.next().unwrap_or_default();
+                    state = values.next().unwrap_or_default();
+                    control = values.next().unwrap_or_default();
+                }
+                None if self.metamethod(&iterator, "__call").is_none() => {
+                    state = iterator;
+                    iterator = Value::native(library::next);
+                    control = Value::Nil;
+                }
+                None => {}
+            }
+        }
+
+        loop {
+            let mut results = self
+                .call(&iterator, vec![state.clone(), control])?
+                .into_iter();
+
+            control = results.next().unwrap_or_default();
+
+            if control.is_nil() {
+                break;
+            }
+
+            let scope = Environment::block(environment);
+            let mut bindings = loop_.bindings().iter();
+
+            if let Some(binding) = bindings.next() {
+                scope.declare(&binding.name, control.clone());
+            }
+
+            for binding in bindings {
+                scope.declare(&binding.name, results.next().unwrap_or_default());
+            }
+
+            match self.execute_block(loop_.block(), &scope)? {
+                Flow::Break => break,
+                Flow::Return(values) => return Ok(Flow::Return(values)),
+                _ => {}
+            }
+        }
+
+        Ok(Flow::Normal)
+    }
+}
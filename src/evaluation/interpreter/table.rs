@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use super::{RuntimeError, TableRef, Value};
+
+/// A table, which stores the sequence from `1` apart from the other keys.
+#[derive(Default)]
+pub struct Table<'a> {
+    array: Vec<Value<'a>>,
+    /// The other entries in insertion order, where removed entries are kept with a `nil` value so
+    /// that iteration can continue past them.
+    entries: Vec<(Value<'a>, Value<'a>)>,
+    index: HashMap<Key, usize>,
+    metatable: Option<TableRef<'a>>,
+}
+
+/// A value that can be hashed, where tables and functions are identified by their address.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Key {
+    Boolean(bool),
+    Number(u64),
+    String(Rc<[u8]>),
+    Reference(usize),
+}
+
+impl Key {
+    fn new(value: &Value) -> Option<Self> {
+        match value {
+            Value::Nil => None,
+            Value::Boolean(value) => Some(Self::Boolean(*value)),
+            Value::Number(number) if number.is_nan() => None,
+            // `0` and `-0` are the same key.
+            Value::Number(number) => Some(Self::Number((number + 0.0).to_bits())),
+            Value::String(bytes) => Some(Self::String(bytes.clone())),
+            Value::Table(table) => Some(Self::Reference(table.address())),
+            Value::Function(function) => Some(Self::Reference(function.address())),
+        }
+    }
+}
+
+/// Returns the position in the array of a key, if it is a positive integer.
+fn array_position(key: &Value) -> Option<usize> {
+    match key {
+        Value::Number(number) if number.fract() == 0.0 && *number >= 1.0 => {
+            Some(*number as usize - 1)
+        }
+        _ => None,
+    }
+}
+
+impl<'a> Table<'a> {
+    /// Constructs a new [`Table`] with the given array items.
+    pub fn from_array(mut array: Vec<Value<'a>>) -> Self {
+        while array.last().is_some_and(Value::is_nil) {
+            array.pop();
+        }
+
+        Self {
+            array,
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> Table<'a> {
+    /// Returns the value of the given key, without metamethods.
+    pub fn get(&self, key: &Value<'a>) -> Value<'a> {
+        if let Some(position) = array_position(key) {
+            if position < self.array.len() {
+                return self.array[position].clone();
+            }
+        }
+
+        Key::new(key)
+            .and_then(|key| self.index.get(&key))
+            .map(|slot| self.entries[*slot].1.clone())
+            .unwrap_or_default()
+    }
+
+    /// Sets the value of the given key without metamethods, where a `nil` value removes the key.
+    pub fn set(&mut self, key: Value<'a>, value: Value<'a>) -> Result<(), RuntimeError> {
+        if let Some(position) = array_position(&key) {
+            if position < self.array.len() {
+                self.array[position] = value;
+
+                while self.array.last().is_some_and(Value::is_nil) {
+                    self.array.pop();
+                }
+
+                return Ok(());
+            }
+
+            if position == self.array.len() && !value.is_nil() {
+                self.remove_entry(&key);
+                self.array.push(value);
+                self.absorb_entries();
+
+                return Ok(());
+            }
+        }
+
+        let hashed = match (&key, Key::new(&key)) {
+            (_, Some(hashed)) => hashed,
+            (Value::Nil, None) => return Err(RuntimeError::new("table index is nil")),
+            (_, None) => return Err(RuntimeError::new("table index is NaN")),
+        };
+
+        match self.index.get(&hashed) {
+            Some(slot) => self.entries[*slot].1 = value,
+            None if value.is_nil() => {}
+            None => {
+                self.index.insert(hashed, self.entries.len());
+                self.entries.push((key, value));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_entry(&mut self, key: &Value<'a>) {
+        if let Some(slot) = Key::new(key).and_then(|key| self.index.get(&key)) {
+            self.entries[*slot].1 = Value::Nil;
+        }
+    }
+
+    /// Moves the entries that continue the sequence into the array.
+    fn absorb_entries(&mut self) {
+        loop {
+            let key = Value::Number((self.array.len() + 1) as f64);
+
+            match self.get(&key) {
+                Value::Nil => break,
+                value => {
+                    self.remove_entry(&key);
+                    self.array.push(value);
+                }
+            }
+        }
+    }
+
+    /// Returns the length of the sequence of this [`Table`], as `#` does without metamethods.
+    pub fn length(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Returns the entry after the given key, as `next` does, or `None` after the last entry.
+    pub fn next(&self, key: &Value<'a>) -> Result<Option<(Value<'a>, Value<'a>)>, RuntimeError> {
+        let start = match key {
+            Value::Nil => 0,
+            _ => match array_position(key).filter(|position| *position < self.array.len()) {
+                Some(position) => position + 1,
+                None => match Key::new(key).and_then(|key| self.index.get(&key)) {
+                    Some(slot) => self.array.len() + slot + 1,
+                    // The array shrinks when its last items are removed during iteration.
+                    None if array_position(key).is_some() => self.array.len(),
+                    None => return Err(RuntimeError::new("invalid key to 'next'")),
+                },
+            },
+        };
+
+        let array = self
+            .array
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find(|(_, value)| !value.is_nil());
+
+        if let Some((position, value)) = array {
+            return Ok(Some((Value::Number((position + 1) as f64), value.clone())));
+        }
+
+        let entry = self
+            .entries
+            .iter()
+            .skip(start.saturating_sub(self.array.len()))
+            .find(|(_, value)| !value.is_nil());
+
+        Ok(entry.cloned())
+    }
+
+    /// Returns the metatable of this [`Table`].
+    pub fn metatable(&self) -> Option<&TableRef<'a>> {
+        self.metatable.as_ref()
+    }
+
+    /// Sets the metatable of this [`Table`].
+    pub fn set_metatable(&mut self, metatable: Option<TableRef<'a>>) {
+        self.metatable = metatable;
+    }
+
+    /// Returns the array items of this [`Table`], from `1` to its length.
+    pub fn array(&self) -> &[Value<'a>] {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the array items of this [`Table`].
+    pub(crate) fn array_mut(&mut self) -> &mut Vec<Value<'a>> {
+        &mut self.array
+    }
+}
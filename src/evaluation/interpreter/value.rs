@@ -0,0 +1,238 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::fmt;
+use std::rc::Rc;
+
+use super::{Environment, Interpreter, RuntimeError, Table};
+use crate::ast::Function;
+use crate::evaluation::constant::format_number;
+
+/// A function implemented in Rust, which receives its arguments and returns its results.
+pub type NativeFunction<'a> =
+    dyn Fn(&mut Interpreter<'a>, Vec<Value<'a>>) -> Result<Vec<Value<'a>>, RuntimeError> + 'a;
+
+/// A value of the interpreter, borrowing the syntax tree of the functions it contains.
+#[derive(Clone, Default)]
+pub enum Value<'a> {
+    #[default]
+    Nil,
+    Boolean(bool),
+    Number(f64),
+    /// The bytes of a string, which need not be valid UTF-8.
+    String(Rc<[u8]>),
+    Table(TableRef<'a>),
+    Function(FunctionRef<'a>),
+}
+
+impl<'a> Value<'a> {
+    /// Constructs a new function [`Value`] that calls the given Rust function.
+    pub fn native<F>(function: F) -> Self
+    where
+        F: Fn(&mut Interpreter<'a>, Vec<Value<'a>>) -> Result<Vec<Value<'a>>, RuntimeError> + 'a,
+    {
+        Self::Function(FunctionRef(Rc::new(Callable::Native(Box::new(function)))))
+    }
+
+    /// Constructs a new table [`Value`] with the given array items.
+    pub fn array(items: Vec<Value<'a>>) -> Self {
+        Self::Table(TableRef::new(Table::from_array(items)))
+    }
+
+    pub(crate) fn closure(
+        function: &'a Function,
+        environment: Rc<Environment<'a>>,
+        method: bool,
+    ) -> Self {
+        Self::Function(FunctionRef(Rc::new(Callable::Closure {
+            function,
+            environment,
+            method,
+        })))
+    }
+}
+
+impl<'a> Value<'a> {
+    /// Returns the name of the type of this [`Value`], as `type` does.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Nil => "nil",
+            Self::Boolean(_) => "boolean",
+            Self::Number(_) => "number",
+            Self::String(_) => "string",
+            Self::Table(_) => "table",
+            Self::Function(_) => "function",
+        }
+    }
+
+    /// Returns whether this [`Value`] is truthy, which is every value but `nil` and `false`.
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Self::Nil | Self::Boolean(false))
+    }
+
+    /// Returns whether this [`Value`] is `nil`.
+    pub fn is_nil(&self) -> bool {
+        matches!(self, Self::Nil)
+    }
+
+    /// Returns the number of this [`Value`], without converting strings.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of this [`Value`], if it is a string.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::String(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of this [`Value`], if it is a string of valid UTF-8.
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_bytes()
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+    }
+
+    /// Returns the table of this [`Value`], if it is a table.
+    pub fn as_table(&self) -> Option<&TableRef<'a>> {
+        match self {
+            Self::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes this [`Value`] converts to in concatenation, which only numbers and strings do.
+    pub(crate) fn to_concat_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Number(number) => Some(format_number(*number).into_bytes()),
+            Self::String(bytes) => Some(bytes.to_vec()),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Value<'_> {
+    /// Compares values without metamethods, where tables and functions are equal only to themselves.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Nil, Self::Nil) => true,
+            (Self::Boolean(left), Self::Boolean(right)) => left == right,
+            (Self::Number(left), Self::Number(right)) => left == right,
+            (Self::String(left), Self::String(right)) => left == right,
+            (Self::Table(left), Self::Table(right)) => left == right,
+            (Self::Function(left), Self::Function(right)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nil => write!(f, "nil"),
+            Self::Boolean(value) => write!(f, "{}", value),
+            Self::Number(number) => write!(f, "{}", format_number(*number)),
+            Self::String(bytes) => write!(f, "{:?}", String::from_utf8_lossy(bytes)),
+            Self::Table(table) => write!(f, "table: {:p}", Rc::as_ptr(&table.0)),
+            Self::Function(function) => write!(f, "function: {:p}", Rc::as_ptr(&function.0)),
+        }
+    }
+}
+
+impl From<bool> for Value<'_> {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<f64> for Value<'_> {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for Value<'_> {
+    fn from(value: &str) -> Self {
+        Self::String(value.as_bytes().into())
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(value: String) -> Self {
+        Self::String(value.into_bytes().into())
+    }
+}
+
+impl From<Vec<u8>> for Value<'_> {
+    fn from(value: Vec<u8>) -> Self {
+        Self::String(value.into())
+    }
+}
+
+impl<'a> From<TableRef<'a>> for Value<'a> {
+    fn from(value: TableRef<'a>) -> Self {
+        Self::Table(value)
+    }
+}
+
+/// A shared reference to a [`Table`], which is equal only to references to the same table.
+#[derive(Clone, Default)]
+pub struct TableRef<'a>(Rc<RefCell<Table<'a>>>);
+
+impl<'a> TableRef<'a> {
+    /// Constructs a new [`TableRef`] to the given [`Table`].
+    pub fn new(table: Table<'a>) -> Self {
+        Self(Rc::new(RefCell::new(table)))
+    }
+
+    /// Borrows the [`Table`] of this [`TableRef`].
+    pub fn borrow(&self) -> Ref<'_, Table<'a>> {
+        self.0.borrow()
+    }
+
+    /// Mutably borrows the [`Table`] of this [`TableRef`].
+    pub fn borrow_mut(&self) -> RefMut<'_, Table<'a>> {
+        self.0.borrow_mut()
+    }
+
+    /// Returns the address of the [`Table`], which identifies it.
+    pub(crate) fn address(&self) -> usize {
+        Rc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl PartialEq for TableRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A shared reference to a function, which is equal only to references to the same function.
+#[derive(Clone)]
+pub struct FunctionRef<'a>(pub(crate) Rc<Callable<'a>>);
+
+impl FunctionRef<'_> {
+    /// Returns the address of the function, which identifies it.
+    pub(crate) fn address(&self) -> usize {
+        Rc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl PartialEq for FunctionRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+pub(crate) enum Callable<'a> {
+    /// A function of the syntax tree, along with the environment it captures.
+    Closure {
+        function: &'a Function,
+        environment: Rc<Environment<'a>>,
+        /// Whether the function was declared as a method, taking an implicit `self`.
+        method: bool,
+    },
+    Native(Box<NativeFunction<'a>>),
+}
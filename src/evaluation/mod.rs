@@ -1,7 +1,14 @@
 mod associativity;
+mod constant;
+mod interpreter;
 mod precedence;
 
 pub use associativity::{Associativity, AssociativityValue};
+pub(crate) use constant::{quote_string, unquote_string};
+pub use constant::{Constant, ConstantValue};
+pub use interpreter::{
+    FunctionRef, Interpreter, NativeFunction, RuntimeError, Table, TableRef, Value,
+};
 pub use precedence::{Precedence, PrecedenceValue};
 
 pub struct EvaluationRules {
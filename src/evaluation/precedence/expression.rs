@@ -9,10 +9,28 @@ impl Precedence for Expression {
             Self::BooleanLiteral(expr) => expr.precedence(),
             Self::NumberLiteral(expr) => expr.precedence(),
             Self::StringLiteral(expr) => expr.precedence(),
+            Self::VarargLiteral(expr) => expr.precedence(),
+            Self::InterpolatedString(expr) => expr.precedence(),
+
+            // Variable
+            Self::Identifier(_) => PrecedenceValue::Verbatim,
+            Self::Index(_) => PrecedenceValue::Verbatim,
+
+            // Constructor
+            Self::Function(_) => PrecedenceValue::Verbatim,
+            Self::TableConstructor(_) => PrecedenceValue::Verbatim,
+
+            // Call
+            Self::FunctionCall(_) => PrecedenceValue::Verbatim,
 
             // Operation
             Self::UnaryOperation(expr) => expr.precedence(),
             Self::BinaryOperation(expr) => expr.precedence(),
+
+            // Other
+            Self::Parenthesized(_) => PrecedenceValue::Verbatim,
+            Self::IfExpression(expr) => expr.precedence(),
+            Self::TypeAssertion(expr) => expr.precedence(),
         }
     }
 }
@@ -1,5 +1,7 @@
 use super::{Precedence, PrecedenceValue};
-use crate::ast::{BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral};
+use crate::ast::{
+    BooleanLiteral, InterpolatedString, NilLiteral, NumberLiteral, StringLiteral, VarargLiteral,
+};
 
 impl Precedence for NilLiteral {
     fn precedence(&self) -> PrecedenceValue {
@@ -24,3 +26,15 @@ impl Precedence for StringLiteral {
         PrecedenceValue::Verbatim
     }
 }
+
+impl Precedence for VarargLiteral {
+    fn precedence(&self) -> PrecedenceValue {
+        PrecedenceValue::Verbatim
+    }
+}
+
+impl Precedence for InterpolatedString {
+    fn precedence(&self) -> PrecedenceValue {
+        PrecedenceValue::Verbatim
+    }
+}
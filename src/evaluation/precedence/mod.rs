@@ -3,17 +3,20 @@ mod literal;
 mod operation;
 mod operator;
 
+/// The precedence of an expression, from the most tightly binding to the least.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum PrecedenceValue {
     Verbatim,
-    Unary,
+    Assertion,
     Exponentiation,
+    Unary,
     Multiplicative,
     Additive,
+    Concatenation,
+    Comparison,
     And,
     Or,
-    Equality,
-    Relational,
+    Conditional,
 }
 
 pub trait Precedence {
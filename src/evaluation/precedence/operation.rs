@@ -1,5 +1,5 @@
 use super::{Precedence, PrecedenceValue};
-use crate::ast::{BinaryOperation, UnaryOperation};
+use crate::ast::{BinaryOperation, IfExpression, TypeAssertion, UnaryOperation};
 
 impl Precedence for UnaryOperation {
     fn precedence(&self) -> PrecedenceValue {
@@ -12,3 +12,15 @@ impl Precedence for BinaryOperation {
         self.operator().precedence()
     }
 }
+
+impl Precedence for TypeAssertion {
+    fn precedence(&self) -> PrecedenceValue {
+        PrecedenceValue::Assertion
+    }
+}
+
+impl Precedence for IfExpression {
+    fn precedence(&self) -> PrecedenceValue {
+        PrecedenceValue::Conditional
+    }
+}
@@ -14,16 +14,18 @@ impl Precedence for BinaryOperator {
             Self::Subtract => PrecedenceValue::Additive,
             Self::Multiply => PrecedenceValue::Multiplicative,
             Self::Divide => PrecedenceValue::Multiplicative,
+            Self::FloorDivide => PrecedenceValue::Multiplicative,
             Self::Modulo => PrecedenceValue::Multiplicative,
             Self::Power => PrecedenceValue::Exponentiation,
+            Self::Concat => PrecedenceValue::Concatenation,
             Self::And => PrecedenceValue::And,
             Self::Or => PrecedenceValue::Or,
-            Self::Equal => PrecedenceValue::Equality,
-            Self::NotEqual => PrecedenceValue::Equality,
-            Self::LessThan => PrecedenceValue::Relational,
-            Self::GreaterThan => PrecedenceValue::Relational,
-            Self::GreaterThanOrEqual => PrecedenceValue::Relational,
-            Self::LessThanOrEqual => PrecedenceValue::Relational,
+            Self::Equal => PrecedenceValue::Comparison,
+            Self::NotEqual => PrecedenceValue::Comparison,
+            Self::LessThan => PrecedenceValue::Comparison,
+            Self::GreaterThan => PrecedenceValue::Comparison,
+            Self::GreaterThanOrEqual => PrecedenceValue::Comparison,
+            Self::LessThanOrEqual => PrecedenceValue::Comparison,
         }
     }
 }
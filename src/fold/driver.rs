@@ -0,0 +1,133 @@
+use crate::ast::{Expression, Statement, TypeAnnotation};
+use crate::fold::{self, Fold};
+
+/// A set of local rewrite rules, applied to every node of a tree by [`BottomUp`] or [`TopDown`].
+///
+/// Every method defaults to returning its node unchanged.
+pub trait Rewriter {
+    fn rewrite_statement(&mut self, statement: Statement) -> Statement {
+        statement
+    }
+
+    fn rewrite_expression(&mut self, expression: Expression) -> Expression {
+        expression
+    }
+
+    fn rewrite_type_annotation(&mut self, annotation: TypeAnnotation) -> TypeAnnotation {
+        annotation
+    }
+}
+
+/// Applies a [`Rewriter`] to every node after its children have been folded.
+///
+/// A rewritten node is not revisited, so rules that create new opportunities for themselves
+/// should be run to a [`fixed_point`].
+/// ```
+/// use luna::ast::{BooleanLiteral, Expression, UnaryOperation};
+/// use luna::fold::{BottomUp, Fold, Rewriter};
+///
+/// // Replaces `not true` with `false`, and `not false` with `true`.
+/// struct NotLiteral;
+///
+/// impl Rewriter for NotLiteral {
+///     fn rewrite_expression(&mut self, expression: Expression) -> Expression {
+///         match &expression {
+///             Expression::UnaryOperation(operation) => match operation.operand() {
+///                 Expression::BooleanLiteral(literal) => BooleanLiteral::from(!literal.value()).into(),
+///                 _ => expression,
+///             },
+///             _ => expression,
+///         }
+///     }
+/// }
+///
+/// let expression = UnaryOperation::not(UnaryOperation::not(BooleanLiteral::from(true)));
+/// let folded = BottomUp(NotLiteral).fold_expression(expression.into());
+/// assert_eq!(folded, BooleanLiteral::from(true).into());
+/// ```
+pub struct BottomUp<R: Rewriter>(pub R);
+
+impl<R: Rewriter> Fold for BottomUp<R> {
+    fn fold_statement(&mut self, statement: Statement) -> Statement {
+        let statement = fold::fold_statement(self, statement);
+        self.0.rewrite_statement(statement)
+    }
+
+    fn fold_expression(&mut self, expression: Expression) -> Expression {
+        let expression = fold::fold_expression(self, expression);
+        self.0.rewrite_expression(expression)
+    }
+
+    fn fold_type_annotation(&mut self, annotation: TypeAnnotation) -> TypeAnnotation {
+        let annotation = fold::fold_type_annotation(self, annotation);
+        self.0.rewrite_type_annotation(annotation)
+    }
+}
+
+/// Applies a [`Rewriter`] to every node before its children are folded.
+///
+/// The children of the rewritten node are folded, rather than the children of the original node.
+pub struct TopDown<R: Rewriter>(pub R);
+
+impl<R: Rewriter> Fold for TopDown<R> {
+    fn fold_statement(&mut self, statement: Statement) -> Statement {
+        let statement = self.0.rewrite_statement(statement);
+        fold::fold_statement(self, statement)
+    }
+
+    fn fold_expression(&mut self, expression: Expression) -> Expression {
+        let expression = self.0.rewrite_expression(expression);
+        fold::fold_expression(self, expression)
+    }
+
+    fn fold_type_annotation(&mut self, annotation: TypeAnnotation) -> TypeAnnotation {
+        let annotation = self.0.rewrite_type_annotation(annotation);
+        fold::fold_type_annotation(self, annotation)
+    }
+}
+
+/// A node that a [`Fold`] can be started from.
+pub trait Foldable: Sized {
+    fn fold_with<F: Fold + ?Sized>(self, folder: &mut F) -> Self;
+}
+
+impl Foldable for Statement {
+    fn fold_with<F: Fold + ?Sized>(self, folder: &mut F) -> Self {
+        folder.fold_statement(self)
+    }
+}
+
+impl Foldable for Expression {
+    fn fold_with<F: Fold + ?Sized>(self, folder: &mut F) -> Self {
+        folder.fold_expression(self)
+    }
+}
+
+impl Foldable for TypeAnnotation {
+    fn fold_with<F: Fold + ?Sized>(self, folder: &mut F) -> Self {
+        folder.fold_type_annotation(self)
+    }
+}
+
+/// Repeatedly folds the given node until it no longer changes, or until `max_iterations` folds have been applied.
+///
+/// Returns the final node, and whether a fixed point was reached.
+pub fn fixed_point<N, F>(folder: &mut F, node: N, max_iterations: usize) -> (N, bool)
+where
+    N: Foldable + Clone + PartialEq,
+    F: Fold + ?Sized,
+{
+    let mut current = node;
+
+    for _ in 0..max_iterations {
+        let next = current.clone().fold_with(folder);
+
+        if next == current {
+            return (next, true);
+        }
+
+        current = next;
+    }
+
+    (current, false)
+}
@@ -0,0 +1,891 @@
+mod driver;
+
+pub use driver::{fixed_point, BottomUp, Foldable, Rewriter, TopDown};
+
+use crate::ast::{
+    Assign, BinaryOperation, Binding, Block, BooleanLiteral, Break, CompoundAssign, Continue, Do,
+    ElseIf, Expression, Function, FunctionCall, FunctionDeclaration, FunctionVariadic, GenericFor,
+    Identifier, If, IfExpression, Index, IndexKey, InterpolatedString, LocalAssign, LocalFunction,
+    NilLiteral, NumberLiteral, NumericFor, Parenthesized, Repeat, Return, Statement, StringLiteral,
+    TableConstructor, TableField, TypeAnnotation, TypeArgument, TypeArray, TypeAssertion,
+    TypeBuiltIn, TypeCombination, TypeDeclaration, TypeFunction, TypeGeneric, TypeGenericPack,
+    TypeGenericParameter, TypeOptional, TypePack, TypePackTail, TypePrimitive, TypeReference,
+    TypeReferenceArgument, TypeSingleton, TypeTable, TypeTableEntry, TypeTableIndexer,
+    UnaryOperation, VarargLiteral, While,
+};
+
+/// Rebuilds a syntax tree by value.
+///
+/// Every method consumes its node and returns the node that replaces it. Methods for the variants of
+/// [`Statement`], [`Expression`] and [`TypeAnnotation`] return the enum itself, so a node can be replaced
+/// with a node of a different variant. [`FunctionCall`] and [`Function`] appear in more than one place,
+/// so their methods return the node itself. The defaults rebuild the node from its folded children
+/// through the matching `fold_*` function.
+/// ```
+/// use luna::ast::{BinaryOperation, Expression, NumberLiteral};
+/// use luna::fold::{self, Fold};
+///
+/// // Replaces `0 + x` with `x`.
+/// struct AddZero;
+///
+/// impl Fold for AddZero {
+///     fn fold_binary_operation(&mut self, operation: BinaryOperation) -> Expression {
+///         let operation = fold::fold_binary_operation(self, operation);
+///
+///         match operation.left() == &NumberLiteral::from("0").into() {
+///             true => operation.into_parts().2,
+///             false => operation.into(),
+///         }
+///     }
+/// }
+///
+/// let expression = BinaryOperation::add(NumberLiteral::from("0"), NumberLiteral::from("1"));
+/// assert_eq!(AddZero.fold_expression(expression.into()), NumberLiteral::from("1").into());
+/// ```
+pub trait Fold {
+    // Statements
+
+    fn fold_statement(&mut self, statement: Statement) -> Statement {
+        fold_statement(self, statement)
+    }
+
+    fn fold_local_assign(&mut self, local_assign: LocalAssign) -> Statement {
+        fold_local_assign(self, local_assign).into()
+    }
+
+    fn fold_assign(&mut self, assign: Assign) -> Statement {
+        fold_assign(self, assign).into()
+    }
+
+    fn fold_compound_assign(&mut self, compound_assign: CompoundAssign) -> Statement {
+        fold_compound_assign(self, compound_assign).into()
+    }
+
+    fn fold_do(&mut self, do_block: Do) -> Statement {
+        fold_do(self, do_block).into()
+    }
+
+    fn fold_while(&mut self, while_loop: While) -> Statement {
+        fold_while(self, while_loop).into()
+    }
+
+    fn fold_repeat(&mut self, repeat_loop: Repeat) -> Statement {
+        fold_repeat(self, repeat_loop).into()
+    }
+
+    fn fold_if(&mut self, if_statement: If) -> Statement {
+        fold_if(self, if_statement).into()
+    }
+
+    fn fold_else_if(&mut self, else_if: ElseIf) -> ElseIf {
+        fold_else_if(self, else_if)
+    }
+
+    fn fold_numeric_for(&mut self, numeric_for: NumericFor) -> Statement {
+        fold_numeric_for(self, numeric_for).into()
+    }
+
+    fn fold_generic_for(&mut self, generic_for: GenericFor) -> Statement {
+        fold_generic_for(self, generic_for).into()
+    }
+
+    fn fold_function_declaration(
+        &mut self,
+        function_declaration: FunctionDeclaration,
+    ) -> Statement {
+        fold_function_declaration(self, function_declaration).into()
+    }
+
+    fn fold_local_function(&mut self, local_function: LocalFunction) -> Statement {
+        fold_local_function(self, local_function).into()
+    }
+
+    fn fold_return(&mut self, return_statement: Return) -> Statement {
+        fold_return(self, return_statement).into()
+    }
+
+    fn fold_break(&mut self, break_statement: Break) -> Statement {
+        break_statement.into()
+    }
+
+    fn fold_continue(&mut self, continue_statement: Continue) -> Statement {
+        continue_statement.into()
+    }
+
+    fn fold_type_declaration(&mut self, type_declaration: TypeDeclaration) -> Statement {
+        fold_type_declaration(self, type_declaration).into()
+    }
+
+    // Expressions
+
+    fn fold_expression(&mut self, expression: Expression) -> Expression {
+        fold_expression(self, expression)
+    }
+
+    fn fold_nil_literal(&mut self, literal: NilLiteral) -> Expression {
+        literal.into()
+    }
+
+    fn fold_boolean_literal(&mut self, literal: BooleanLiteral) -> Expression {
+        literal.into()
+    }
+
+    fn fold_string_literal(&mut self, literal: StringLiteral) -> Expression {
+        literal.into()
+    }
+
+    fn fold_number_literal(&mut self, literal: NumberLiteral) -> Expression {
+        literal.into()
+    }
+
+    fn fold_vararg_literal(&mut self, literal: VarargLiteral) -> Expression {
+        literal.into()
+    }
+
+    fn fold_interpolated_string(&mut self, string: InterpolatedString) -> Expression {
+        fold_interpolated_string(self, string).into()
+    }
+
+    fn fold_identifier(&mut self, identifier: Identifier) -> Expression {
+        identifier.into()
+    }
+
+    fn fold_index(&mut self, index: Index) -> Expression {
+        fold_index(self, index).into()
+    }
+
+    fn fold_function_call(&mut self, call: FunctionCall) -> FunctionCall {
+        fold_function_call(self, call)
+    }
+
+    fn fold_function(&mut self, function: Function) -> Function {
+        fold_function(self, function)
+    }
+
+    fn fold_function_variadic(&mut self, variadic: FunctionVariadic) -> FunctionVariadic {
+        fold_function_variadic(self, variadic)
+    }
+
+    fn fold_table_constructor(&mut self, table: TableConstructor) -> Expression {
+        fold_table_constructor(self, table).into()
+    }
+
+    fn fold_table_field(&mut self, field: TableField) -> TableField {
+        fold_table_field(self, field)
+    }
+
+    fn fold_parenthesized(&mut self, parenthesized: Parenthesized) -> Expression {
+        fold_parenthesized(self, parenthesized).into()
+    }
+
+    fn fold_if_expression(&mut self, if_expression: IfExpression) -> Expression {
+        fold_if_expression(self, if_expression).into()
+    }
+
+    fn fold_type_assertion(&mut self, assertion: TypeAssertion) -> Expression {
+        fold_type_assertion(self, assertion).into()
+    }
+
+    fn fold_unary_operation(&mut self, operation: UnaryOperation) -> Expression {
+        fold_unary_operation(self, operation).into()
+    }
+
+    fn fold_binary_operation(&mut self, operation: BinaryOperation) -> Expression {
+        fold_binary_operation(self, operation).into()
+    }
+
+    // Constructs
+
+    fn fold_block(&mut self, block: Block) -> Block {
+        fold_block(self, block)
+    }
+
+    fn fold_binding(&mut self, binding: Binding) -> Binding {
+        fold_binding(self, binding)
+    }
+
+    // Type annotations
+
+    fn fold_type_annotation(&mut self, annotation: TypeAnnotation) -> TypeAnnotation {
+        fold_type_annotation(self, annotation)
+    }
+
+    fn fold_type_built_in(&mut self, annotation: TypeBuiltIn) -> TypeAnnotation {
+        annotation.into()
+    }
+
+    fn fold_type_primitive(&mut self, annotation: TypePrimitive) -> TypeAnnotation {
+        annotation.into()
+    }
+
+    fn fold_type_singleton(&mut self, annotation: TypeSingleton) -> TypeAnnotation {
+        annotation.into()
+    }
+
+    fn fold_type_reference(&mut self, annotation: TypeReference) -> TypeAnnotation {
+        fold_type_reference(self, annotation).into()
+    }
+
+    fn fold_type_reference_argument(
+        &mut self,
+        argument: TypeReferenceArgument,
+    ) -> TypeReferenceArgument {
+        fold_type_reference_argument(self, argument)
+    }
+
+    fn fold_type_function(&mut self, annotation: TypeFunction) -> TypeAnnotation {
+        fold_type_function(self, annotation).into()
+    }
+
+    fn fold_type_argument(&mut self, argument: TypeArgument) -> TypeArgument {
+        fold_type_argument(self, argument)
+    }
+
+    fn fold_type_table(&mut self, annotation: TypeTable) -> TypeAnnotation {
+        fold_type_table(self, annotation).into()
+    }
+
+    fn fold_type_table_entry(&mut self, entry: TypeTableEntry) -> TypeTableEntry {
+        fold_type_table_entry(self, entry)
+    }
+
+    fn fold_type_table_indexer(&mut self, indexer: TypeTableIndexer) -> TypeTableIndexer {
+        fold_type_table_indexer(self, indexer)
+    }
+
+    fn fold_type_array(&mut self, annotation: TypeArray) -> TypeAnnotation {
+        fold_type_array(self, annotation).into()
+    }
+
+    fn fold_type_optional(&mut self, annotation: TypeOptional) -> TypeAnnotation {
+        fold_type_optional(self, annotation).into()
+    }
+
+    fn fold_type_combination(&mut self, annotation: TypeCombination) -> TypeAnnotation {
+        fold_type_combination(self, annotation).into()
+    }
+
+    fn fold_type_typeof(&mut self, expression: Expression) -> TypeAnnotation {
+        TypeAnnotation::Typeof(self.fold_expression(expression))
+    }
+
+    fn fold_type_pack(&mut self, pack: TypePack) -> TypePack {
+        fold_type_pack(self, pack)
+    }
+
+    fn fold_type_pack_tail(&mut self, tail: TypePackTail) -> TypePackTail {
+        fold_type_pack_tail(self, tail)
+    }
+
+    fn fold_type_generic_parameter(
+        &mut self,
+        parameter: TypeGenericParameter,
+    ) -> TypeGenericParameter {
+        fold_type_generic_parameter(self, parameter)
+    }
+
+    fn fold_type_generic(&mut self, generic: TypeGeneric) -> TypeGenericParameter {
+        fold_type_generic(self, generic).into()
+    }
+
+    fn fold_type_generic_pack(&mut self, generic: TypeGenericPack) -> TypeGenericParameter {
+        fold_type_generic_pack(self, generic).into()
+    }
+}
+
+// Statements
+
+pub fn fold_statement<F: Fold + ?Sized>(folder: &mut F, statement: Statement) -> Statement {
+    match statement {
+        Statement::LocalAssign(node) => folder.fold_local_assign(node),
+        Statement::Assign(node) => folder.fold_assign(node),
+        Statement::CompoundAssign(node) => folder.fold_compound_assign(node),
+        Statement::FunctionCall(node) => folder.fold_function_call(node).into(),
+        Statement::Do(node) => folder.fold_do(node),
+        Statement::While(node) => folder.fold_while(node),
+        Statement::Repeat(node) => folder.fold_repeat(node),
+        Statement::If(node) => folder.fold_if(node),
+        Statement::NumericFor(node) => folder.fold_numeric_for(node),
+        Statement::GenericFor(node) => folder.fold_generic_for(node),
+        Statement::FunctionDeclaration(node) => folder.fold_function_declaration(node),
+        Statement::LocalFunction(node) => folder.fold_local_function(node),
+        Statement::Return(node) => folder.fold_return(node),
+        Statement::Break(node) => folder.fold_break(node),
+        Statement::Continue(node) => folder.fold_continue(node),
+        Statement::TypeDeclaration(node) => folder.fold_type_declaration(node),
+    }
+}
+
+pub fn fold_local_assign<F: Fold + ?Sized>(
+    folder: &mut F,
+    local_assign: LocalAssign,
+) -> LocalAssign {
+    let (bindings, values) = local_assign.into_parts();
+
+    let bindings = bindings
+        .into_iter()
+        .map(|binding| folder.fold_binding(binding))
+        .collect::<Vec<_>>();
+
+    let values = values
+        .into_iter()
+        .map(|value| folder.fold_expression(value))
+        .collect::<Vec<_>>();
+
+    LocalAssign::assign_many(&bindings, &values)
+}
+
+pub fn fold_assign<F: Fold + ?Sized>(folder: &mut F, assign: Assign) -> Assign {
+    let (targets, values) = assign.into_parts();
+
+    let targets = targets
+        .into_iter()
+        .map(|value| folder.fold_expression(value))
+        .collect();
+
+    let values = values
+        .into_iter()
+        .map(|value| folder.fold_expression(value))
+        .collect();
+
+    Assign::new(targets, values)
+}
+
+pub fn fold_compound_assign<F: Fold + ?Sized>(
+    folder: &mut F,
+    compound_assign: CompoundAssign,
+) -> CompoundAssign {
+    let (operator, target, value) = compound_assign.into_parts();
+
+    let target = folder.fold_expression(target);
+    let value = folder.fold_expression(value);
+
+    CompoundAssign::new(operator, target, value)
+}
+
+pub fn fold_do<F: Fold + ?Sized>(folder: &mut F, do_block: Do) -> Do {
+    Do::new(folder.fold_block(do_block.into_block()))
+}
+
+pub fn fold_while<F: Fold + ?Sized>(folder: &mut F, while_loop: While) -> While {
+    let (condition, block) = while_loop.into_parts();
+
+    let condition = folder.fold_expression(condition);
+    let block = folder.fold_block(block);
+
+    While::new(condition, block)
+}
+
+pub fn fold_repeat<F: Fold + ?Sized>(folder: &mut F, repeat_loop: Repeat) -> Repeat {
+    let (block, condition) = repeat_loop.into_parts();
+
+    let block = folder.fold_block(block);
+    let condition = folder.fold_expression(condition);
+
+    Repeat::new(block, condition)
+}
+
+pub fn fold_if<F: Fold + ?Sized>(folder: &mut F, if_statement: If) -> If {
+    let (condition, block, else_ifs, else_block) = if_statement.into_parts();
+
+    let condition = folder.fold_expression(condition);
+    let block = folder.fold_block(block);
+
+    let else_ifs = else_ifs
+        .into_iter()
+        .map(|else_if| folder.fold_else_if(else_if))
+        .collect();
+
+    let else_block = else_block.map(|block| folder.fold_block(block));
+
+    If::new(condition, block, else_ifs, else_block)
+}
+
+pub fn fold_else_if<F: Fold + ?Sized>(folder: &mut F, else_if: ElseIf) -> ElseIf {
+    let (condition, block) = else_if.into_parts();
+
+    let condition = folder.fold_expression(condition);
+    let block = folder.fold_block(block);
+
+    ElseIf::new(condition, block)
+}
+
+pub fn fold_numeric_for<F: Fold + ?Sized>(folder: &mut F, numeric_for: NumericFor) -> NumericFor {
+    let (binding, start, end, step, block) = numeric_for.into_parts();
+
+    let binding = folder.fold_binding(binding);
+    let start = folder.fold_expression(start);
+    let end = folder.fold_expression(end);
+    let step = step.map(|step| folder.fold_expression(step));
+    let block = folder.fold_block(block);
+
+    NumericFor::new(binding, start, end, step, block)
+}
+
+pub fn fold_generic_for<F: Fold + ?Sized>(folder: &mut F, generic_for: GenericFor) -> GenericFor {
+    let (bindings, values, block) = generic_for.into_parts();
+
+    let bindings = bindings
+        .into_iter()
+        .map(|binding| folder.fold_binding(binding))
+        .collect();
+
+    let values = values
+        .into_iter()
+        .map(|value| folder.fold_expression(value))
+        .collect();
+
+    let block = folder.fold_block(block);
+
+    GenericFor::new(bindings, values, block)
+}
+
+pub fn fold_function_declaration<F: Fold + ?Sized>(
+    folder: &mut F,
+    function_declaration: FunctionDeclaration,
+) -> FunctionDeclaration {
+    let (name, function) = function_declaration.into_parts();
+
+    FunctionDeclaration::new(name, folder.fold_function(function))
+}
+
+pub fn fold_local_function<F: Fold + ?Sized>(
+    folder: &mut F,
+    local_function: LocalFunction,
+) -> LocalFunction {
+    let (name, function) = local_function.into_parts();
+
+    LocalFunction::new(name, folder.fold_function(function))
+}
+
+pub fn fold_return<F: Fold + ?Sized>(folder: &mut F, return_statement: Return) -> Return {
+    let (values, trivia) = return_statement.into_parts();
+
+    let values = values
+        .into_iter()
+        .map(|value| folder.fold_expression(value))
+        .collect();
+
+    Return::with_comments(values, trivia)
+}
+
+pub fn fold_type_declaration<F: Fold + ?Sized>(
+    folder: &mut F,
+    type_declaration: TypeDeclaration,
+) -> TypeDeclaration {
+    let (exported, name, generics, annotation) = type_declaration.into_parts();
+
+    let generics = generics
+        .into_iter()
+        .map(|generic| folder.fold_type_generic_parameter(generic))
+        .collect();
+
+    let annotation = folder.fold_type_annotation(annotation);
+
+    match exported {
+        true => TypeDeclaration::export(name, generics, annotation),
+        false => TypeDeclaration::new(name, generics, annotation),
+    }
+}
+
+// Expressions
+
+pub fn fold_expression<F: Fold + ?Sized>(folder: &mut F, expression: Expression) -> Expression {
+    match expression {
+        Expression::NilLiteral(node) => folder.fold_nil_literal(node),
+        Expression::BooleanLiteral(node) => folder.fold_boolean_literal(node),
+        Expression::StringLiteral(node) => folder.fold_string_literal(node),
+        Expression::NumberLiteral(node) => folder.fold_number_literal(node),
+        Expression::VarargLiteral(node) => folder.fold_vararg_literal(node),
+        Expression::InterpolatedString(node) => folder.fold_interpolated_string(node),
+
+        Expression::Identifier(node) => folder.fold_identifier(node),
+        Expression::Index(node) => folder.fold_index(node),
+        Expression::Function(node) => folder.fold_function(node).into(),
+        Expression::TableConstructor(node) => folder.fold_table_constructor(node),
+        Expression::FunctionCall(node) => folder.fold_function_call(node).into(),
+
+        Expression::UnaryOperation(node) => folder.fold_unary_operation(node),
+        Expression::BinaryOperation(node) => folder.fold_binary_operation(node),
+        Expression::Parenthesized(node) => folder.fold_parenthesized(node),
+        Expression::IfExpression(node) => folder.fold_if_expression(node),
+        Expression::TypeAssertion(node) => folder.fold_type_assertion(node),
+    }
+}
+
+pub fn fold_interpolated_string<F: Fold + ?Sized>(
+    folder: &mut F,
+    string: InterpolatedString,
+) -> InterpolatedString {
+    let (segments, expressions) = string.into_parts();
+
+    let expressions = expressions
+        .into_iter()
+        .map(|expression| folder.fold_expression(expression))
+        .collect();
+
+    InterpolatedString::new(segments, expressions)
+}
+
+pub fn fold_index<F: Fold + ?Sized>(folder: &mut F, index: Index) -> Index {
+    let (object, key) = index.into_parts();
+
+    let object = folder.fold_expression(object);
+
+    let key = match key {
+        IndexKey::Field(name) => IndexKey::Field(name),
+        IndexKey::Computed(key) => IndexKey::Computed(Box::new(folder.fold_expression(*key))),
+    };
+
+    Index::new(object, key)
+}
+
+pub fn fold_function_call<F: Fold + ?Sized>(folder: &mut F, call: FunctionCall) -> FunctionCall {
+    let (function, method, arguments, trivia, dangling_comments) = call.into_parts();
+
+    let function = folder.fold_expression(function);
+
+    let arguments = arguments
+        .into_iter()
+        .map(|value| folder.fold_expression(value))
+        .collect();
+
+    FunctionCall::with_comments(function, method, arguments, trivia, dangling_comments)
+}
+
+pub fn fold_function<F: Fold + ?Sized>(folder: &mut F, function: Function) -> Function {
+    let (generics, parameters, variadic, returns, block, parameter_trivia, parameter_comments) =
+        function.into_parts();
+
+    let generics = generics
+        .into_iter()
+        .map(|generic| folder.fold_type_generic_parameter(generic))
+        .collect();
+
+    let parameters = parameters
+        .into_iter()
+        .map(|parameter| folder.fold_binding(parameter))
+        .collect();
+
+    let variadic = variadic.map(|variadic| folder.fold_function_variadic(variadic));
+    let returns = returns.map(|returns| folder.fold_type_pack(returns));
+    let block = folder.fold_block(block);
+
+    Function::with_comments(
+        generics,
+        parameters,
+        variadic,
+        returns,
+        block,
+        parameter_trivia,
+        parameter_comments,
+    )
+}
+
+pub fn fold_function_variadic<F: Fold + ?Sized>(
+    folder: &mut F,
+    variadic: FunctionVariadic,
+) -> FunctionVariadic {
+    let annotation = variadic
+        .into_annotation()
+        .map(|annotation| folder.fold_type_pack_tail(annotation));
+
+    FunctionVariadic::new(annotation)
+}
+
+pub fn fold_table_constructor<F: Fold + ?Sized>(
+    folder: &mut F,
+    table: TableConstructor,
+) -> TableConstructor {
+    let (fields, trivia, dangling_comments) = table.into_parts();
+
+    let fields = fields
+        .into_iter()
+        .map(|field| folder.fold_table_field(field))
+        .collect();
+
+    TableConstructor::with_comments(fields, trivia, dangling_comments)
+}
+
+pub fn fold_table_field<F: Fold + ?Sized>(folder: &mut F, field: TableField) -> TableField {
+    match field {
+        TableField::Named(name, value) => TableField::Named(name, folder.fold_expression(value)),
+        TableField::Keyed(key, value) => {
+            let key = folder.fold_expression(key);
+            let value = folder.fold_expression(value);
+
+            TableField::Keyed(key, value)
+        }
+        TableField::Positional(value) => TableField::Positional(folder.fold_expression(value)),
+    }
+}
+
+pub fn fold_parenthesized<F: Fold + ?Sized>(
+    folder: &mut F,
+    parenthesized: Parenthesized,
+) -> Parenthesized {
+    Parenthesized::new(folder.fold_expression(parenthesized.into_expression()))
+}
+
+pub fn fold_if_expression<F: Fold + ?Sized>(
+    folder: &mut F,
+    if_expression: IfExpression,
+) -> IfExpression {
+    let (condition, consequent, else_ifs, alternative) = if_expression.into_parts();
+
+    let condition = folder.fold_expression(condition);
+    let consequent = folder.fold_expression(consequent);
+
+    let else_ifs = else_ifs
+        .into_iter()
+        .map(|(condition, consequent)| {
+            let condition = folder.fold_expression(condition);
+            let consequent = folder.fold_expression(consequent);
+
+            (condition, consequent)
+        })
+        .collect();
+
+    let alternative = folder.fold_expression(alternative);
+
+    IfExpression::new(condition, consequent, else_ifs, alternative)
+}
+
+pub fn fold_type_assertion<F: Fold + ?Sized>(
+    folder: &mut F,
+    assertion: TypeAssertion,
+) -> TypeAssertion {
+    let (expression, annotation) = assertion.into_parts();
+
+    let expression = folder.fold_expression(expression);
+    let annotation = folder.fold_type_annotation(annotation);
+
+    TypeAssertion::new(expression, annotation)
+}
+
+pub fn fold_unary_operation<F: Fold + ?Sized>(
+    folder: &mut F,
+    operation: UnaryOperation,
+) -> UnaryOperation {
+    let (operator, operand) = operation.into_parts();
+
+    UnaryOperation::new(operator, folder.fold_expression(operand))
+}
+
+pub fn fold_binary_operation<F: Fold + ?Sized>(
+    folder: &mut F,
+    operation: BinaryOperation,
+) -> BinaryOperation {
+    let (operator, left, right) = operation.into_parts();
+
+    let left = folder.fold_expression(left);
+    let right = folder.fold_expression(right);
+
+    BinaryOperation::new(operator, left, right)
+}
+
+// Constructs
+
+pub fn fold_block<F: Fold + ?Sized>(folder: &mut F, block: Block) -> Block {
+    let (statements, trivia, dangling_comments) = block.into_parts();
+
+    let statements = statements
+        .into_iter()
+        .map(|statement| folder.fold_statement(statement))
+        .collect();
+
+    Block::with_comments(statements, trivia, dangling_comments)
+}
+
+pub fn fold_binding<F: Fold + ?Sized>(folder: &mut F, binding: Binding) -> Binding {
+    Binding {
+        name: binding.name,
+        annotation: binding
+            .annotation
+            .map(|annotation| folder.fold_type_annotation(annotation)),
+    }
+}
+
+// Type annotations
+
+pub fn fold_type_annotation<F: Fold + ?Sized>(
+    folder: &mut F,
+    annotation: TypeAnnotation,
+) -> TypeAnnotation {
+    match annotation {
+        TypeAnnotation::BuiltIn(node) => folder.fold_type_built_in(node),
+        TypeAnnotation::Primitive(node) => folder.fold_type_primitive(node),
+        TypeAnnotation::Reference(node) => folder.fold_type_reference(node),
+        TypeAnnotation::Function(node) => folder.fold_type_function(node),
+        TypeAnnotation::Table(node) => folder.fold_type_table(node),
+        TypeAnnotation::Array(node) => folder.fold_type_array(node),
+        TypeAnnotation::Singleton(node) => folder.fold_type_singleton(node),
+        TypeAnnotation::Optional(node) => folder.fold_type_optional(node),
+        TypeAnnotation::Combination(node) => folder.fold_type_combination(node),
+        TypeAnnotation::Typeof(node) => folder.fold_type_typeof(node),
+    }
+}
+
+pub fn fold_type_reference<F: Fold + ?Sized>(
+    folder: &mut F,
+    annotation: TypeReference,
+) -> TypeReference {
+    let (module, name, arguments) = annotation.into_parts();
+
+    let arguments = arguments
+        .into_iter()
+        .map(|argument| folder.fold_type_reference_argument(argument))
+        .collect();
+
+    TypeReference::new(module, name, arguments)
+}
+
+pub fn fold_type_reference_argument<F: Fold + ?Sized>(
+    folder: &mut F,
+    argument: TypeReferenceArgument,
+) -> TypeReferenceArgument {
+    match argument {
+        TypeReferenceArgument::Type(node) => folder.fold_type_annotation(node).into(),
+        TypeReferenceArgument::Pack(node) => folder.fold_type_pack(node).into(),
+    }
+}
+
+pub fn fold_type_function<F: Fold + ?Sized>(
+    folder: &mut F,
+    annotation: TypeFunction,
+) -> TypeFunction {
+    let (generics, arguments, variadic, result) = annotation.into_parts();
+
+    let generics = generics
+        .into_iter()
+        .map(|generic| folder.fold_type_generic_parameter(generic))
+        .collect();
+
+    let arguments = arguments
+        .into_iter()
+        .map(|argument| folder.fold_type_argument(argument))
+        .collect();
+
+    let variadic = variadic.map(|tail| folder.fold_type_pack_tail(tail));
+    let result = folder.fold_type_pack(result);
+
+    TypeFunction::new(generics, arguments, variadic, result)
+}
+
+pub fn fold_type_argument<F: Fold + ?Sized>(
+    folder: &mut F,
+    argument: TypeArgument,
+) -> TypeArgument {
+    let (name, val) = argument.into_parts();
+
+    TypeArgument::new(name, folder.fold_type_annotation(val))
+}
+
+pub fn fold_type_table<F: Fold + ?Sized>(folder: &mut F, annotation: TypeTable) -> TypeTable {
+    let (entries, indexer) = annotation.into_parts();
+
+    let entries = entries
+        .into_iter()
+        .map(|entry| folder.fold_type_table_entry(entry))
+        .collect();
+
+    let indexer = indexer.map(|indexer| folder.fold_type_table_indexer(indexer));
+
+    TypeTable::new(entries, indexer)
+}
+
+pub fn fold_type_table_entry<F: Fold + ?Sized>(
+    folder: &mut F,
+    entry: TypeTableEntry,
+) -> TypeTableEntry {
+    let (access, index, value) = entry.into_parts();
+
+    TypeTableEntry::with_access(access, index, folder.fold_type_annotation(value))
+}
+
+pub fn fold_type_table_indexer<F: Fold + ?Sized>(
+    folder: &mut F,
+    indexer: TypeTableIndexer,
+) -> TypeTableIndexer {
+    let (index, value) = indexer.into_parts();
+
+    let index = folder.fold_type_annotation(index);
+    let value = folder.fold_type_annotation(value);
+
+    TypeTableIndexer::new(index, value)
+}
+
+pub fn fold_type_array<F: Fold + ?Sized>(folder: &mut F, annotation: TypeArray) -> TypeArray {
+    let item = annotation
+        .into_item()
+        .map(|item| Box::new(folder.fold_type_annotation(*item)));
+
+    TypeArray::new(item)
+}
+
+pub fn fold_type_optional<F: Fold + ?Sized>(
+    folder: &mut F,
+    annotation: TypeOptional,
+) -> TypeOptional {
+    TypeOptional::new(folder.fold_type_annotation(annotation.into_annotation()))
+}
+
+pub fn fold_type_combination<F: Fold + ?Sized>(
+    folder: &mut F,
+    annotation: TypeCombination,
+) -> TypeCombination {
+    let (operator, left, right) = annotation.into_parts();
+
+    let left = folder.fold_type_annotation(left);
+    let right = folder.fold_type_annotation(right);
+
+    TypeCombination::new(operator, left, right)
+}
+
+pub fn fold_type_pack<F: Fold + ?Sized>(folder: &mut F, pack: TypePack) -> TypePack {
+    let (types, tail) = pack.into_parts();
+
+    let types = types
+        .into_iter()
+        .map(|annotation| folder.fold_type_annotation(annotation))
+        .collect();
+
+    let tail = tail.map(|tail| folder.fold_type_pack_tail(tail));
+
+    TypePack::new(types, tail)
+}
+
+pub fn fold_type_pack_tail<F: Fold + ?Sized>(folder: &mut F, tail: TypePackTail) -> TypePackTail {
+    match tail {
+        TypePackTail::Variadic(node) => TypePackTail::variadic(folder.fold_type_annotation(*node)),
+        TypePackTail::Generic(name) => TypePackTail::Generic(name),
+    }
+}
+
+pub fn fold_type_generic_parameter<F: Fold + ?Sized>(
+    folder: &mut F,
+    parameter: TypeGenericParameter,
+) -> TypeGenericParameter {
+    match parameter {
+        TypeGenericParameter::Type(node) => folder.fold_type_generic(node),
+        TypeGenericParameter::Pack(node) => folder.fold_type_generic_pack(node),
+    }
+}
+
+pub fn fold_type_generic<F: Fold + ?Sized>(folder: &mut F, generic: TypeGeneric) -> TypeGeneric {
+    let (name, default) = generic.into_parts();
+
+    TypeGeneric::new(
+        name,
+        default.map(|default| folder.fold_type_annotation(default)),
+    )
+}
+
+pub fn fold_type_generic_pack<F: Fold + ?Sized>(
+    folder: &mut F,
+    generic: TypeGenericPack,
+) -> TypeGenericPack {
+    let (name, default) = generic.into_parts();
+
+    TypeGenericPack::new(name, default.map(|default| folder.fold_type_pack(default)))
+}
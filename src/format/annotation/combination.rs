@@ -1,12 +1,84 @@
-use crate::ast::TypeCombination;
-use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+use crate::ast::{TypeAnnotation, TypeCombination, TypeOperator};
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
 
 impl SourceFormatItem for TypeCombination {
+    /// Formats the combination on one line, or with each member after the first on its own line,
+    /// preceded by the operator, when it does not fit.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("type Event = \"Began\" | \"Changed\" | \"Ended\" | \"Cancelled\"").unwrap();
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     max_line_width: 40,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "type Event = \"Began\"\n    | \"Changed\"\n    | \"Ended\"\n    | \"Cancelled\""
+    /// );
+    /// ```
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
-        let operator = self.operator().text();
-        let left = self.left().format_string(settings);
-        let right = self.right().format_string(settings);
+        let mut members = Vec::new();
+        collect_members(self, settings, &mut members);
 
-        SourceItem::Text(format!("{} {} {}", left, operator, right))
+        let mut members = members.into_iter();
+        let first = members.next().unwrap_or_else(|| Document::text(""));
+
+        let rest = members
+            .map(|member| {
+                Document::concat(vec![
+                    Document::line(),
+                    Document::text(format!("{} ", self.operator().text())),
+                    member,
+                ])
+            })
+            .collect();
+
+        SourceItem::document(Document::group(Document::concat(vec![
+            first,
+            Document::indent(Document::concat(rest)),
+        ])))
+    }
+}
+
+/// Collects the members of a chain of combinations of the same operator, such as `A | B | C`, so
+/// that they break together.
+fn collect_members(
+    combination: &TypeCombination,
+    settings: &SourceFormatSettings,
+    members: &mut Vec<Document>,
+) {
+    for member in [combination.left(), combination.right()] {
+        match member {
+            TypeAnnotation::Combination(inner) if inner.operator() == combination.operator() => {
+                collect_members(inner, settings, members)
+            }
+            member => members.push(format_member(member, combination.operator(), settings)),
+        }
+    }
+}
+
+/// Formats a member of a combination, wrapping functions, whose return type would otherwise take
+/// the rest of the combination, and combinations of the other operator, which cannot be mixed.
+fn format_member(
+    member: &TypeAnnotation,
+    operator: TypeOperator,
+    settings: &SourceFormatSettings,
+) -> Document {
+    let wrapped = match member {
+        TypeAnnotation::Function(_) => true,
+        TypeAnnotation::Combination(combination) => combination.operator() != operator,
+        _ => false,
+    };
+
+    match wrapped {
+        true => Document::concat(vec![
+            Document::text("("),
+            member.document(settings),
+            Document::text(")"),
+        ]),
+        false => member.document(settings),
     }
 }
@@ -1,43 +1,53 @@
-use itertools::Itertools;
-
 use crate::ast::TypeArgument;
 use crate::ast::TypeFunction;
-use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+use crate::format::annotation::{format_generics, format_return_pack};
+use crate::format::{
+    format_arguments, Document, SourceFormatItem, SourceFormatSettings, SourceItem,
+};
 
 impl SourceFormatItem for TypeArgument {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
-        let value = self.val().format_string(settings);
-
-        let argument = match self.name() {
-            Some(name) => format!("{}: {}", name, value),
-            None => format!("{}", value),
-        };
+        let value = self.val().document(settings);
 
-        SourceItem::Text(argument)
+        SourceItem::document(match self.name() {
+            Some(name) => Document::concat(vec![Document::text(format!("{}: ", name)), value]),
+            None => value,
+        })
     }
 }
 
 impl SourceFormatItem for TypeFunction {
+    /// Formats the function type on one line, or with one argument per line when they do not fit.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("type F = (argumentOne: number, argumentTwo: string, argumentThree: boolean) -> string").unwrap();
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     max_line_width: 60,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "type F = (\n    argumentOne: number,\n    argumentTwo: string,\n    argumentThree: boolean\n) -> string"
+    /// );
+    /// ```
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let generics = format_generics(self.generics(), settings);
+
         let arguments = self
             .arguments()
             .iter()
-            .map(|argument| argument.format_string(settings))
-            .join(", ");
-
-        let result = self
-            .result()
-            .iter()
-            .map(|result| result.format_string(settings))
-            .join(", ");
+            .map(|argument| argument.document(settings))
+            .chain(self.variadic().map(|tail| tail.document(settings)));
 
-        // Note: this is intentionally wrapping zero arguments in parenthesis.
-        // Consider the following example: (number) -> ()
-        let result_has_parenthesis = self.result().len() != 1;
+        let result = format_return_pack(self.result(), settings);
 
-        match result_has_parenthesis {
-            false => SourceItem::text(format!("({}) -> {}", arguments, result)),
-            true => SourceItem::text(format!("({}) -> ({})", arguments, result)),
-        }
+        SourceItem::document(Document::concat(vec![
+            Document::text(generics),
+            format_arguments(arguments),
+            Document::text(format!(" -> {}", result)),
+        ]))
     }
 }
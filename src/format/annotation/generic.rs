@@ -0,0 +1,56 @@
+use itertools::Itertools;
+
+use crate::ast::{TypeGeneric, TypeGenericPack, TypeGenericParameter};
+use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for TypeGeneric {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self.default() {
+            Some(default) => SourceItem::Text(format!(
+                "{} = {}",
+                self.name(),
+                default.format_string(settings)
+            )),
+            None => SourceItem::text(self.name()),
+        }
+    }
+}
+
+impl SourceFormatItem for TypeGenericPack {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self.default() {
+            Some(default) => SourceItem::Text(format!(
+                "{}... = {}",
+                self.name(),
+                default.format_string(settings)
+            )),
+            None => SourceItem::Text(format!("{}...", self.name())),
+        }
+    }
+}
+
+impl SourceFormatItem for TypeGenericParameter {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self {
+            Self::Type(a) => a.format(settings),
+            Self::Pack(a) => a.format(settings),
+        }
+    }
+}
+
+/// Formats a list of [`TypeGenericParameter`]s in angle brackets, or nothing if the list is empty.
+pub(crate) fn format_generics(
+    generics: &[TypeGenericParameter],
+    settings: &SourceFormatSettings,
+) -> String {
+    if generics.is_empty() {
+        return String::new();
+    }
+
+    let parameters = generics
+        .iter()
+        .map(|generic| generic.format_string(settings))
+        .join(", ");
+
+    format!("<{}>", parameters)
+}
@@ -1,25 +1,37 @@
 mod built_in;
 mod combination;
 mod function;
+mod generic;
 mod optional;
+mod pack;
 mod primitive;
+mod reference;
 mod singleton;
 mod table;
 
+pub(crate) use generic::format_generics;
+pub(crate) use pack::format_return_pack;
+
 use crate::ast::TypeAnnotation;
-use crate::format::{SourceFormatItem, SourceFormatSettings, SourceItem};
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
 
 impl SourceFormatItem for TypeAnnotation {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
         match self {
             Self::BuiltIn(a) => a.format(settings),
             Self::Primitive(a) => a.format(settings),
+            Self::Reference(a) => a.format(settings),
             Self::Function(a) => a.format(settings),
             Self::Table(a) => a.format(settings),
             Self::Array(a) => a.format(settings),
             Self::Singleton(a) => a.format(settings),
             Self::Optional(a) => a.format(settings),
             Self::Combination(a) => a.format(settings),
+            Self::Typeof(a) => SourceItem::document(Document::concat(vec![
+                Document::text("typeof("),
+                a.document(settings),
+                Document::text(")"),
+            ])),
         }
     }
 }
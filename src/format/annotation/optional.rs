@@ -1,10 +1,15 @@
-use crate::ast::TypeOptional;
+use crate::ast::{TypeAnnotation, TypeOptional};
 use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
 
 impl SourceFormatItem for TypeOptional {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
         let value = self.annotation().format_string(settings);
 
-        SourceItem::Text(format!("{}?", value))
+        match self.annotation() {
+            TypeAnnotation::Function(_) | TypeAnnotation::Combination(_) => {
+                SourceItem::Text(format!("({})?", value))
+            }
+            _ => SourceItem::Text(format!("{}?", value)),
+        }
     }
 }
@@ -0,0 +1,46 @@
+use itertools::Itertools;
+
+use crate::ast::{TypePack, TypePackTail};
+use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for TypePackTail {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self {
+            Self::Variadic(annotation) => {
+                SourceItem::Text(format!("...{}", annotation.format_string(settings)))
+            }
+            Self::Generic(name) => SourceItem::Text(format!("{}...", name)),
+        }
+    }
+}
+
+impl SourceFormatItem for TypePack {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let tail = self.pack_tail().map(|tail| tail.format_string(settings));
+
+        // A lone tail, such as `T...`, does not need to be wrapped in parenthesis.
+        if self.is_bare_tail() {
+            return SourceItem::text(tail.unwrap_or_default());
+        }
+
+        let content = self
+            .types()
+            .iter()
+            .map(|annotation| annotation.format_string(settings))
+            .chain(tail)
+            .join(", ");
+
+        SourceItem::Text(format!("({})", content))
+    }
+}
+
+/// Formats a [`TypePack`] in a return position, such as in `() -> number` or `function(): (number, string)`.
+pub(crate) fn format_return_pack(pack: &TypePack, settings: &SourceFormatSettings) -> String {
+    // Note: this is intentionally wrapping zero results in parenthesis.
+    // Consider the following example: (number) -> ()
+    // A single result without a tail is the only case that can be written without them.
+    match (pack.types().as_slice(), pack.pack_tail()) {
+        ([single], None) => single.format_string(settings),
+        _ => pack.format_string(settings),
+    }
+}
@@ -0,0 +1,51 @@
+use itertools::Itertools;
+
+use crate::ast::{TypeReference, TypeReferenceArgument};
+use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for TypeReferenceArgument {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self {
+            Self::Type(a) => a.format(settings),
+            Self::Pack(a) => a.format(settings),
+        }
+    }
+}
+
+impl SourceFormatItem for TypeReference {
+    /// Formats the reference as `Module.Name<A, B>`.
+    /// ```
+    /// use luna::ast::{TypePrimitive, TypeReference, TypeReferenceArgument};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let map = TypeReference::generic(
+    ///     "Map",
+    ///     vec![
+    ///         TypeReferenceArgument::annotation(TypePrimitive::String),
+    ///         TypeReferenceArgument::annotation(TypePrimitive::Number),
+    ///     ],
+    /// );
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(map.format_string(&settings), "Map<string, number>");
+    /// assert_eq!(TypeReference::qualified("Module", "Foo").format_string(&settings), "Module.Foo");
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let name = match self.module() {
+            Some(module) => format!("{}.{}", module, self.name()),
+            None => self.name().to_string(),
+        };
+
+        if self.arguments().is_empty() {
+            return SourceItem::Text(name);
+        }
+
+        let arguments = self
+            .arguments()
+            .iter()
+            .map(|argument| argument.format_string(settings))
+            .join(", ");
+
+        SourceItem::Text(format!("{}<{}>", name, arguments))
+    }
+}
@@ -1,10 +1,12 @@
-use itertools::Itertools;
-
 use crate::ast::TypeArray;
 use crate::ast::TypeTable;
+use crate::ast::TypeTableAccess;
 use crate::ast::TypeTableEntry;
 use crate::ast::TypeTableIndexer;
-use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+use crate::format::{
+    format_delimited, Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem,
+};
+use crate::lexer::is_identifier;
 
 impl SourceFormatItem for TypeTableIndexer {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
@@ -15,30 +17,98 @@ impl SourceFormatItem for TypeTableIndexer {
     }
 }
 
+/// Wraps the given text in double quotes, escaping it where necessary.
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\0' => quoted.push_str("\\0"),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
 impl SourceFormatItem for TypeTableEntry {
+    /// Formats the entry as `name: value`, using `["name"]: value` when the name is not a valid identifier.
+    /// ```
+    /// use luna::ast::{TypeAnnotation, TypePrimitive, TypeTable, TypeTableEntry};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let table = TypeTable::new(
+    ///     vec![
+    ///         TypeTableEntry::read("name".to_string(), TypePrimitive::String.into()),
+    ///         TypeTableEntry::new("my-key".to_string(), TypePrimitive::Number.into()),
+    ///     ],
+    ///     None,
+    /// );
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(table.format_string(&settings), r#"{ read name: string, ["my-key"]: number }"#);
+    /// ```
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
-        let index = self.index();
-        let value = self.value().format_string(settings);
+        let index = match is_identifier(self.index()) {
+            true => self.index().to_string(),
+            false => format!("[{}]", quote(self.index())),
+        };
+
+        let key = match self.access() {
+            TypeTableAccess::ReadWrite => format!("{}: ", index),
+            access => format!("{} {}: ", access.text(), index),
+        };
 
-        SourceItem::Text(format!("{}: {}", index, value))
+        SourceItem::document(Document::concat(vec![
+            Document::text(key),
+            self.value().document(settings),
+        ]))
     }
 }
 
 impl SourceFormatItem for TypeTable {
+    /// Formats the table type on one line, or with one entry per line when it does not fit, with
+    /// the separators the settings describe.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings, TableSeparator, TrailingSeparator};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("type Point = { x: number, y: number }").unwrap();
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     table_separator: TableSeparator::Semicolon,
+    ///     trailing_separator: TrailingSeparator::Always,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "type Point = { x: number; y: number; }"
+    /// );
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     max_line_width: 20,
+    ///     trailing_separator: TrailingSeparator::Never,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "type Point = {\n    x: number,\n    y: number\n}"
+    /// );
+    /// ```
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
-        let mut entries = self
+        let entries = self
             .entries()
             .iter()
-            .map(|entry| entry.format_string(settings));
-
-        let indexer = self.indexer().as_ref().map(|a| a.format_string(settings));
-
-        let content = match indexer {
-            Some(indexer) => entries.chain(std::iter::once(indexer)).join(", "),
-            None => entries.join(", "),
-        };
+            .map(|entry| entry.document(settings))
+            .chain(self.indexer().as_ref().map(|a| a.document(settings)));
 
-        SourceItem::Text(format!("{{ {} }}", content))
+        SourceItem::document(format_delimited("{", entries, "}", settings))
     }
 }
 
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use regex::Regex;
+
+use crate::format::config::ConfigError;
+use crate::format::{IndentationMode, LineEnding, SourceFormatSettings};
+
+/// Returns the properties the `.editorconfig` files in the directories above a file set for it,
+/// where the files nearer to it take precedence, up to the first marked as `root`.
+pub(super) fn properties(path: &Path) -> Result<HashMap<String, String>, ConfigError> {
+    let mut files = Vec::new();
+
+    for directory in path.ancestors().skip(1) {
+        let file = directory.join(".editorconfig");
+
+        if !file.is_file() {
+            continue;
+        }
+
+        let source = fs::read_to_string(&file)
+            .map_err(|error| ConfigError::new(error.to_string()).in_file(&file))?;
+
+        let sections = parse(&source);
+        let root = sections
+            .first()
+            .filter(|section| section.glob.is_none())
+            .and_then(|preamble| preamble.properties.get("root"))
+            .is_some_and(|root| root == "true");
+
+        files.push((directory, sections));
+
+        if root {
+            break;
+        }
+    }
+
+    let mut properties = HashMap::new();
+
+    for (directory, sections) in files.into_iter().rev() {
+        let Ok(relative) = path.strip_prefix(directory) else {
+            continue;
+        };
+
+        let relative = relative.to_string_lossy().replace('\\', "/");
+
+        for section in sections {
+            if section.glob.is_some_and(|glob| matches(&glob, &relative)) {
+                properties.extend(section.properties);
+            }
+        }
+    }
+
+    Ok(properties)
+}
+
+impl SourceFormatSettings {
+    /// Returns these settings, overridden by the indentation, line ending and maximum line length
+    /// of the given `.editorconfig` properties.
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use luna::format::{IndentationMode, LineEnding, SourceFormatSettings};
+    ///
+    /// let properties = HashMap::from([
+    ///     ("indent_style".to_string(), "space".to_string()),
+    ///     ("indent_size".to_string(), "2".to_string()),
+    ///     ("end_of_line".to_string(), "crlf".to_string()),
+    /// ]);
+    ///
+    /// let settings = SourceFormatSettings::default().with_editorconfig(&properties);
+    ///
+    /// assert!(matches!(settings.indentation, IndentationMode::Spaces(2)));
+    /// assert_eq!(settings.line_ending, LineEnding::CrLf);
+    /// ```
+    pub fn with_editorconfig(mut self, properties: &HashMap<String, String>) -> Self {
+        let size = properties
+            .get("indent_size")
+            .and_then(|size| size.parse::<usize>().ok());
+
+        self.indentation = match (properties.get("indent_style"), size) {
+            (Some(style), _) if style == "tab" => IndentationMode::Tabs(1),
+            (Some(style), size) if style == "space" => IndentationMode::Spaces(size.unwrap_or(4)),
+            (_, Some(size)) if matches!(self.indentation, IndentationMode::Spaces(_)) => {
+                IndentationMode::Spaces(size)
+            }
+            _ => self.indentation,
+        };
+
+        match properties.get("end_of_line").map(String::as_str) {
+            Some("lf") => self.line_ending = LineEnding::Lf,
+            Some("crlf") => self.line_ending = LineEnding::CrLf,
+            _ => {}
+        }
+
+        match properties.get("max_line_length").map(String::as_str) {
+            Some("off") => self.max_line_width = usize::MAX,
+            Some(length) => {
+                if let Ok(length) = length.parse() {
+                    self.max_line_width = length;
+                }
+            }
+            None => {}
+        }
+
+        self
+    }
+}
+
+/// A section of an `.editorconfig` file, where the properties before the first section have no
+/// glob.
+struct Section {
+    glob: Option<String>,
+    properties: HashMap<String, String>,
+}
+
+fn parse(source: &str) -> Vec<Section> {
+    let mut sections = vec![Section {
+        glob: None,
+        properties: HashMap::new(),
+    }];
+
+    for line in source.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(glob) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            sections.push(Section {
+                glob: Some(glob.to_string()),
+                properties: HashMap::new(),
+            });
+        } else if let Some((key, value)) = line.split_once('=') {
+            // Keys and values are case-insensitive.
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+
+            if let Some(section) = sections.last_mut() {
+                section.properties.insert(key, value);
+            }
+        }
+    }
+
+    sections
+}
+
+/// Returns whether a glob of an `.editorconfig` section matches a path relative to it. A glob
+/// without a `/` matches files of any directory.
+fn matches(glob: &str, path: &str) -> bool {
+    let pattern = match glob.strip_prefix('/') {
+        Some(glob) => format!("^{}$", translate(glob)),
+        None if glob.contains('/') => format!("^{}$", translate(glob)),
+        None => format!("^(?:.*/)?{}$", translate(glob)),
+    };
+
+    Regex::new(&pattern).is_ok_and(|regex| regex.is_match(path))
+}
+
+/// Translates a glob into a regular expression.
+fn translate(glob: &str) -> String {
+    let mut pattern = String::new();
+    let mut characters = glob.chars().peekable();
+    let mut braces = 0;
+
+    while let Some(character) = characters.next() {
+        match character {
+            '*' if characters.peek() == Some(&'*') => {
+                characters.next();
+                pattern.push_str(".*");
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            '{' => {
+                braces += 1;
+                pattern.push_str("(?:");
+            }
+            '}' if braces > 0 => {
+                braces -= 1;
+                pattern.push(')');
+            }
+            ',' if braces > 0 => pattern.push('|'),
+            '[' => {
+                pattern.push('[');
+
+                if characters.peek() == Some(&'!') {
+                    characters.next();
+                    pattern.push('^');
+                }
+
+                for character in characters.by_ref() {
+                    match character {
+                        ']' => break,
+                        '\\' | '[' => pattern.push_str(&regex::escape(&character.to_string())),
+                        character => pattern.push(character),
+                    }
+                }
+
+                pattern.push(']');
+            }
+            '\\' => {
+                if let Some(escaped) = characters.next() {
+                    pattern.push_str(&regex::escape(&escaped.to_string()));
+                }
+            }
+            character => pattern.push_str(&regex::escape(&character.to_string())),
+        }
+    }
+
+    pattern
+}
@@ -0,0 +1,347 @@
+//! Loading of [`SourceFormatSettings`] from configuration files.
+//!
+//! The settings of a file come from the nearest `luna.toml` in its directory or the directories
+//! above it, whose `[format]` table sets any of the settings. Whatever it leaves unset falls back
+//! to the `.editorconfig` files applying to the file, and then to the defaults.
+//! ```toml
+//! [format]
+//! indentation = "tabs"
+//! indent_width = 1
+//! max_line_width = 100
+//! max_blank_lines = 1
+//! line_ending = "lf"
+//! quote_style = "prefer_double"
+//! trailing_separator = "multiline"
+//! table_separator = "comma"
+//! call_parentheses = "always"
+//!
+//! [format.operator_spacing]
+//! concat = false
+//!
+//! [format.number]
+//! hex_digits = "upper"
+//! exponent = "lower"
+//! digit_separators = "insert"
+//! leading_zero = "insert"
+//! ```
+mod editorconfig;
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::format::{
+    CallParentheses, DigitSeparators, IndentationMode, LeadingZero, LetterCase, LineEnding,
+    QuoteStyle, SourceFormatSettings, TableSeparator, TrailingSeparator,
+};
+
+/// The name of the configuration file.
+pub const CONFIG_FILE: &str = "luna.toml";
+
+/// An error reading or interpreting a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    path: Option<PathBuf>,
+    message: String,
+}
+
+impl ConfigError {
+    /// Constructs a new [`ConfigError`] with the given message, not tied to a file.
+    pub fn new<T: AsRef<str>>(message: T) -> Self {
+        Self {
+            path: None,
+            message: message.as_ref().to_string(),
+        }
+    }
+
+    /// Returns this [`ConfigError`], as having occurred in the file at the given path.
+    pub fn in_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Returns the path of the file this [`ConfigError`] occurred in, if it is known.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the message of this [`ConfigError`].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}: {}", path.display(), self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Returns the path of the nearest `luna.toml` in the directory of the given file or the
+/// directories above it.
+pub fn find_config<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+    let path = std::path::absolute(path.as_ref()).ok()?;
+
+    path.ancestors()
+        .skip(1)
+        .map(|directory| directory.join(CONFIG_FILE))
+        .find(|config| config.is_file())
+}
+
+impl SourceFormatSettings {
+    /// Discovers the settings to format the file at the given path with, from the nearest
+    /// `luna.toml` and then the `.editorconfig` files applying to it.
+    /// ```
+    /// use std::fs;
+    /// use std::time::{SystemTime, UNIX_EPOCH};
+    ///
+    /// use luna::format::{IndentationMode, LineEnding, SourceFormatSettings};
+    ///
+    /// let time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    /// let project = std::env::temp_dir().join(format!(
+    ///     "luna-discover-{}-{}",
+    ///     std::process::id(),
+    ///     time.as_nanos()
+    /// ));
+    /// fs::create_dir_all(project.join("src")).unwrap();
+    /// fs::write(project.join(".editorconfig"), "root = true\n\n[*.luau]\nindent_style = tab\nend_of_line = crlf\n").unwrap();
+    /// fs::write(project.join("luna.toml"), "[format]\nmax_line_width = 80\n").unwrap();
+    ///
+    /// let settings = SourceFormatSettings::discover(project.join("src/main.luau")).unwrap();
+    ///
+    /// assert!(matches!(settings.indentation, IndentationMode::Tabs(1)));
+    /// assert_eq!(settings.line_ending, LineEnding::CrLf);
+    /// assert_eq!(settings.max_line_width, 80);
+    ///
+    /// fs::remove_dir_all(project).unwrap();
+    /// ```
+    pub fn discover<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = std::path::absolute(path.as_ref())
+            .map_err(|error| ConfigError::new(error.to_string()).in_file(path.as_ref()))?;
+
+        let settings = Self::default().with_editorconfig(&editorconfig::properties(&path)?);
+
+        match find_config(&path) {
+            Some(config) => {
+                let source = fs::read_to_string(&config)
+                    .map_err(|error| ConfigError::new(error.to_string()).in_file(&config))?;
+
+                settings
+                    .with_config(&source)
+                    .map_err(|error| error.in_file(&config))
+            }
+            None => Ok(settings),
+        }
+    }
+
+    /// Returns these settings, overridden by those the `[format]` table of the given `luna.toml`
+    /// source sets.
+    /// ```
+    /// use luna::format::{IndentationMode, SourceFormatSettings};
+    ///
+    /// let settings = SourceFormatSettings::default()
+    ///     .with_config("[format]\nindentation = \"tabs\"\nmax_line_width = 80\n\n[format.operator_spacing]\nconcat = false")
+    ///     .unwrap();
+    ///
+    /// assert!(matches!(settings.indentation, IndentationMode::Tabs(1)));
+    /// assert_eq!(settings.max_line_width, 80);
+    /// assert!(!settings.operator_spacing.concat);
+    ///
+    /// let error = SourceFormatSettings::default().with_config("[format]\nmax_line_widht = 80");
+    /// assert_eq!(error.err().unwrap().message(), "unknown setting `format.max_line_widht`");
+    /// ```
+    pub fn with_config(mut self, source: &str) -> Result<Self, ConfigError> {
+        let config = source
+            .parse::<Table>()
+            .map_err(|error| ConfigError::new(error.message()))?;
+
+        let format = match config.get("format") {
+            Some(Value::Table(format)) => format,
+            Some(_) => return Err(ConfigError::new("`format` must be a table")),
+            None => return Ok(self),
+        };
+
+        let mut indentation = None;
+        let mut indent_width = None;
+
+        for (key, value) in format {
+            match key.as_str() {
+                "indentation" => indentation = Some(string(key, value)?),
+                "indent_width" => indent_width = Some(integer(key, value)?),
+                "max_line_width" => self.max_line_width = integer(key, value)?,
+                "max_blank_lines" => self.max_blank_lines = integer(key, value)?,
+                "line_ending" => {
+                    self.line_ending = match string(key, value)? {
+                        "lf" => LineEnding::Lf,
+                        "crlf" => LineEnding::CrLf,
+                        _ => return Err(invalid(key, "`lf` or `crlf`")),
+                    }
+                }
+                "quote_style" => {
+                    self.quote_style = match string(key, value)? {
+                        "prefer_double" => QuoteStyle::PreferDouble,
+                        "prefer_single" => QuoteStyle::PreferSingle,
+                        "force_double" => QuoteStyle::ForceDouble,
+                        "force_single" => QuoteStyle::ForceSingle,
+                        "keep" => QuoteStyle::Keep,
+                        _ => {
+                            return Err(invalid(
+                                key,
+                                "`prefer_double`, `prefer_single`, `force_double`, `force_single` or `keep`",
+                            ))
+                        }
+                    }
+                }
+                "trailing_separator" => {
+                    self.trailing_separator = match string(key, value)? {
+                        "always" => TrailingSeparator::Always,
+                        "never" => TrailingSeparator::Never,
+                        "multiline" => TrailingSeparator::Multiline,
+                        _ => return Err(invalid(key, "`always`, `never` or `multiline`")),
+                    }
+                }
+                "table_separator" => {
+                    self.table_separator = match string(key, value)? {
+                        "comma" => TableSeparator::Comma,
+                        "semicolon" => TableSeparator::Semicolon,
+                        _ => return Err(invalid(key, "`comma` or `semicolon`")),
+                    }
+                }
+                "call_parentheses" => {
+                    self.call_parentheses = match string(key, value)? {
+                        "always" => CallParentheses::Always,
+                        "omit" => CallParentheses::Omit,
+                        "omit_string" => CallParentheses::OmitString,
+                        "omit_table" => CallParentheses::OmitTable,
+                        _ => {
+                            return Err(invalid(
+                                key,
+                                "`always`, `omit`, `omit_string` or `omit_table`",
+                            ))
+                        }
+                    }
+                }
+                "number" => {
+                    let Value::Table(number) = value else {
+                        return Err(invalid(key, "a table"));
+                    };
+
+                    for (setting, value) in number {
+                        let key = format!("number.{}", setting);
+                        let format = &mut self.number_format;
+
+                        match setting.as_str() {
+                            "hex_digits" => format.hex_digits = letter_case(&key, value)?,
+                            "exponent" => format.exponent = letter_case(&key, value)?,
+                            "digit_separators" => {
+                                format.digit_separators = match string(&key, value)? {
+                                    "keep" => DigitSeparators::Keep,
+                                    "remove" => DigitSeparators::Remove,
+                                    "insert" => DigitSeparators::Insert,
+                                    _ => return Err(invalid(&key, "`keep`, `remove` or `insert`")),
+                                }
+                            }
+                            "leading_zero" => {
+                                format.leading_zero = match string(&key, value)? {
+                                    "keep" => LeadingZero::Keep,
+                                    "insert" => LeadingZero::Insert,
+                                    "remove" => LeadingZero::Remove,
+                                    _ => return Err(invalid(&key, "`keep`, `insert` or `remove`")),
+                                }
+                            }
+                            _ => return Err(unknown(&key)),
+                        }
+                    }
+                }
+                "operator_spacing" => {
+                    let Value::Table(spacing) = value else {
+                        return Err(invalid(key, "a table"));
+                    };
+
+                    for (operator, value) in spacing {
+                        let key = format!("operator_spacing.{}", operator);
+
+                        match self.operator_spacing.get_mut(operator) {
+                            Some(spacing) => *spacing = boolean(&key, value)?,
+                            None => return Err(unknown(&key)),
+                        }
+                    }
+                }
+                _ => return Err(unknown(key)),
+            }
+        }
+
+        self.indentation = indentation_mode(&self.indentation, indentation, indent_width)?;
+        Ok(self)
+    }
+}
+
+/// Returns the indentation with the given style and width, where either of them that is not set
+/// is kept from the current indentation.
+fn indentation_mode(
+    current: &IndentationMode,
+    style: Option<&str>,
+    width: Option<usize>,
+) -> Result<IndentationMode, ConfigError> {
+    let (current_style, current_width) = match current {
+        IndentationMode::None => ("none", 0),
+        IndentationMode::Spaces(spaces) => ("spaces", *spaces),
+        IndentationMode::Tabs(tabs) => ("tabs", *tabs),
+    };
+
+    let width = match (style, width) {
+        (_, Some(width)) => width,
+        // A width of spaces is no width of tabs, so switching style resets it.
+        (Some(style), None) if style != current_style => match style {
+            "tabs" => 1,
+            _ => 4,
+        },
+        (_, None) => current_width,
+    };
+
+    match style.unwrap_or(current_style) {
+        "none" => Ok(IndentationMode::None),
+        "spaces" => Ok(IndentationMode::Spaces(width)),
+        "tabs" => Ok(IndentationMode::Tabs(width)),
+        _ => Err(invalid("indentation", "`spaces`, `tabs` or `none`")),
+    }
+}
+
+fn string<'v>(key: &str, value: &'v Value) -> Result<&'v str, ConfigError> {
+    value.as_str().ok_or_else(|| invalid(key, "a string"))
+}
+
+fn integer(key: &str, value: &Value) -> Result<usize, ConfigError> {
+    value
+        .as_integer()
+        .and_then(|integer| usize::try_from(integer).ok())
+        .ok_or_else(|| invalid(key, "a non-negative integer"))
+}
+
+fn letter_case(key: &str, value: &Value) -> Result<LetterCase, ConfigError> {
+    match string(key, value)? {
+        "keep" => Ok(LetterCase::Keep),
+        "lower" => Ok(LetterCase::Lower),
+        "upper" => Ok(LetterCase::Upper),
+        _ => Err(invalid(key, "`keep`, `lower` or `upper`")),
+    }
+}
+
+fn boolean(key: &str, value: &Value) -> Result<bool, ConfigError> {
+    value.as_bool().ok_or_else(|| invalid(key, "a boolean"))
+}
+
+fn invalid(key: &str, expected: &str) -> ConfigError {
+    ConfigError::new(format!("`format.{}` must be {}", key, expected))
+}
+
+fn unknown(key: &str) -> ConfigError {
+    ConfigError::new(format!("unknown setting `format.{}`", key))
+}
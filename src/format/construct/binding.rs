@@ -0,0 +1,15 @@
+use crate::ast::Binding;
+use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for Binding {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match &self.annotation {
+            Some(annotation) => SourceItem::Text(format!(
+                "{}: {}",
+                self.name,
+                annotation.format_string(settings)
+            )),
+            None => SourceItem::text(&self.name),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ast::{Block, Statement, Trivia};
+use crate::format::construct::format_trailing;
+use crate::format::expression::starts_with_parenthesis;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject};
+
+impl SourceFormatItem for Block {
+    /// Formats each statement on its own lines, along with the comments attached to it.
+    ///
+    /// Blank lines between statements are kept from the source, up to
+    /// [`max_blank_lines`](SourceFormatSettings::max_blank_lines) of them, and function
+    /// declarations are set apart from the statements around them by a blank line.
+    ///
+    /// A statement after a `-- luna: ignore` comment, between `-- luna: ignore-start` and
+    /// `-- luna: ignore-end` comments, or in a file with a `-- luna: ignore-file` comment, is kept
+    /// as written, along with the blank lines before it.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("-- The player's score.\nlocal score = 0 -- reset each round\nwhile true do -- forever\nend").unwrap();
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "-- The player's score.\nlocal score = 0 -- reset each round\nwhile true do\n    -- forever\nend"
+    /// );
+    ///
+    /// let chunk = parse("local a = 1\n\n\n\nlocal b = 2\n-- Adds one.\nlocal function increment(x)\n    return x + 1\nend\nprint(increment(a))").unwrap();
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "local a = 1\n\nlocal b = 2\n\n-- Adds one.\nlocal function increment(x)\n    return x + 1\nend\n\nprint(increment(a))"
+    /// );
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let blank_lines = |count: usize| count.min(settings.max_blank_lines);
+        let mut objects = Vec::new();
+
+        for (index, statement) in self.statements().iter().enumerate() {
+            let trivia = self.trivia().get(index);
+            let verbatim = trivia.and_then(Trivia::verbatim);
+
+            // A statement the source tells the formatter to ignore is kept as written.
+            let item = match verbatim {
+                Some(text) => SourceItem::document(Document::verbatim(text)),
+                None => statement.format(settings),
+            };
+
+            // A statement starting with a parenthesis would continue the one before it as a call,
+            // as in `f()\n(g)()`, so it is separated from it by a semicolon.
+            let item = match index > 0 && is_parenthesized(statement) {
+                true => {
+                    SourceItem::document(Document::concat(vec![Document::text(";"), item.into()]))
+                }
+                false => item,
+            };
+
+            let item = format_trailing(item, trivia, settings);
+            let leading = trivia
+                .map(|trivia| trivia.leading().as_slice())
+                .unwrap_or(&[]);
+            let before = trivia.map_or(0, |trivia| trivia.blank_lines());
+            let before = match verbatim {
+                Some(_) => before,
+                None => blank_lines(before),
+            };
+
+            // The leading comments of a statement stay with it, so the statement and its comments
+            // are set apart together.
+            let (item, before) = match leading.first() {
+                Some(first) => {
+                    let mut lines = leading
+                        .iter()
+                        .map(|comment| {
+                            SourceObject::line(comment.format(settings))
+                                .with_blank_lines(blank_lines(comment.blank_lines()))
+                        })
+                        .collect::<Vec<_>>();
+
+                    lines.push(SourceObject::line(item).with_blank_lines(before));
+                    (SourceItem::block(lines), blank_lines(first.blank_lines()))
+                }
+                None => (item, before),
+            };
+
+            let object = match verbatim.is_none() && is_separated(statement) {
+                true => SourceObject::separated(item),
+                false => SourceObject::line(item),
+            };
+
+            objects.push(object.with_blank_lines(before));
+        }
+
+        for comment in self.dangling_comments() {
+            objects.push(
+                SourceObject::line(comment.format(settings))
+                    .with_blank_lines(blank_lines(comment.blank_lines())),
+            );
+        }
+
+        SourceItem::block(objects)
+    }
+}
+
+/// Returns whether a statement starts with a parenthesis.
+fn is_parenthesized(statement: &Statement) -> bool {
+    match statement {
+        Statement::FunctionCall(call) => starts_with_parenthesis(call.function()),
+        Statement::Assign(assign) => assign
+            .targets()
+            .first()
+            .is_some_and(starts_with_parenthesis),
+        Statement::CompoundAssign(assign) => starts_with_parenthesis(assign.target()),
+        _ => false,
+    }
+}
+
+/// Returns whether a statement is set apart from the statements around it by a blank line.
+fn is_separated(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::FunctionDeclaration(_) | Statement::LocalFunction(_)
+    )
+}
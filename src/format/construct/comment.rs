@@ -0,0 +1,177 @@
+use crate::ast::{Comment, Trivia};
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for Comment {
+    /// Formats the comment as written, without indenting the lines of a multi-line comment.
+    ///
+    /// Comments are kept before the statement, table field, argument, parameter or returned value
+    /// they precede, after the one they follow on the same line, or at the end of the block, table
+    /// or parentheses they close. Comments in the header of a statement, such as the condition of
+    /// an `if`, and within other expressions are moved before their statement.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let corpus = [
+    ///     (
+    ///         "local function f() -- after the signature\nreturn 1 -- after return\nend -- after end",
+    ///         "local function f()\n    -- after the signature\n    return 1 -- after return\nend -- after end",
+    ///     ),
+    ///     (
+    ///         "if x then\n-- only a comment\nelse\n--[[ a long\n   comment ]]\nend",
+    ///         "if x then\n    -- only a comment\nelse\n    --[[ a long\n   comment ]]\nend",
+    ///     ),
+    ///     (
+    ///         "local t = { -- options\na = 1, -- one\nb = 2 -- two\n-- more to come\n}",
+    ///         "local t = {\n    -- options\n    a = 1, -- one\n    b = 2, -- two\n    -- more to come\n}",
+    ///     ),
+    ///     ("local empty = { --[[ nothing ]] }", "local empty = {\n    --[[ nothing ]]\n}"),
+    ///     ("call(a, -- within\nb)", "call(\n    a, -- within\n    b\n)"),
+    ///     ("call(--[[ first ]] a, b)", "call(--[[ first ]] a, b)"),
+    ///     ("return --[[ r ]] 1", "return --[[ r ]] 1"),
+    ///     ("return -- r\n1, 2", "return\n    -- r\n    1,\n    2"),
+    ///     ("local function f(--[[ p ]] a) end", "local function f(--[[ p ]] a) end"),
+    ///     ("function f(\na, -- first\nb -- second\n)\nend", "function f(\n    a, -- first\n    b -- second\n) end"),
+    ///     ("if --[[ a ]] x then\ny()\nend", "--[[ a ]]\nif x then\n    y()\nend"),
+    ///     ("for i = 1, --[[ n ]] 10 do\ny()\nend", "--[[ n ]]\nfor i = 1, 10 do\n    y()\nend"),
+    ///     ("local x = 1; -- after a semicolon", "local x = 1 -- after a semicolon"),
+    ///     ("do\nlocal a = 1\n-- at the end\nend\n-- end of file", "do\n    local a = 1\n    -- at the end\nend\n-- end of file"),
+    ///     ("local f = function() -- todo\nend", "local f = function()\n    -- todo\nend"),
+    ///     ("repeat --[[ wait ]] until done -- finished  ", "repeat\n    --[[ wait ]]\nuntil done -- finished"),
+    /// ];
+    ///
+    /// let settings = SourceFormatSettings::default();
+    ///
+    /// for (source, expected) in corpus {
+    ///     let formatted = parse(source).unwrap().block().format_string(&settings);
+    ///     assert_eq!(formatted, expected);
+    ///
+    ///     let reformatted = parse(&formatted).unwrap().block().format_string(&settings);
+    ///     assert_eq!(reformatted, formatted);
+    /// }
+    /// ```
+    fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::document(Document::verbatim(self.text()))
+    }
+}
+
+/// Appends the trailing comments of a [`Trivia`] to the last line of a formatted node.
+pub(crate) fn format_trailing(
+    item: SourceItem,
+    trivia: Option<&Trivia>,
+    settings: &SourceFormatSettings,
+) -> SourceItem {
+    let trailing = match trivia {
+        Some(trivia) if !trivia.trailing().is_empty() => trivia.trailing(),
+        _ => return item,
+    };
+
+    let mut documents = vec![item.into()];
+
+    for comment in trailing {
+        documents.push(Document::text(" "));
+        documents.push(comment.document(settings));
+    }
+
+    SourceItem::document(Document::concat(documents))
+}
+
+/// Returns whether the comments of a list, such as the arguments of a call, can all stay within
+/// its lines, which a line comment or a comment spanning lines cannot.
+pub(crate) fn fits_inline(trivia: &[Trivia], dangling_comments: &[Comment]) -> bool {
+    trivia
+        .iter()
+        .flat_map(|trivia| trivia.leading().iter().chain(trivia.trailing()))
+        .chain(dangling_comments)
+        .all(|comment| !comment.is_line() && !comment.text().contains('\n'))
+}
+
+/// Places the comments of a list that fit inline before the items they precede, where comments
+/// after the last item follow it.
+pub(crate) fn format_inline_comments(
+    items: Vec<Document>,
+    trivia: &[Trivia],
+    dangling_comments: &[Comment],
+    settings: &SourceFormatSettings,
+) -> Vec<Document> {
+    let mut documents = Vec::new();
+    let mut pending = Vec::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        let trivia = trivia.get(index);
+        let mut parts = Vec::new();
+
+        for comment in pending
+            .drain(..)
+            .chain(trivia.into_iter().flat_map(|trivia| trivia.leading()))
+        {
+            parts.push(comment.document(settings));
+            parts.push(Document::text(" "));
+        }
+
+        parts.push(item);
+        documents.push(Document::concat(parts));
+
+        // Comments after the separator of an item precede the next one.
+        pending.extend(trivia.into_iter().flat_map(|trivia| trivia.trailing()));
+    }
+
+    if let Some(last) = documents.pop() {
+        let mut parts = vec![last];
+
+        for comment in pending.into_iter().chain(dangling_comments) {
+            parts.push(Document::text(" "));
+            parts.push(comment.document(settings));
+        }
+
+        documents.push(Document::concat(parts));
+    }
+
+    documents
+}
+
+/// Formats a list between delimiters with each item and comment on its own line, as a line
+/// comment would otherwise take the rest of the list. Items are given followed by their
+/// separators, and a list without a closing delimiter, such as the values of a `return`, ends
+/// with its last item.
+pub(crate) fn format_commented_list(
+    open: &str,
+    items: Vec<Document>,
+    trivia: &[Trivia],
+    dangling_comments: &[Comment],
+    close: &str,
+    settings: &SourceFormatSettings,
+) -> Document {
+    let mut lines = Vec::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        let trivia = trivia.get(index);
+
+        for comment in trivia.into_iter().flat_map(|trivia| trivia.leading()) {
+            lines.push(comment.document(settings));
+        }
+
+        lines.push(format_trailing(SourceItem::document(item), trivia, settings).into());
+    }
+
+    for comment in dangling_comments {
+        lines.push(comment.document(settings));
+    }
+
+    let lines = lines
+        .into_iter()
+        .flat_map(|line| [Document::hard_line(), line])
+        .collect();
+
+    let mut documents = vec![
+        Document::text(open),
+        Document::indent(Document::concat(lines)),
+    ];
+
+    if !close.is_empty() {
+        documents.push(Document::hard_line());
+        documents.push(Document::text(close));
+    }
+
+    Document::concat(documents)
+}
@@ -0,0 +1,7 @@
+mod binding;
+mod block;
+mod comment;
+
+pub(crate) use comment::{
+    fits_inline, format_commented_list, format_inline_comments, format_trailing,
+};
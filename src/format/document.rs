@@ -0,0 +1,423 @@
+use crate::format::{IndentationMode, SourceFormatSettings, SourceItem, TrailingSeparator};
+
+/// A document of the layout engine, describing text along with the places it may break across
+/// lines, following the model of Wadler's "prettier printer".
+///
+/// A [`Group`](Document::Group) is printed on a single line when it fits within
+/// [`max_line_width`](SourceFormatSettings::max_line_width), and otherwise breaks each of its own
+/// lines, leaving the groups it contains to fit by themselves.
+/// ```
+/// use luna::format::{Document, SourceFormatSettings, SourceItem};
+///
+/// let arguments = Document::join(
+///     ["first", "second", "third"].map(Document::text),
+///     Document::concat(vec![Document::text(","), Document::line()]),
+/// );
+///
+/// let call = SourceItem::document(Document::group(Document::concat(vec![
+///     Document::text("call("),
+///     Document::indent(Document::concat(vec![Document::soft_line(), arguments])),
+///     Document::soft_line(),
+///     Document::text(")"),
+/// ])));
+///
+/// let settings = SourceFormatSettings::default();
+/// assert_eq!(call.format(&settings, 0), "call(first, second, third)");
+///
+/// let settings = SourceFormatSettings {
+///     max_line_width: 16,
+///     ..Default::default()
+/// };
+/// assert_eq!(call.format(&settings, 0), "call(\n    first,\n    second,\n    third\n)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Document {
+    /// Text, whose lines after the first are indented to the current indentation. Source tokens
+    /// that may span lines, such as long strings, are [`Verbatim`](Document::Verbatim) instead,
+    /// as indenting them would change their contents.
+    Text(String),
+    /// Text whose lines after the first are printed as written, such as a multi-line comment.
+    Verbatim(String),
+    /// A space when its group is flat, or a line break when it is broken.
+    Line,
+    /// Nothing when its group is flat, or a line break when it is broken.
+    SoftLine,
+    /// A line break, whatever the layout of its group.
+    HardLine,
+    /// Documents printed one after the other.
+    Concat(Vec<Document>),
+    /// A document whose lines are indented one level deeper.
+    Indent(Box<Document>),
+    /// A document whose lines break together, and only when it does not fit on the line.
+    Group(Box<Document>),
+    /// A document printed when its group is broken, and another printed when it is flat.
+    IfBreak(Box<Document>, Box<Document>),
+}
+
+impl Document {
+    /// Constructs a new [`Document::Text`] with the given text.
+    pub fn text<T: AsRef<str>>(text: T) -> Self {
+        Self::Text(text.as_ref().to_string())
+    }
+
+    /// Constructs a new [`Document::Verbatim`] with the given text.
+    pub fn verbatim<T: AsRef<str>>(text: T) -> Self {
+        Self::Verbatim(text.as_ref().to_string())
+    }
+
+    /// Constructs a new [`Document::Line`].
+    pub fn line() -> Self {
+        Self::Line
+    }
+
+    /// Constructs a new [`Document::SoftLine`].
+    pub fn soft_line() -> Self {
+        Self::SoftLine
+    }
+
+    /// Constructs a new [`Document::HardLine`].
+    pub fn hard_line() -> Self {
+        Self::HardLine
+    }
+
+    /// Constructs a new [`Document::Concat`] of the given documents.
+    pub fn concat(documents: Vec<Document>) -> Self {
+        Self::Concat(documents)
+    }
+
+    /// Constructs a new [`Document::Indent`] of the given document.
+    pub fn indent(document: Document) -> Self {
+        Self::Indent(Box::new(document))
+    }
+
+    /// Constructs a new [`Document::Group`] of the given document.
+    pub fn group(document: Document) -> Self {
+        Self::Group(Box::new(document))
+    }
+
+    /// Constructs a new [`Document::IfBreak`], printing `broken` when its group breaks and `flat`
+    /// otherwise.
+    pub fn if_break(broken: Document, flat: Document) -> Self {
+        Self::IfBreak(Box::new(broken), Box::new(flat))
+    }
+
+    /// Concatenates the given documents with a separator between each of them.
+    pub fn join<I: IntoIterator<Item = Document>>(documents: I, separator: Document) -> Self {
+        let mut joined = Vec::new();
+
+        for document in documents {
+            if !joined.is_empty() {
+                joined.push(separator.clone());
+            }
+
+            joined.push(document);
+        }
+
+        Self::Concat(joined)
+    }
+
+    /// Returns whether this [`Document`] prints nothing.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) | Self::Verbatim(text) => text.is_empty(),
+            Self::Line | Self::SoftLine | Self::HardLine => false,
+            Self::Concat(documents) => documents.iter().all(Document::is_empty),
+            Self::Indent(document) | Self::Group(document) => document.is_empty(),
+            Self::IfBreak(broken, flat) => broken.is_empty() && flat.is_empty(),
+        }
+    }
+
+    /// Returns whether this [`Document`] contains a line break, whatever the layout of its groups.
+    fn has_line_break(&self) -> bool {
+        match self {
+            Self::Text(text) | Self::Verbatim(text) => text.contains('\n'),
+            Self::Line | Self::SoftLine => false,
+            Self::HardLine => true,
+            Self::Concat(documents) => documents.iter().any(Document::has_line_break),
+            Self::Indent(document) | Self::Group(document) => document.has_line_break(),
+            Self::IfBreak(broken, flat) => broken.has_line_break() || flat.has_line_break(),
+        }
+    }
+
+    /// Prints this [`Document`], starting on a new line at the given indentation.
+    pub fn format(&self, settings: &SourceFormatSettings, indent: usize) -> String {
+        let mut printer = Printer {
+            settings,
+            output: String::new(),
+            column: 0,
+            line_start: true,
+        };
+
+        printer.print(self, indent);
+        printer.output
+    }
+}
+
+impl From<SourceItem> for Document {
+    fn from(item: SourceItem) -> Self {
+        match item {
+            SourceItem::Text(text) => Self::Text(text),
+            SourceItem::Document(document) => document,
+
+            // The objects of a block are laid out as `SourceItem::format` does.
+            SourceItem::Block(objects) => {
+                let mut documents = Vec::new();
+                let mut last_separated = false;
+
+                for object in objects {
+                    let lines = 1 + object.blank_lines_before(last_separated);
+                    let description = object.description;
+                    let document = Document::from(object.item);
+
+                    if document.is_empty() {
+                        continue;
+                    }
+
+                    if !documents.is_empty() {
+                        documents.extend(std::iter::repeat_n(Self::HardLine, lines));
+                    }
+
+                    documents.push(match description.indented {
+                        true => Self::indent(document),
+                        false => document,
+                    });
+
+                    last_separated = description.separated;
+                }
+
+                Self::Concat(documents)
+            }
+        }
+    }
+}
+
+/// Formats items between delimiters, as `{ a, b }` when they fit on the line and with one item
+/// per line otherwise, separated and followed by a separator as the settings describe.
+pub(crate) fn format_delimited<I: IntoIterator<Item = Document>>(
+    open: &str,
+    items: I,
+    close: &str,
+    settings: &SourceFormatSettings,
+) -> Document {
+    let separator = settings.table_separator.text();
+
+    let items = Document::join(
+        items,
+        Document::concat(vec![Document::text(separator), Document::line()]),
+    );
+
+    let trailing = match settings.trailing_separator {
+        TrailingSeparator::Always => Document::text(separator),
+        TrailingSeparator::Never => Document::text(""),
+        TrailingSeparator::Multiline => {
+            Document::if_break(Document::text(separator), Document::text(""))
+        }
+    };
+
+    Document::group(Document::concat(vec![
+        Document::text(open),
+        Document::indent(Document::concat(vec![Document::line(), items])),
+        trailing,
+        Document::line(),
+        Document::text(close),
+    ]))
+}
+
+/// Formats arguments between parentheses, as `(a, b)` when they fit on the line and with one
+/// argument per line otherwise.
+pub(crate) fn format_arguments<I: IntoIterator<Item = Document>>(arguments: I) -> Document {
+    let arguments = Document::join(
+        arguments,
+        Document::concat(vec![Document::text(","), Document::line()]),
+    );
+
+    if arguments.is_empty() {
+        return Document::text("()");
+    }
+
+    Document::group(Document::concat(vec![
+        Document::text("("),
+        Document::indent(Document::concat(vec![Document::soft_line(), arguments])),
+        Document::soft_line(),
+        Document::text(")"),
+    ]))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+struct Printer<'s> {
+    settings: &'s SourceFormatSettings,
+    output: String,
+    column: usize,
+    /// Whether nothing was printed since the last line break, so the indentation is still due.
+    /// Indenting lazily keeps blank lines free of trailing whitespace.
+    line_start: bool,
+}
+
+impl Printer<'_> {
+    fn print(&mut self, document: &Document, indent: usize) {
+        let mut commands = vec![(indent, Mode::Break, document)];
+
+        while let Some((indent, mode, document)) = commands.pop() {
+            match document {
+                Document::Text(text) => self.write(text, indent),
+                Document::Verbatim(text) => self.write_verbatim(text, indent),
+
+                Document::Line if mode == Mode::Flat => self.write(" ", indent),
+                Document::SoftLine if mode == Mode::Flat => {}
+                Document::Line | Document::SoftLine | Document::HardLine => self.newline(),
+
+                Document::Concat(documents) => commands.extend(
+                    documents
+                        .iter()
+                        .rev()
+                        .map(|document| (indent, mode, document)),
+                ),
+                Document::Indent(document) => commands.push((indent + 1, mode, document)),
+
+                Document::Group(document) => {
+                    let mode = match mode == Mode::Flat || self.fits(document, indent, &commands) {
+                        true => Mode::Flat,
+                        false => Mode::Break,
+                    };
+
+                    commands.push((indent, mode, document));
+                }
+
+                Document::IfBreak(broken, flat) => match mode {
+                    Mode::Break => commands.push((indent, mode, broken)),
+                    Mode::Flat => commands.push((indent, mode, flat)),
+                },
+            }
+        }
+    }
+
+    /// Returns whether a group fits flat on the rest of the line, along with whatever follows it
+    /// up to the next line break. A group containing a line break, such as the body of a function,
+    /// never fits.
+    fn fits(&self, document: &Document, indent: usize, rest: &[(usize, Mode, &Document)]) -> bool {
+        if document.has_line_break() {
+            return false;
+        }
+
+        let column = match self.line_start {
+            true => indentation_width(self.settings, indent),
+            false => self.column,
+        };
+
+        let Some(mut remaining) = self.settings.max_line_width.checked_sub(column) else {
+            return false;
+        };
+
+        let mut stack = vec![(Mode::Flat, document)];
+        let mut rest = rest.iter().rev();
+
+        loop {
+            let (mode, document) = match stack.pop() {
+                Some(command) => command,
+                None => match rest.next() {
+                    Some((_, mode, document)) => (*mode, *document),
+                    None => return true,
+                },
+            };
+
+            let width = match document {
+                Document::Text(text) | Document::Verbatim(text) => match text.split_once('\n') {
+                    Some((line, _)) => return remaining >= text_width(line),
+                    None => text_width(text),
+                },
+
+                Document::Line if mode == Mode::Flat => 1,
+                Document::SoftLine if mode == Mode::Flat => 0,
+                Document::Line | Document::SoftLine | Document::HardLine => return true,
+
+                Document::Concat(documents) => {
+                    stack.extend(documents.iter().rev().map(|document| (mode, document)));
+                    0
+                }
+                Document::Indent(document) | Document::Group(document) => {
+                    stack.push((mode, document));
+                    0
+                }
+                Document::IfBreak(broken, flat) => {
+                    stack.push(match mode {
+                        Mode::Break => (mode, broken),
+                        Mode::Flat => (mode, flat),
+                    });
+                    0
+                }
+            };
+
+            match remaining.checked_sub(width) {
+                Some(left) => remaining = left,
+                None => return false,
+            }
+        }
+    }
+
+    fn write(&mut self, text: &str, indent: usize) {
+        for (index, line) in text.split('\n').enumerate() {
+            if index > 0 {
+                self.newline();
+            }
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if self.line_start {
+                let indentation = indentation(self.settings, indent);
+
+                self.output.push_str(&indentation);
+                self.column = indentation_width(self.settings, indent);
+                self.line_start = false;
+            }
+
+            self.output.push_str(line);
+            self.column += text_width(line);
+        }
+    }
+
+    fn write_verbatim(&mut self, text: &str, indent: usize) {
+        let mut lines = text.split('\n');
+        self.write(lines.next().unwrap_or_default(), indent);
+
+        for line in lines {
+            self.output.push('\n');
+            self.output.push_str(line);
+            self.column = text_width(line);
+            self.line_start = false;
+        }
+    }
+
+    fn newline(&mut self) {
+        self.output.push('\n');
+        self.column = 0;
+        self.line_start = true;
+    }
+}
+
+pub(crate) fn indentation(settings: &SourceFormatSettings, indent: usize) -> String {
+    match settings.indentation {
+        IndentationMode::None => String::new(),
+        IndentationMode::Spaces(spaces) => " ".repeat(spaces * indent),
+        IndentationMode::Tabs(tabs) => "\t".repeat(tabs * indent),
+    }
+}
+
+/// Returns the width of the indentation, where a tab is measured as four columns.
+fn indentation_width(settings: &SourceFormatSettings, indent: usize) -> usize {
+    match settings.indentation {
+        IndentationMode::None => 0,
+        IndentationMode::Spaces(spaces) => spaces * indent,
+        IndentationMode::Tabs(tabs) => tabs * indent * 4,
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    text.chars().count()
+}
@@ -0,0 +1,124 @@
+use crate::ast::{Expression, FunctionCall};
+use crate::format::construct::{fits_inline, format_commented_list, format_inline_comments};
+use crate::format::expression::format_prefix;
+use crate::format::{
+    format_arguments, CallParentheses, Document, SourceFormatItem, SourceFormatSettings, SourceItem,
+};
+
+impl SourceFormatItem for FunctionCall {
+    /// Formats the call on one line, or with one argument per line when they do not fit, leaving
+    /// out the parentheses around a sole string or table argument as the settings describe.
+    /// ```
+    /// use luna::format::{CallParentheses, SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("print(\"the quick brown fox\", \"jumps over\", \"the lazy dog\")").unwrap();
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "print(\"the quick brown fox\", \"jumps over\", \"the lazy dog\")"
+    /// );
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     max_line_width: 40,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "print(\n    \"the quick brown fox\",\n    \"jumps over\",\n    \"the lazy dog\"\n)"
+    /// );
+    ///
+    /// let chunk = parse("local json = require('json')\nconfigure({ debug = true })").unwrap();
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     call_parentheses: CallParentheses::OmitString,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "local json = require \"json\"\nconfigure({ debug = true })"
+    /// );
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let function = match self.method_name() {
+            Some(method) => Document::concat(vec![
+                format_prefix(self.function(), settings),
+                Document::text(format!(":{}", method)),
+            ]),
+            None => format_prefix(self.function(), settings),
+        };
+
+        let omit = settings.call_parentheses;
+
+        let arguments = match self.arguments().as_slice() {
+            _ if self.has_comments() => self.format_commented_arguments(settings),
+            [] => Document::text("()"),
+
+            [argument @ Expression::StringLiteral(_)]
+                if matches!(omit, CallParentheses::Omit | CallParentheses::OmitString) =>
+            {
+                Document::concat(vec![Document::text(" "), argument.document(settings)])
+            }
+            [argument @ Expression::TableConstructor(_)]
+                if matches!(omit, CallParentheses::Omit | CallParentheses::OmitTable) =>
+            {
+                Document::concat(vec![Document::text(" "), argument.document(settings)])
+            }
+
+            // A sole table or function breaks within its own delimiters, as in `f({`.
+            [argument @ (Expression::TableConstructor(_) | Expression::Function(_))] => {
+                Document::concat(vec![
+                    Document::text("("),
+                    argument.document(settings),
+                    Document::text(")"),
+                ])
+            }
+
+            arguments => {
+                format_arguments(arguments.iter().map(|argument| argument.document(settings)))
+            }
+        };
+
+        SourceItem::document(Document::concat(vec![function, arguments]))
+    }
+}
+
+impl FunctionCall {
+    /// Formats the arguments with their comments, which stay in place when they fit within a
+    /// line, and otherwise put each argument and comment on its own line.
+    fn format_commented_arguments(&self, settings: &SourceFormatSettings) -> Document {
+        let arguments = self
+            .arguments()
+            .iter()
+            .map(|argument| argument.document(settings));
+
+        if fits_inline(self.trivia(), self.dangling_comments()) && !self.arguments().is_empty() {
+            return format_arguments(format_inline_comments(
+                arguments.collect(),
+                self.trivia(),
+                self.dangling_comments(),
+                settings,
+            ));
+        }
+
+        let last = self.arguments().len().saturating_sub(1);
+
+        let arguments = arguments
+            .enumerate()
+            .map(|(index, argument)| match index == last {
+                true => argument,
+                false => Document::concat(vec![argument, Document::text(",")]),
+            })
+            .collect();
+
+        format_commented_list(
+            "(",
+            arguments,
+            self.trivia(),
+            self.dangling_comments(),
+            ")",
+            settings,
+        )
+    }
+}
@@ -0,0 +1,105 @@
+use crate::ast::{Function, FunctionVariadic, TypePackTail};
+use crate::format::annotation::{format_generics, format_return_pack};
+use crate::format::construct::{fits_inline, format_commented_list, format_inline_comments};
+use crate::format::{
+    Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject,
+};
+
+impl SourceFormatItem for FunctionVariadic {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self.annotation() {
+            // `...: number` is written with the type of each value, rather than as `...number`.
+            Some(TypePackTail::Variadic(annotation)) => {
+                SourceItem::Text(format!("...: {}", annotation.format_string(settings)))
+            }
+            Some(tail) => SourceItem::Text(format!("...: {}", tail.format_string(settings))),
+            None => SourceItem::text("..."),
+        }
+    }
+}
+
+/// Formats a [`Function`] with the given header, such as `local function name`, followed by its
+/// signature, indented block and `end`.
+pub(crate) fn format_function(
+    header: &str,
+    function: &Function,
+    settings: &SourceFormatSettings,
+) -> SourceItem {
+    let generics = format_generics(function.generics(), settings);
+
+    let parameters = function
+        .parameters()
+        .iter()
+        .map(|parameter| parameter.format_string(settings))
+        .chain(function.variadic().map(|v| v.format_string(settings)))
+        .collect::<Vec<_>>();
+
+    let returns = match function.returns() {
+        Some(returns) => format!(": {}", format_return_pack(returns, settings)),
+        None => String::new(),
+    };
+
+    let parameters = match function.has_parameter_comments() {
+        true => format_commented_parameters(function, parameters, settings),
+        false => Document::text(format!("({})", parameters.join(", "))),
+    };
+
+    let signature = Document::concat(vec![
+        Document::text(format!("{}{}", header, generics)),
+        parameters,
+        Document::text(returns),
+    ]);
+
+    if function.block().is_empty() && function.block().dangling_comments().is_empty() {
+        return SourceItem::document(Document::concat(vec![signature, Document::text(" end")]));
+    }
+
+    SourceItem::block(vec![
+        SourceObject::line(SourceItem::document(signature)),
+        SourceObject::indented(function.block().format(settings)),
+        SourceObject::line(SourceItem::text("end")),
+    ])
+}
+
+/// Formats the parameters of a function with their comments, which stay in place when they fit
+/// within a line, and otherwise put each parameter and comment on its own line.
+fn format_commented_parameters(
+    function: &Function,
+    parameters: Vec<String>,
+    settings: &SourceFormatSettings,
+) -> Document {
+    let trivia = function.parameter_trivia();
+    let comments = function.parameter_comments();
+
+    if fits_inline(trivia, comments) && !parameters.is_empty() {
+        let parameters = parameters.into_iter().map(Document::text).collect();
+
+        return Document::concat(vec![
+            Document::text("("),
+            Document::join(
+                format_inline_comments(parameters, trivia, comments, settings),
+                Document::text(", "),
+            ),
+            Document::text(")"),
+        ]);
+    }
+
+    let last = parameters.len().saturating_sub(1);
+
+    let parameters = parameters
+        .into_iter()
+        .enumerate()
+        .map(|(index, parameter)| match index == last {
+            true => Document::text(parameter),
+            false => Document::text(format!("{},", parameter)),
+        })
+        .collect();
+
+    format_commented_list("(", parameters, trivia, comments, ")", settings)
+}
+
+impl SourceFormatItem for Function {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        format_function("function", self, settings)
+    }
+}
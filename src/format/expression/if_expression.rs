@@ -0,0 +1,25 @@
+use crate::ast::IfExpression;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for IfExpression {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let mut documents = vec![
+            Document::text("if "),
+            self.condition().document(settings),
+            Document::text(" then "),
+            self.consequent().document(settings),
+        ];
+
+        for (condition, consequent) in self.else_ifs() {
+            documents.push(Document::text(" elseif "));
+            documents.push(condition.document(settings));
+            documents.push(Document::text(" then "));
+            documents.push(consequent.document(settings));
+        }
+
+        documents.push(Document::text(" else "));
+        documents.push(self.alternative().document(settings));
+
+        SourceItem::document(Document::concat(documents))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::ast::InterpolatedString;
+use crate::format::{Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for InterpolatedString {
+    /// Formats the string with its segments as written and its expressions formatted between
+    /// braces.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("print(`{a+b} of {#{1, 2}} in {`{ nested }`}`)").unwrap();
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "print(`{a + b} of {#{ 1, 2 }} in {`{nested}`}`)"
+    /// );
+    ///
+    /// let chunk = parse("do local s, t = `{count}\\z\n    items`, 'and \\z\n    more' end").unwrap();
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "do\n    local s, t = `{count}\\z\n    items`, \"and \\z\n    more\"\nend"
+    /// );
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let mut segments = self.segments().iter().map(String::as_str);
+        let mut documents = vec![Document::text("`")];
+        documents.extend(segments.next().map(format_segment));
+
+        for (expression, segment) in self.expressions().iter().zip(segments) {
+            // `{{` is not allowed, so an expression starting with a brace is spaced from it.
+            let spacing = match expression.format_string(settings).starts_with('{') {
+                true => " ",
+                false => "",
+            };
+
+            documents.push(Document::text(format!("{{{}", spacing)));
+            documents.push(expression.document(settings));
+            documents.push(Document::text(format!("{}}}", spacing)));
+            documents.push(format_segment(segment));
+        }
+
+        documents.push(Document::text("`"));
+
+        SourceItem::document(Document::concat(documents))
+    }
+}
+
+/// Formats a segment as written, where one continued on the next line with `\` changes its value
+/// when indented.
+fn format_segment(segment: &str) -> Document {
+    match segment.contains('\n') {
+        true => Document::verbatim(segment),
+        false => Document::text(segment),
+    }
+}
@@ -1,5 +1,10 @@
-use crate::ast::expression::literal::{BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral};
-use crate::format::{SourceFormatItem, SourceFormatSettings, SourceItem};
+use crate::ast::expression::literal::{
+    BooleanLiteral, NilLiteral, NumberLiteral, StringLiteral, VarargLiteral,
+};
+use crate::format::{
+    DigitSeparators, Document, LeadingZero, LetterCase, NumberFormat, QuoteStyle, SourceFormatItem,
+    SourceFormatSettings, SourceItem,
+};
 
 impl SourceFormatItem for NilLiteral {
     fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
@@ -14,13 +19,284 @@ impl SourceFormatItem for BooleanLiteral {
 }
 
 impl SourceFormatItem for NumberLiteral {
-    fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
-        SourceItem::text(self.value())
+    /// Formats a number literal as the [`number_format`](SourceFormatSettings::number_format)
+    /// describes, keeping its value.
+    /// ```
+    /// use luna::ast::NumberLiteral;
+    /// use luna::format::{DigitSeparators, LeadingZero, LetterCase, NumberFormat};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let format = |literal: &str, number_format| {
+    ///     let settings = SourceFormatSettings {
+    ///         number_format,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     NumberLiteral::from(literal).format_string(&settings)
+    /// };
+    ///
+    /// let default = NumberFormat::default();
+    /// assert_eq!(format(".5", default), ".5");
+    /// assert_eq!(format("1.5E-3", default), "1.5E-3");
+    /// assert_eq!(format("0XffA0", default), "0xffA0");
+    /// assert_eq!(format("1_0", default), "1_0");
+    ///
+    /// let normalized = NumberFormat {
+    ///     hex_digits: LetterCase::Upper,
+    ///     exponent: LetterCase::Upper,
+    ///     digit_separators: DigitSeparators::Insert,
+    ///     leading_zero: LeadingZero::Insert,
+    /// };
+    /// assert_eq!(format("1000000", normalized), "1_000_000");
+    /// assert_eq!(format("100000", normalized), "100000");
+    /// assert_eq!(format("12_34567.123_4e1_0", normalized), "1_234_567.1234E10");
+    /// assert_eq!(format("0_xdeadbeef00", normalized), "0xDE_ADBE_EF00");
+    /// assert_eq!(format("0B1010_1", normalized), "0b10101");
+    /// assert_eq!(format(".5", normalized), "0.5");
+    ///
+    /// let removed = NumberFormat {
+    ///     digit_separators: DigitSeparators::Remove,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(format("1_000_000", removed), "1000000");
+    /// assert_eq!(format("0x_FF_FF", removed), "0xFFFF");
+    ///
+    /// let without_zero = NumberFormat {
+    ///     leading_zero: LeadingZero::Remove,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(format("0.5", without_zero), ".5");
+    /// assert_eq!(format("0", without_zero), "0");
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::text(format_number(self.value(), &settings.number_format))
     }
 }
 
 impl SourceFormatItem for StringLiteral {
+    /// Formats a string literal with the quotes of the
+    /// [`quote_style`](SourceFormatSettings::quote_style), escaping those quotes within it and
+    /// unescaping the others.
+    /// ```
+    /// use luna::ast::StringLiteral;
+    /// use luna::format::{QuoteStyle, SourceFormat, SourceFormatSettings};
+    ///
+    /// let format = |literal: &str, quote_style| {
+    ///     let settings = SourceFormatSettings {
+    ///         quote_style,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     StringLiteral::from(literal).format_string(&settings)
+    /// };
+    ///
+    /// assert_eq!(format("'text'", QuoteStyle::PreferDouble), "\"text\"");
+    /// assert_eq!(format("'say \"hi\"'", QuoteStyle::PreferDouble), "'say \"hi\"'");
+    /// assert_eq!(format("\"it's\"", QuoteStyle::PreferSingle), "\"it's\"");
+    /// assert_eq!(format("'it\\'s'", QuoteStyle::ForceDouble), "\"it's\"");
+    /// assert_eq!(format("\"say \\\"hi\\\"\"", QuoteStyle::ForceSingle), "'say \"hi\"'");
+    /// assert_eq!(format("\"it's\"", QuoteStyle::ForceSingle), "'it\\'s'");
+    /// assert_eq!(format("'\\\\'", QuoteStyle::ForceDouble), "\"\\\\\"");
+    /// assert_eq!(format("'text'", QuoteStyle::Keep), "'text'");
+    /// assert_eq!(format("[['text']]", QuoteStyle::ForceDouble), "[['text']]");
+    /// ```
+    ///
+    /// The lines of a string spanning lines are kept as written, however deeply it is nested.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let source = concat!(
+    ///     "local function f() if ready then ",
+    ///     "local s = [[\nx\n  y]] return s, 'a\\\nb' ",
+    ///     "end end"
+    /// );
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// let formatted = parse(source).unwrap().block().format_string(&settings);
+    /// assert_eq!(
+    ///     formatted,
+    ///     concat!(
+    ///         "local function f()\n",
+    ///         "    if ready then\n",
+    ///         "        local s = [[\nx\n  y]]\n",
+    ///         "        return s, \"a\\\nb\"\n",
+    ///         "    end\n",
+    ///         "end"
+    ///     )
+    /// );
+    ///
+    /// let reformatted = parse(&formatted).unwrap().block().format_string(&settings);
+    /// assert_eq!(reformatted, formatted);
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let literal = self.value();
+
+        let formatted = match literal.chars().next().filter(|c| *c == '"' || *c == '\'') {
+            Some(quote) => {
+                let content = &literal[1..literal.len() - 1];
+
+                let target = match settings.quote_style {
+                    QuoteStyle::Keep => quote,
+                    QuoteStyle::ForceDouble => '"',
+                    QuoteStyle::ForceSingle => '\'',
+                    QuoteStyle::PreferDouble => preferred_quote(content, '"', '\''),
+                    QuoteStyle::PreferSingle => preferred_quote(content, '\'', '"'),
+                };
+
+                requote(content, target)
+            }
+            None => literal.to_string(),
+        };
+
+        // Indenting the lines of a long string, or of one continued with `\`, changes its value.
+        match formatted.contains('\n') {
+            true => SourceItem::document(Document::verbatim(formatted)),
+            false => SourceItem::Text(formatted),
+        }
+    }
+}
+
+impl SourceFormatItem for VarargLiteral {
     fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
-        SourceItem::text(self.value())
+        SourceItem::text("...")
+    }
+}
+
+/// Returns the preferred quote, unless the other needs fewer escapes in the given content. Each
+/// quote within it needs escaping when it matches the quotes around it, whether it is escaped in
+/// the source or not.
+fn preferred_quote(content: &str, preferred: char, other: char) -> char {
+    match content.matches(other).count() < content.matches(preferred).count() {
+        true => other,
+        false => preferred,
+    }
+}
+
+/// Quotes the content of a string literal with the given quote, escaping it within the content
+/// and unescaping the other quote. Any other escape sequence is kept as written.
+fn requote(content: &str, quote: char) -> String {
+    let mut literal = String::with_capacity(content.len() + 2);
+    let mut characters = content.chars();
+
+    literal.push(quote);
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => match characters.next() {
+                Some(escaped @ ('"' | '\'')) if escaped != quote => literal.push(escaped),
+                Some(escaped) => {
+                    literal.push('\\');
+                    literal.push(escaped);
+                }
+                None => literal.push('\\'),
+            },
+            character if character == quote => {
+                literal.push('\\');
+                literal.push(character);
+            }
+            character => literal.push(character),
+        }
+    }
+
+    literal.push(quote);
+    literal
+}
+
+fn format_number(literal: &str, format: &NumberFormat) -> String {
+    // The prefix of a hexadecimal or binary literal may itself contain underscores, as in `0_x1`.
+    let radix = literal
+        .find(['x', 'X', 'b', 'B'])
+        .map(|position| literal.split_at(position));
+
+    if let Some((_, digits)) = radix {
+        let (prefix, digits) = digits.split_at(1);
+
+        let prefix = match prefix {
+            "x" | "X" => "0x",
+            _ => "0b",
+        };
+
+        let digits = separate(digits, format.digit_separators, Some(4));
+        return format!("{}{}", prefix, letter_case(&digits, format.hex_digits));
+    }
+
+    let (mantissa, exponent) = match literal.find(['e', 'E']) {
+        Some(position) => {
+            let (mantissa, exponent) = literal.split_at(position);
+            (mantissa, Some(&exponent[1..]))
+        }
+        None => (literal, None),
+    };
+
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+
+    let mut number = separate(integer, format.digit_separators, Some(3));
+
+    match format.leading_zero {
+        LeadingZero::Insert if number.is_empty() => number.push('0'),
+        // Only zeros, such as the `0_0` of `0_0.5`, are removed.
+        LeadingZero::Remove if fraction.is_some() && number.trim_matches(['0', '_']).is_empty() => {
+            number.clear()
+        }
+        _ => {}
+    }
+
+    if let Some(fraction) = fraction {
+        number.push('.');
+        number.push_str(&separate(fraction, format.digit_separators, None));
+    }
+
+    if let Some(exponent) = exponent {
+        number.push_str(&letter_case(
+            &literal[mantissa.len()..][..1],
+            format.exponent,
+        ));
+        number.push_str(&separate(exponent, format.digit_separators, None));
+    }
+
+    number
+}
+
+/// Writes the underscores of the given digits, where those inserted separate groups of the given
+/// size, if any.
+fn separate(digits: &str, separators: DigitSeparators, group: Option<usize>) -> String {
+    let digits = match separators {
+        DigitSeparators::Keep => return digits.to_string(),
+        _ => digits.replace('_', ""),
+    };
+
+    let size = match (separators, group) {
+        (DigitSeparators::Insert, Some(size)) => size,
+        _ => return digits,
+    };
+
+    let count = digits.len();
+
+    if count <= size * 2 {
+        return digits;
+    }
+
+    let mut separated = String::with_capacity(digits.len() + count / size);
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (count - index) % size == 0 {
+            separated.push('_');
+        }
+
+        separated.push(digit);
+    }
+
+    separated
+}
+
+fn letter_case(text: &str, case: LetterCase) -> String {
+    match case {
+        LetterCase::Keep => text.to_string(),
+        LetterCase::Lower => text.to_ascii_lowercase(),
+        LetterCase::Upper => text.to_ascii_uppercase(),
     }
 }
@@ -1,9 +1,20 @@
 use crate::ast::Expression;
 use crate::format::{SourceFormatItem, SourceFormatSettings, SourceItem};
 
+mod call;
+mod function_expression;
+mod if_expression;
+mod interpolated_string;
 mod literal;
 mod operation;
 mod operator;
+mod parenthesized;
+mod table_constructor;
+mod type_assertion;
+mod variable;
+
+pub(crate) use function_expression::format_function;
+pub(crate) use variable::{format_prefix, starts_with_parenthesis};
 
 impl SourceFormatItem for Expression {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
@@ -12,9 +23,23 @@ impl SourceFormatItem for Expression {
             Expression::BooleanLiteral(expr) => expr.format(settings),
             Expression::NumberLiteral(expr) => expr.format(settings),
             Expression::StringLiteral(expr) => expr.format(settings),
+            Expression::VarargLiteral(expr) => expr.format(settings),
+            Expression::InterpolatedString(expr) => expr.format(settings),
+
+            Expression::Identifier(expr) => expr.format(settings),
+            Expression::Index(expr) => expr.format(settings),
+
+            Expression::Function(expr) => expr.format(settings),
+            Expression::TableConstructor(expr) => expr.format(settings),
+
+            Expression::FunctionCall(expr) => expr.format(settings),
 
             Expression::UnaryOperation(expr) => expr.format(settings),
             Expression::BinaryOperation(expr) => expr.format(settings),
+
+            Expression::Parenthesized(expr) => expr.format(settings),
+            Expression::IfExpression(expr) => expr.format(settings),
+            Expression::TypeAssertion(expr) => expr.format(settings),
         }
     }
 }
@@ -1,39 +1,106 @@
-use crate::ast::{BinaryOperation, UnaryOperation};
+use crate::ast::{BinaryOperation, Expression, UnaryOperation};
 use crate::evaluation::{Associativity, EvaluationOrder};
-use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+use crate::format::{Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
 
 impl SourceFormatItem for UnaryOperation {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
-        let operator = self.operator().format_string(settings);
-        let operand = self.operand().format_string(settings);
+        let operator = Document::text(self.operator().format_string(settings));
+        let operand = self.operand().document(settings);
 
         match self.should_wrap(self.operand(), self.associativity().is_right()) {
-            true => SourceItem::text(format!("{}({})", operator, operand)),
-            false => SourceItem::text(format!("{}{}", operator, operand)),
+            true => SourceItem::document(Document::concat(vec![
+                operator,
+                Document::text("("),
+                operand,
+                Document::text(")"),
+            ])),
+            false => SourceItem::document(Document::concat(vec![operator, operand])),
         }
     }
 }
 
 impl SourceFormatItem for BinaryOperation {
+    /// Formats the operation on one line, or with each operand of a chain of operators of the same
+    /// precedence on its own line, preceded by its operator, when it does not fit.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("local message = \"Welcome back, \" .. player.Name .. \"!\"").unwrap();
+    ///
+    /// let settings = SourceFormatSettings {
+    ///     max_line_width: 40,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "local message = \"Welcome back, \"\n    .. player.Name\n    .. \"!\""
+    /// );
+    /// ```
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
-        let operator = self.operator().format_string(settings);
+        let mut chain = self.chain(settings).into_iter();
+        let first = chain.next().unwrap_or_else(|| Document::text(""));
+
+        SourceItem::document(Document::group(Document::concat(vec![
+            first,
+            Document::indent(Document::concat(chain.collect())),
+        ])))
+    }
+}
 
-        let left = self.left().format_string(settings);
-        let right = self.right().format_string(settings);
+impl BinaryOperation {
+    /// Returns the first operand of the chain of operations of the same precedence this operation
+    /// belongs to, such as `a + b - c`, followed by each operator along with the operand after it.
+    fn chain(&self, settings: &SourceFormatSettings) -> Vec<Document> {
+        let operator = self.operator().format_string(settings);
 
         let left_should_wrap = self.should_wrap(self.left(), self.associativity().is_right());
-        let right_should_wrap = self.should_wrap(self.right(), self.associativity().is_left());
+        // An unspaced subtraction followed by a negation would otherwise start a comment, as in `a--b`.
+        let right_should_wrap = self.should_wrap(self.right(), self.associativity().is_left())
+            || (operator.ends_with('-') && self.right().format_string(settings).starts_with('-'));
 
-        let left_string = match left_should_wrap {
-            true => format!("({})", left),
-            false => left,
-        };
+        let mut chain = self.operand(self.left(), left_should_wrap, settings);
+        let mut right = self
+            .operand(self.right(), right_should_wrap, settings)
+            .into_iter();
 
-        let right_string = match right_should_wrap {
-            true => format!("({})", right),
-            false => right,
+        // A spaced operator is preceded by a line, which breaks before it, while an unspaced
+        // operator stays unspaced when flat.
+        let link = match operator.strip_prefix(' ') {
+            Some(operator) => Document::concat(vec![Document::line(), Document::text(operator)]),
+            None => Document::concat(vec![Document::soft_line(), Document::text(operator)]),
         };
 
-        SourceItem::text(format!("{}{}{}", left_string, operator, right_string))
+        chain.extend(
+            right
+                .next()
+                .map(|first| Document::concat(vec![link, first])),
+        );
+        chain.extend(right);
+        chain
+    }
+
+    /// Returns the chain of an operand, which is the operand alone unless it continues the chain
+    /// of this operation.
+    fn operand(
+        &self,
+        operand: &Expression,
+        wrapped: bool,
+        settings: &SourceFormatSettings,
+    ) -> Vec<Document> {
+        match operand {
+            _ if wrapped => vec![Document::concat(vec![
+                Document::text("("),
+                operand.document(settings),
+                Document::text(")"),
+            ])],
+            Expression::BinaryOperation(operation)
+                if operation.evaluation_rules().precedence()
+                    == self.evaluation_rules().precedence() =>
+            {
+                operation.chain(settings)
+            }
+            operand => vec![operand.document(settings)],
+        }
     }
 }
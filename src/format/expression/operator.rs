@@ -9,9 +9,10 @@ impl SourceFormatItem for UnaryOperator {
             UnaryOperator::Length => settings.operator_spacing.length,
         };
 
+        // Unary operators are prefixes, so they are only spaced from their operand.
         match spaced {
             false => SourceItem::text(self.text()),
-            true => SourceItem::text(format!(" {} ", self.text())),
+            true => SourceItem::text(format!("{} ", self.text())),
         }
     }
 }
@@ -24,9 +25,13 @@ impl SourceFormatItem for BinaryOperator {
             BinaryOperator::Subtract => settings.operator_spacing.subtract,
             BinaryOperator::Multiply => settings.operator_spacing.multiply,
             BinaryOperator::Divide => settings.operator_spacing.divide,
+            BinaryOperator::FloorDivide => settings.operator_spacing.floor_divide,
             BinaryOperator::Modulo => settings.operator_spacing.modulo,
             BinaryOperator::Power => settings.operator_spacing.power,
 
+            // Concatenation
+            BinaryOperator::Concat => settings.operator_spacing.concat,
+
             // Logical
             BinaryOperator::And => settings.operator_spacing.and,
             BinaryOperator::Or => settings.operator_spacing.or,
@@ -0,0 +1,12 @@
+use crate::ast::Parenthesized;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for Parenthesized {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::document(Document::concat(vec![
+            Document::text("("),
+            self.expression().document(settings),
+            Document::text(")"),
+        ]))
+    }
+}
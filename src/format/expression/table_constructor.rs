@@ -0,0 +1,85 @@
+use crate::ast::{TableConstructor, TableField};
+use crate::format::construct::format_commented_list;
+use crate::format::{
+    format_delimited, Document, SourceFormatItem, SourceFormatSettings, SourceItem,
+    TrailingSeparator,
+};
+
+impl SourceFormatItem for TableField {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self {
+            Self::Named(name, value) => SourceItem::document(Document::concat(vec![
+                Document::text(format!("{} = ", name)),
+                value.document(settings),
+            ])),
+            Self::Keyed(key, value) => SourceItem::document(Document::concat(vec![
+                Document::text("["),
+                key.document(settings),
+                Document::text("] = "),
+                value.document(settings),
+            ])),
+            Self::Positional(value) => value.format(settings),
+        }
+    }
+}
+
+impl SourceFormatItem for TableConstructor {
+    /// Formats the table on one line, or with one field per line when it does not fit or has
+    /// comments, which are kept next to the fields they are attached to.
+    /// ```
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    /// use luna::parser::parse;
+    ///
+    /// let chunk = parse("local weights = { heavy = 3, -- rarely used\nlight = 1 }").unwrap();
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(
+    ///     chunk.block().format_string(&settings),
+    ///     "local weights = {\n    heavy = 3, -- rarely used\n    light = 1,\n}"
+    /// );
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        if self.has_comments() {
+            return SourceItem::document(self.format_commented(settings));
+        }
+
+        if self.fields().is_empty() {
+            return SourceItem::text("{}");
+        }
+
+        let fields = self.fields().iter().map(|field| field.document(settings));
+
+        SourceItem::document(format_delimited("{", fields, "}", settings))
+    }
+}
+
+impl TableConstructor {
+    /// Formats the table with each field and comment on its own line, as a line comment would
+    /// otherwise take the rest of the table.
+    fn format_commented(&self, settings: &SourceFormatSettings) -> Document {
+        let last = self.fields().len().saturating_sub(1);
+
+        let fields = self
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let separator = match settings.trailing_separator {
+                    TrailingSeparator::Never if index == last => "",
+                    _ => settings.table_separator.text(),
+                };
+
+                Document::concat(vec![field.document(settings), Document::text(separator)])
+            })
+            .collect();
+
+        format_commented_list(
+            "{",
+            fields,
+            self.trivia(),
+            self.dangling_comments(),
+            "}",
+            settings,
+        )
+    }
+}
@@ -0,0 +1,22 @@
+use crate::ast::TypeAssertion;
+use crate::evaluation::EvaluationOrder;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for TypeAssertion {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let expression = self.expression().document(settings);
+        let annotation = self.annotation().document(settings);
+
+        // Only simple expressions can be asserted, so operations and other assertions are wrapped.
+        let expression = match self.should_wrap(self.expression(), true) {
+            true => Document::concat(vec![Document::text("("), expression, Document::text(")")]),
+            false => expression,
+        };
+
+        SourceItem::document(Document::concat(vec![
+            expression,
+            Document::text(" :: "),
+            annotation,
+        ]))
+    }
+}
@@ -0,0 +1,50 @@
+use crate::ast::{Expression, Identifier, Index, IndexKey};
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+/// Formats an [`Expression`] that is indexed or called, wrapping it in parenthesis unless it is
+/// syntactically a prefix expression. For example, `("a"):rep(2)` and `(f or g)()`.
+pub(crate) fn format_prefix(expression: &Expression, settings: &SourceFormatSettings) -> Document {
+    let document = expression.document(settings);
+
+    match expression {
+        Expression::Identifier(_)
+        | Expression::Index(_)
+        | Expression::FunctionCall(_)
+        | Expression::Parenthesized(_) => document,
+        _ => Document::concat(vec![Document::text("("), document, Document::text(")")]),
+    }
+}
+
+/// Returns whether an expression formatted by [`format_prefix`] starts with a parenthesis, which a
+/// statement must not follow another with, as it would continue it as a call.
+pub(crate) fn starts_with_parenthesis(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(_) => false,
+        Expression::Index(index) => starts_with_parenthesis(index.object()),
+        Expression::FunctionCall(call) => starts_with_parenthesis(call.function()),
+        _ => true,
+    }
+}
+
+impl SourceFormatItem for Identifier {
+    fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::text(self.name())
+    }
+}
+
+impl SourceFormatItem for Index {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let object = format_prefix(self.object(), settings);
+
+        let key = match self.key() {
+            IndexKey::Field(name) => Document::text(format!(".{}", name)),
+            IndexKey::Computed(key) => Document::concat(vec![
+                Document::text("["),
+                key.document(settings),
+                Document::text("]"),
+            ]),
+        };
+
+        SourceItem::document(Document::concat(vec![object, key]))
+    }
+}
@@ -1,12 +1,23 @@
 pub mod annotation;
+pub mod config;
+pub mod construct;
+pub mod document;
 pub mod expression;
+mod range;
 pub mod settings;
+pub mod statement;
 
+pub use document::Document;
+pub(crate) use document::{format_arguments, format_delimited};
+pub use range::{format_on_type, format_range};
 pub use settings::*;
 
 pub struct FormatDescription {
     pub indented: bool,
+    /// Whether the object is set apart from the objects around it by at least one blank line.
     pub separated: bool,
+    /// The number of blank lines before the object, such as those kept from the source.
+    pub blank_lines: usize,
 }
 
 pub struct SourceObject {
@@ -14,9 +25,68 @@ pub struct SourceObject {
     description: FormatDescription,
 }
 
+impl SourceObject {
+    /// Constructs a new [`SourceObject`] with the given [`SourceItem`] and [`FormatDescription`].
+    pub fn new(item: SourceItem, description: FormatDescription) -> Self {
+        Self { item, description }
+    }
+
+    /// Constructs a new [`SourceObject`] that is placed on its own lines at the current indentation.
+    pub fn line(item: SourceItem) -> Self {
+        Self::new(
+            item,
+            FormatDescription {
+                indented: false,
+                separated: false,
+                blank_lines: 0,
+            },
+        )
+    }
+
+    /// Constructs a new [`SourceObject`] that is placed on its own lines, set apart from the
+    /// objects around it by a blank line.
+    pub fn separated(item: SourceItem) -> Self {
+        Self::new(
+            item,
+            FormatDescription {
+                indented: false,
+                separated: true,
+                blank_lines: 0,
+            },
+        )
+    }
+
+    /// Constructs a new [`SourceObject`] that is placed on its own lines, one level deeper than the current indentation.
+    pub fn indented(item: SourceItem) -> Self {
+        Self::new(
+            item,
+            FormatDescription {
+                indented: true,
+                separated: false,
+                blank_lines: 0,
+            },
+        )
+    }
+
+    /// Returns this [`SourceObject`] preceded by the given number of blank lines.
+    pub fn with_blank_lines(mut self, blank_lines: usize) -> Self {
+        self.description.blank_lines = blank_lines;
+        self
+    }
+
+    /// Returns the number of blank lines between the previous object and this one.
+    fn blank_lines_before(&self, last_separated: bool) -> usize {
+        let separated = last_separated || self.description.separated;
+        self.description.blank_lines.max(usize::from(separated))
+    }
+}
+
 pub enum SourceItem {
     Text(String),
     Block(Vec<SourceObject>),
+    /// A [`Document`] laid out by the width of its lines, such as a call that breaks its arguments
+    /// across lines when they do not fit.
+    Document(Document),
 }
 
 impl SourceItem {
@@ -28,19 +98,24 @@ impl SourceItem {
         Self::Block(objects)
     }
 
-    fn indentation(settings: &SourceFormatSettings, indent: usize) -> String {
-        match settings.indentation {
-            IndentationMode::None => String::new(),
-            IndentationMode::Spaces(spaces) => " ".repeat(spaces * indent),
-            IndentationMode::Tabs(tabs) => "\t".repeat(tabs * indent),
-        }
+    pub fn document(document: Document) -> Self {
+        Self::Document(document)
     }
 
     pub fn format(&self, settings: &SourceFormatSettings, indent: usize) -> String {
-        let indentation = Self::indentation(settings, indent);
-
-        let body = match self {
-            Self::Text(text) => text.to_string(),
+        match self {
+            // Text may span multiple lines, such as a function expression, so every line is indented.
+            Self::Text(text) => {
+                let indentation = document::indentation(settings, indent);
+
+                text.split('\n')
+                    .map(|line| match line.is_empty() {
+                        true => String::new(),
+                        false => format!("{}{}", indentation, line),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
 
             Self::Block(objects) => {
                 let mut buffer = String::new();
@@ -48,26 +123,44 @@ impl SourceItem {
 
                 for object in objects {
                     let this_separated = object.description.separated;
+                    let object_indent = indent + usize::from(object.description.indented);
 
-                    buffer.push_str(object.item.format(settings, indent + 1).as_str());
+                    let formatted = object.item.format(settings, object_indent);
 
-                    if last_separated || this_separated {
+                    // Empty objects, such as the body of an empty block, do not take up a line.
+                    if formatted.is_empty() {
+                        continue;
+                    }
+
+                    if !buffer.is_empty() {
                         buffer.push('\n');
+
+                        for _ in 0..object.blank_lines_before(last_separated) {
+                            buffer.push('\n');
+                        }
                     }
 
+                    buffer.push_str(formatted.as_str());
+
                     last_separated = this_separated;
                 }
 
                 buffer
             }
-        };
 
-        format!("{}{}", indentation, body)
+            Self::Document(document) => document.format(settings, indent),
+        }
     }
 }
 
 pub(crate) trait SourceFormatItem {
     fn format(&self, settings: &SourceFormatSettings) -> SourceItem;
+
+    /// Formats this item as a [`Document`], so that it is laid out along with the item containing
+    /// it.
+    fn document(&self, settings: &SourceFormatSettings) -> Document {
+        self.format(settings).into()
+    }
 }
 
 pub trait SourceFormat {
@@ -79,6 +172,11 @@ where
     T: SourceFormatItem,
 {
     fn format_string(&self, settings: &SourceFormatSettings) -> String {
-        self.format(settings).format(settings, 0)
+        let formatted = self.format(settings).format(settings, 0);
+
+        match settings.line_ending {
+            LineEnding::Lf => formatted,
+            line_ending => formatted.replace('\n', line_ending.text()),
+        }
     }
 }
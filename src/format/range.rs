@@ -0,0 +1,131 @@
+use crate::ast::{Block, Trivia};
+use crate::edit::TextEdit;
+use crate::format::{SourceFormat, SourceFormatSettings};
+use crate::parser::{parse, ParseError, Span};
+
+/// Formats the statements of the chunk that intersect the given byte range, along with their
+/// comments, returning the edits that do so. The source outside of them is left as it is.
+///
+/// Statements nested within others, such as in the body of a function, are formatted along with
+/// the statement of the chunk containing them.
+/// ```
+/// use luna::edit::apply_edits;
+/// use luna::format::{format_range, SourceFormatSettings};
+///
+/// let source = "local a=1\nlocal   b =  {1,2}\nlocal c=3";
+/// let settings = SourceFormatSettings::default();
+///
+/// let edits = format_range(source, 12..14, &settings).unwrap();
+/// assert_eq!(
+///     apply_edits(source, &edits),
+///     "local a=1\nlocal b = { 1, 2 }\nlocal c=3"
+/// );
+///
+/// // The edits only cover the text that changed.
+/// assert_eq!(edits.len(), 1);
+/// assert_eq!(edits[0].span(), &(16..27));
+///
+/// // The semicolons and trailing comments after the statements are kept.
+/// let source = "local a=1;  local   b=x;print(1) -- done";
+/// let edits = format_range(source, 14..15, &settings).unwrap();
+/// assert_eq!(apply_edits(source, &edits), "local a=1;  local b = x;print(1) -- done");
+///
+/// let source = "local a=1;local   b=2 --[[two]];local c=3";
+/// let edits = format_range(source, 0..30, &settings).unwrap();
+/// assert_eq!(apply_edits(source, &edits), "local a = 1\nlocal b = 2 --[[two]];local c=3");
+/// ```
+pub fn format_range(
+    source: &str,
+    range: Span,
+    settings: &SourceFormatSettings,
+) -> Result<Vec<TextEdit>, ParseError> {
+    let chunk = parse(source)?;
+    let spans = chunk.statement_spans();
+    let block = chunk.block();
+
+    let intersects = |span: &Span| span.start <= range.end && range.start <= span.end;
+
+    let Some(first) = spans.iter().position(intersects) else {
+        return Ok(Vec::new());
+    };
+    let last = spans.iter().rposition(intersects).unwrap_or(first);
+
+    let statements = block.statements()[first..=last].to_vec();
+    let mut trivia = (first..=last)
+        .map(|index| block.trivia().get(index).cloned().unwrap_or_default())
+        .collect::<Vec<_>>();
+
+    // The spans end at the last token of their statements, so the semicolons and trailing
+    // comments after the last one are left as they are.
+    if let Some(trivia) = trivia.last_mut() {
+        *trivia = without_trailing(trivia);
+    }
+
+    let formatted = Block::with_comments(statements, trivia, Vec::new()).format_string(settings);
+    let span = spans[first].start..spans[last].end;
+
+    Ok(minimal_edit(source, span, &formatted).into_iter().collect())
+}
+
+/// Formats the statement of the chunk at the given byte offset, such as the one just finished by
+/// typing `end`, returning the edits that do so.
+/// ```
+/// use luna::edit::apply_edits;
+/// use luna::format::{format_on_type, SourceFormatSettings};
+///
+/// let source = "print(1)\nif ready then go() end";
+/// let settings = SourceFormatSettings::default();
+///
+/// let edits = format_on_type(source, source.len(), &settings).unwrap();
+/// assert_eq!(apply_edits(source, &edits), "print(1)\nif ready then\n    go()\nend");
+/// ```
+pub fn format_on_type(
+    source: &str,
+    offset: usize,
+    settings: &SourceFormatSettings,
+) -> Result<Vec<TextEdit>, ParseError> {
+    format_range(source, offset..offset, settings)
+}
+
+fn without_trailing(trivia: &Trivia) -> Trivia {
+    let without =
+        Trivia::with_blank_lines(trivia.leading().clone(), Vec::new(), trivia.blank_lines());
+
+    match trivia.verbatim() {
+        Some(text) => without.with_verbatim(text),
+        None => without,
+    }
+}
+
+/// Returns the edit replacing the given span of the source with the given text, narrowed to the
+/// text that differs, if any does.
+fn minimal_edit(source: &str, span: Span, text: &str) -> Option<TextEdit> {
+    let original = &source[span.clone()];
+
+    if original == text {
+        return None;
+    }
+
+    let prefix = common_length(original.chars(), text.chars());
+    let suffix = common_length(
+        original[prefix..].chars().rev(),
+        text[prefix..].chars().rev(),
+    );
+
+    Some(TextEdit::new(
+        span.start + prefix..span.end - suffix,
+        &text[prefix..text.len() - suffix],
+    ))
+}
+
+/// Returns the length in bytes of the characters the given iterators start with in common.
+fn common_length<A, B>(a: A, b: B) -> usize
+where
+    A: Iterator<Item = char>,
+    B: Iterator<Item = char>,
+{
+    a.zip(b)
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum()
+}
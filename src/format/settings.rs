@@ -10,6 +10,131 @@ impl Default for IndentationMode {
     }
 }
 
+/// The line ending a formatted source is written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the text of this [`LineEnding`].
+    pub fn text(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// The quotes string literals are written with. Long strings, such as `[[text]]`, are always
+/// kept as written, and the lines of any string spanning lines are never indented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    /// Double quotes, unless single quotes need fewer escapes.
+    #[default]
+    PreferDouble,
+    /// Single quotes, unless double quotes need fewer escapes.
+    PreferSingle,
+    ForceDouble,
+    ForceSingle,
+    /// The quotes of the source.
+    Keep,
+}
+
+/// Where the parentheses around the arguments of a call are left out, which is only possible
+/// for a sole string or table argument, as in `require "module"` or `configure { debug = true }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CallParentheses {
+    /// Around any arguments.
+    #[default]
+    Always,
+    /// Left out wherever possible.
+    Omit,
+    /// Left out for a sole string argument.
+    OmitString,
+    /// Left out for a sole table argument.
+    OmitTable,
+}
+
+/// Whether the last field of a table is followed by a separator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrailingSeparator {
+    Always,
+    Never,
+    /// Only when the table is broken across lines.
+    #[default]
+    Multiline,
+}
+
+/// The separator between the fields of tables and table types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableSeparator {
+    #[default]
+    Comma,
+    Semicolon,
+}
+
+impl TableSeparator {
+    /// Returns the text of this [`TableSeparator`].
+    pub fn text(&self) -> &'static str {
+        match self {
+            Self::Comma => ",",
+            Self::Semicolon => ";",
+        }
+    }
+}
+
+/// The case letters of a number literal are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LetterCase {
+    /// The case of the source.
+    #[default]
+    Keep,
+    Lower,
+    Upper,
+}
+
+/// The underscores separating groups of digits in number literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitSeparators {
+    /// The underscores of the source.
+    #[default]
+    Keep,
+    /// No underscores.
+    Remove,
+    /// Underscores between each group of three decimal digits, or four hexadecimal or binary
+    /// digits, in literals longer than two groups, such as `1_000_000`. The digits after the
+    /// decimal point and in the exponent are written without them.
+    Insert,
+}
+
+/// The zero before a leading decimal point in number literals, as in `0.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeadingZero {
+    /// The zero of the source, if any.
+    #[default]
+    Keep,
+    /// A zero before every leading decimal point.
+    Insert,
+    /// No zero before a decimal point, as in `.5`.
+    Remove,
+}
+
+/// How number literals are written, which never changes their value. The prefixes of hexadecimal
+/// and binary literals are always written as `0x` and `0b`, and everything else is written as in
+/// the source by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NumberFormat {
+    /// The case of the digits of hexadecimal literals.
+    pub hex_digits: LetterCase,
+    /// The case of the `e` of exponents.
+    pub exponent: LetterCase,
+    pub digit_separators: DigitSeparators,
+    pub leading_zero: LeadingZero,
+}
+
 pub struct OperatorSpacing {
     // Types
     pub union: bool,
@@ -24,9 +149,13 @@ pub struct OperatorSpacing {
     pub subtract: bool,
     pub multiply: bool,
     pub divide: bool,
+    pub floor_divide: bool,
     pub modulo: bool,
     pub power: bool,
 
+    // Concatenation
+    pub concat: bool,
+
     // Logical
     pub and: bool,
     pub or: bool,
@@ -42,6 +171,38 @@ pub struct OperatorSpacing {
     pub less_than_or_equal: bool,
 }
 
+impl OperatorSpacing {
+    /// Returns a mutable reference to the spacing of the operator with the given name, which is
+    /// the name of its field, such as `floor_divide`.
+    pub fn get_mut(&mut self, operator: &str) -> Option<&mut bool> {
+        let spacing = match operator {
+            "union" => &mut self.union,
+            "intersection" => &mut self.intersection,
+            "negate" => &mut self.negate,
+            "length" => &mut self.length,
+            "add" => &mut self.add,
+            "subtract" => &mut self.subtract,
+            "multiply" => &mut self.multiply,
+            "divide" => &mut self.divide,
+            "floor_divide" => &mut self.floor_divide,
+            "modulo" => &mut self.modulo,
+            "power" => &mut self.power,
+            "concat" => &mut self.concat,
+            "and" => &mut self.and,
+            "or" => &mut self.or,
+            "equal" => &mut self.equal,
+            "not_equal" => &mut self.not_equal,
+            "less_than" => &mut self.less_than,
+            "greater_than" => &mut self.greater_than,
+            "greater_than_or_equal" => &mut self.greater_than_or_equal,
+            "less_than_or_equal" => &mut self.less_than_or_equal,
+            _ => return None,
+        };
+
+        Some(spacing)
+    }
+}
+
 impl Default for OperatorSpacing {
     fn default() -> Self {
         Self {
@@ -58,9 +219,13 @@ impl Default for OperatorSpacing {
             subtract: true,
             multiply: true,
             divide: true,
+            floor_divide: true,
             modulo: true,
             power: true,
 
+            // Concatenation
+            concat: true,
+
             // Logical
             and: true,
             or: true,
@@ -78,8 +243,36 @@ impl Default for OperatorSpacing {
     }
 }
 
-#[derive(Default)]
 pub struct SourceFormatSettings {
     pub indentation: IndentationMode,
     pub operator_spacing: OperatorSpacing,
+    /// The width lines are kept within where possible, by breaking calls, tables, type
+    /// combinations and operator chains across lines.
+    pub max_line_width: usize,
+    /// The number of consecutive blank lines kept from the source, where longer runs are
+    /// collapsed.
+    pub max_blank_lines: usize,
+    pub line_ending: LineEnding,
+    pub quote_style: QuoteStyle,
+    pub number_format: NumberFormat,
+    pub trailing_separator: TrailingSeparator,
+    pub table_separator: TableSeparator,
+    pub call_parentheses: CallParentheses,
+}
+
+impl Default for SourceFormatSettings {
+    fn default() -> Self {
+        Self {
+            indentation: IndentationMode::default(),
+            operator_spacing: OperatorSpacing::default(),
+            max_line_width: 120,
+            max_blank_lines: 1,
+            line_ending: LineEnding::default(),
+            quote_style: QuoteStyle::default(),
+            number_format: NumberFormat::default(),
+            trailing_separator: TrailingSeparator::default(),
+            table_separator: TableSeparator::default(),
+            call_parentheses: CallParentheses::default(),
+        }
+    }
 }
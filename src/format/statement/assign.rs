@@ -0,0 +1,24 @@
+use crate::ast::Assign;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for Assign {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let targets = Document::join(
+            self.targets()
+                .iter()
+                .map(|target| target.document(settings)),
+            Document::text(", "),
+        );
+
+        let values = Document::join(
+            self.values().iter().map(|value| value.document(settings)),
+            Document::text(", "),
+        );
+
+        SourceItem::document(Document::concat(vec![
+            targets,
+            Document::text(" = "),
+            values,
+        ]))
+    }
+}
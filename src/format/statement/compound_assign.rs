@@ -0,0 +1,12 @@
+use crate::ast::CompoundAssign;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for CompoundAssign {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::document(Document::concat(vec![
+            self.target().document(settings),
+            Document::text(format!(" {} ", self.operator().text())),
+            self.value().document(settings),
+        ]))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::ast::{Break, Continue, Return};
+use crate::format::construct::{fits_inline, format_commented_list, format_inline_comments};
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for Return {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        if self.values().is_empty() {
+            return SourceItem::text("return");
+        }
+
+        let values = self
+            .values()
+            .iter()
+            .map(|value| value.document(settings))
+            .collect::<Vec<_>>();
+
+        // Comments that cannot stay within a line put each value on its own line, after `return`.
+        if !fits_inline(self.trivia(), &[]) {
+            let last = values.len() - 1;
+
+            let values = values
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| match index == last {
+                    true => value,
+                    false => Document::concat(vec![value, Document::text(",")]),
+                })
+                .collect();
+
+            return SourceItem::document(format_commented_list(
+                "return",
+                values,
+                self.trivia(),
+                &[],
+                "",
+                settings,
+            ));
+        }
+
+        let values = Document::join(
+            format_inline_comments(values, self.trivia(), &[], settings),
+            Document::text(", "),
+        );
+
+        SourceItem::document(Document::concat(vec![Document::text("return "), values]))
+    }
+}
+
+impl SourceFormatItem for Break {
+    fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::text("break")
+    }
+}
+
+impl SourceFormatItem for Continue {
+    fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::text("continue")
+    }
+}
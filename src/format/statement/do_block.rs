@@ -0,0 +1,12 @@
+use crate::ast::Do;
+use crate::format::{SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject};
+
+impl SourceFormatItem for Do {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        SourceItem::block(vec![
+            SourceObject::line(SourceItem::text("do")),
+            SourceObject::indented(self.block().format(settings)),
+            SourceObject::line(SourceItem::text("end")),
+        ])
+    }
+}
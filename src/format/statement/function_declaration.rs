@@ -0,0 +1,22 @@
+use crate::ast::{FunctionDeclaration, FunctionName};
+use crate::format::expression::format_function;
+use crate::format::{SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for FunctionName {
+    fn format(&self, _settings: &SourceFormatSettings) -> SourceItem {
+        let path = self.path().join(".");
+
+        match self.method() {
+            Some(method) => SourceItem::Text(format!("{}:{}", path, method)),
+            None => SourceItem::Text(path),
+        }
+    }
+}
+
+impl SourceFormatItem for FunctionDeclaration {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let header = format!("function {}", self.name().format_string(settings));
+
+        format_function(&header, self.function(), settings)
+    }
+}
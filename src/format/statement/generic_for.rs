@@ -0,0 +1,33 @@
+use itertools::Itertools;
+
+use crate::ast::GenericFor;
+use crate::format::{
+    Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject,
+};
+
+impl SourceFormatItem for GenericFor {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let bindings = self
+            .bindings()
+            .iter()
+            .map(|binding| binding.format_string(settings))
+            .join(", ");
+
+        let values = Document::join(
+            self.values().iter().map(|value| value.document(settings)),
+            Document::text(", "),
+        );
+
+        let header = Document::concat(vec![
+            Document::text(format!("for {} in ", bindings)),
+            values,
+            Document::text(" do"),
+        ]);
+
+        SourceItem::block(vec![
+            SourceObject::line(SourceItem::document(header)),
+            SourceObject::indented(self.block().format(settings)),
+            SourceObject::line(SourceItem::text("end")),
+        ])
+    }
+}
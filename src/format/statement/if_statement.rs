@@ -0,0 +1,45 @@
+use crate::ast::If;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject};
+
+impl SourceFormatItem for If {
+    /// Formats the statement with every branch on its own line and its block indented.
+    /// ```
+    /// use luna::ast::{Block, Identifier, If, Return};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let statement = If::simple(Identifier::new("ready"), Block::new(vec![Return::empty().into()]));
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(statement.format_string(&settings), "if ready then\n    return\nend");
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let mut objects = vec![
+            SourceObject::line(SourceItem::document(Document::concat(vec![
+                Document::text("if "),
+                self.condition().document(settings),
+                Document::text(" then"),
+            ]))),
+            SourceObject::indented(self.block().format(settings)),
+        ];
+
+        for else_if in self.else_ifs() {
+            objects.push(SourceObject::line(SourceItem::document(Document::concat(
+                vec![
+                    Document::text("elseif "),
+                    else_if.condition().document(settings),
+                    Document::text(" then"),
+                ],
+            ))));
+            objects.push(SourceObject::indented(else_if.block().format(settings)));
+        }
+
+        if let Some(block) = self.else_block() {
+            objects.push(SourceObject::line(SourceItem::text("else")));
+            objects.push(SourceObject::indented(block.format(settings)));
+        }
+
+        objects.push(SourceObject::line(SourceItem::text("end")));
+
+        SourceItem::block(objects)
+    }
+}
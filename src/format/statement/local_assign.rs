@@ -0,0 +1,27 @@
+use itertools::Itertools;
+
+use crate::ast::LocalAssign;
+use crate::format::{Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for LocalAssign {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let bindings = self
+            .bindings()
+            .iter()
+            .map(|binding| binding.format_string(settings))
+            .join(", ");
+
+        let values = Document::join(
+            self.values().iter().map(|value| value.document(settings)),
+            Document::text(", "),
+        );
+
+        match self.values().is_empty() {
+            true => SourceItem::Text(format!("local {}", bindings)),
+            false => SourceItem::document(Document::concat(vec![
+                Document::text(format!("local {} = ", bindings)),
+                values,
+            ])),
+        }
+    }
+}
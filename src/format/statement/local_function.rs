@@ -0,0 +1,11 @@
+use crate::ast::LocalFunction;
+use crate::format::expression::format_function;
+use crate::format::{SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for LocalFunction {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let header = format!("local function {}", self.name());
+
+        format_function(&header, self.function(), settings)
+    }
+}
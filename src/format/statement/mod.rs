@@ -0,0 +1,39 @@
+mod assign;
+mod compound_assign;
+mod control_flow;
+mod do_block;
+mod function_declaration;
+mod generic_for;
+mod if_statement;
+mod local_assign;
+mod local_function;
+mod numeric_for;
+mod repeat_loop;
+mod type_declaration;
+mod while_loop;
+
+use crate::ast::Statement;
+use crate::format::{SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for Statement {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        match self {
+            Self::LocalAssign(a) => a.format(settings),
+            Self::Assign(a) => a.format(settings),
+            Self::CompoundAssign(a) => a.format(settings),
+            Self::FunctionCall(a) => a.format(settings),
+            Self::Do(a) => a.format(settings),
+            Self::While(a) => a.format(settings),
+            Self::Repeat(a) => a.format(settings),
+            Self::If(a) => a.format(settings),
+            Self::NumericFor(a) => a.format(settings),
+            Self::GenericFor(a) => a.format(settings),
+            Self::FunctionDeclaration(a) => a.format(settings),
+            Self::LocalFunction(a) => a.format(settings),
+            Self::Return(a) => a.format(settings),
+            Self::Break(a) => a.format(settings),
+            Self::Continue(a) => a.format(settings),
+            Self::TypeDeclaration(a) => a.format(settings),
+        }
+    }
+}
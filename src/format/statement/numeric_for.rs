@@ -0,0 +1,28 @@
+use crate::ast::NumericFor;
+use crate::format::{
+    Document, SourceFormat, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject,
+};
+
+impl SourceFormatItem for NumericFor {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let mut header = vec![
+            Document::text(format!("for {} = ", self.binding().format_string(settings))),
+            self.start().document(settings),
+            Document::text(", "),
+            self.end().document(settings),
+        ];
+
+        if let Some(step) = self.step() {
+            header.push(Document::text(", "));
+            header.push(step.document(settings));
+        }
+
+        header.push(Document::text(" do"));
+
+        SourceItem::block(vec![
+            SourceObject::line(SourceItem::document(Document::concat(header))),
+            SourceObject::indented(self.block().format(settings)),
+            SourceObject::line(SourceItem::text("end")),
+        ])
+    }
+}
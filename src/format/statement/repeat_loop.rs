@@ -0,0 +1,17 @@
+use crate::ast::Repeat;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject};
+
+impl SourceFormatItem for Repeat {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let footer = Document::concat(vec![
+            Document::text("until "),
+            self.condition().document(settings),
+        ]);
+
+        SourceItem::block(vec![
+            SourceObject::line(SourceItem::text("repeat")),
+            SourceObject::indented(self.block().format(settings)),
+            SourceObject::line(SourceItem::document(footer)),
+        ])
+    }
+}
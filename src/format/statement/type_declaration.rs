@@ -0,0 +1,32 @@
+use crate::ast::TypeDeclaration;
+use crate::format::annotation::format_generics;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem};
+
+impl SourceFormatItem for TypeDeclaration {
+    /// Formats the declaration as `type Name<T> = ...`, prefixed with `export` when exported.
+    /// ```
+    /// use luna::ast::{TypeDeclaration, TypeGeneric, TypePrimitive, TypeOptional};
+    /// use luna::format::{SourceFormat, SourceFormatSettings};
+    ///
+    /// let declaration = TypeDeclaration::export(
+    ///     "Maybe",
+    ///     vec![TypeGeneric::defaulted("T", TypePrimitive::string()).into()],
+    ///     TypeOptional::new(TypePrimitive::Number),
+    /// );
+    ///
+    /// let settings = SourceFormatSettings::default();
+    /// assert_eq!(declaration.format_string(&settings), "export type Maybe<T = string> = number?");
+    /// ```
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let export = match self.is_exported() {
+            true => "export ",
+            false => "",
+        };
+
+        let generics = format_generics(self.generics(), settings);
+        SourceItem::document(Document::concat(vec![
+            Document::text(format!("{}type {}{} = ", export, self.name(), generics)),
+            self.annotation().document(settings),
+        ]))
+    }
+}
@@ -0,0 +1,18 @@
+use crate::ast::While;
+use crate::format::{Document, SourceFormatItem, SourceFormatSettings, SourceItem, SourceObject};
+
+impl SourceFormatItem for While {
+    fn format(&self, settings: &SourceFormatSettings) -> SourceItem {
+        let header = Document::concat(vec![
+            Document::text("while "),
+            self.condition().document(settings),
+            Document::text(" do"),
+        ]);
+
+        SourceItem::block(vec![
+            SourceObject::line(SourceItem::document(header)),
+            SourceObject::indented(self.block().format(settings)),
+            SourceObject::line(SourceItem::text("end")),
+        ])
+    }
+}
@@ -0,0 +1,27 @@
+/// The reserved keywords of Luau, which can never be used as identifiers.
+/// Note that `continue`, `type`, `export` and `typeof` are contextual, and are not included.
+pub const KEYWORDS: [&str; 21] = [
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "if", "in", "local",
+    "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+/// Returns whether the given text is a valid Luau identifier.
+/// ```
+/// use luna::lexer::is_identifier;
+///
+/// assert!(is_identifier("foo_bar1"));
+/// assert!(!is_identifier("my-key"));
+/// assert!(!is_identifier("1st"));
+/// assert!(!is_identifier("end"));
+/// ```
+pub fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+
+    let starts_correctly = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+
+    starts_correctly
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&text)
+}
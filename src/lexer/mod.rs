@@ -1 +1,4 @@
+pub mod identifier;
 pub mod token;
+
+pub use identifier::{is_identifier, KEYWORDS};
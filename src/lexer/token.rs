@@ -1,35 +1,169 @@
-use logos::{Logos, Lexer};
+use std::ops::Range;
+
+use logos::{Lexer, Logos};
+
+/// Consumes the rest of an interpolated string, including the expressions between its braces, up
+/// to the closing backtick.
+fn template_literal(lex: &mut Lexer<Token>) -> bool {
+    match template_parts(lex.remainder()) {
+        Some((length, _)) => {
+            lex.bump(length);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Scans the rest of an interpolated string after its opening backtick, returning its length up to
+/// and including the closing backtick, along with the ranges of the expressions between its
+/// braces, relative to the given text.
+pub(crate) fn template_parts(text: &str) -> Option<(usize, Vec<Range<usize>>)> {
+    let mut expressions = Vec::new();
+    let mut position = 0;
+
+    while let Some(character) = text[position..].chars().next() {
+        position += character.len_utf8();
+
+        match character {
+            // `\z` skips the whitespace after it, line breaks included.
+            '\\' if text[position..].starts_with('z') => {
+                let rest = &text[position + 1..];
+                position = text.len() - rest.trim_start().len();
+            }
+            '\\' => {
+                position += text[position..].chars().next()?.len_utf8();
+            }
+            '\n' => return None,
+            '`' => return Some((position, expressions)),
+            '{' => {
+                // The expression ends at the brace closing it, which the tokens of the
+                // expression tell apart from those within its strings and tables.
+                let mut depth = 0usize;
+                let mut tokens = Token::lexer(&text[position..]).spanned();
+
+                let end = loop {
+                    match tokens.next()? {
+                        (Ok(Token::LeftCurlyBracket), _) => depth += 1,
+                        (Ok(Token::RightCurlyBracket), span) if depth == 0 => {
+                            break position + span.start;
+                        }
+                        (Ok(Token::RightCurlyBracket), _) => depth -= 1,
+                        (Ok(_), _) => {}
+                        (Err(_), _) => return None,
+                    }
+                };
+
+                expressions.push(position..end);
+                position = end + 1;
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Consumes the rest of a long bracket, such as `[==[ ... ]==]`, given the length of its opening.
+fn long_bracket(lex: &mut Lexer<Token>, opening: usize) -> bool {
+    let level = opening - 2;
+    let closing = format!("]{}]", "=".repeat(level));
+
+    match lex.remainder().find(closing.as_str()) {
+        Some(index) => {
+            lex.bump(index + closing.len());
+            true
+        }
+        None => false,
+    }
+}
+
+fn long_string(lex: &mut Lexer<Token>) -> bool {
+    let opening = lex.slice().len();
+    long_bracket(lex, opening)
+}
+
+/// Consumes a comment, which is either a long bracket or runs to the end of the line.
+fn comment(lex: &mut Lexer<Token>) -> bool {
+    let remainder = lex.remainder();
+    let level = remainder
+        .strip_prefix('[')
+        .map(|rest| rest.len() - rest.trim_start_matches('=').len());
+
+    if let Some(level) = level {
+        if remainder[level + 1..].starts_with('[') {
+            lex.bump(level + 2);
+            return long_bracket(lex, level + 2);
+        }
+    }
 
-fn template_literal<'a>(lex: &mut Lexer<Token>) -> () {
-    
+    let end = remainder.find('\n').unwrap_or(remainder.len());
+    lex.bump(end);
+
+    true
 }
 
-#[derive(Logos, Debug, PartialEq)]
-#[logos(skip r"[ \t\n]")]
+#[derive(Logos, Debug, Clone, Copy, PartialEq, Eq)]
+#[logos(skip r"[ \t\r\n\f]+")]
 pub enum Token {
     #[regex(r"\d[_\d]*")] // Integer
     #[regex(r"\.\d[_\d]*")] // Decimal
     #[regex(r"\d[_\d]*\.[_\d]*")] // Float
-    #[regex(r"\d[_\d]*\.[_\d]*[eE][\-\+]?[_\d]*\d")] // Scientific notation
-    #[regex(r"0_*[xX][_\da-fA-F]*[\da-fA-F][_\da-fA-F]*")] // Hexadecimal
-    #[regex(r"0_*[bB][_01]*[01][_01]*")] // Binary
+    #[regex(r"\d[_\d]*\.[_\d]*[eE][\-\+]?[_\d]+")] // Scientific notation
+    #[regex(r"\d[_\d]*[eE][\-\+]?[_\d]+")] // Scientific notation without a fraction
+    #[regex(r"\.\d[_\d]*[eE][\-\+]?[_\d]+")] // Scientific notation without an integer
+    #[regex(r"0_*[xX][_\da-fA-F]+")] // Hexadecimal
+    #[regex(r"0_*[bB][_01]+")] // Binary
     NumberLiteral,
 
-    #[regex(r#""([^"\\]|\\.)*""#)]
+    #[regex(r#""([^"\\\n]|\\z[ \t\r\n\f\x0B]*|\\(.|\n))*""#)]
+    #[regex(r#"'([^'\\\n]|\\z[ \t\r\n\f\x0B]*|\\(.|\n))*'"#)]
+    #[regex(r"\[=*\[", long_string)]
     StringLiteral,
 
     #[token("`", template_literal)]
     TemplateLiteral,
 
+    /// The start of an interpolated string up to its first expression, such as `` `a{ ``, which
+    /// the parser splits a [`TemplateLiteral`](Token::TemplateLiteral) with expressions into.
+    InterpolatedStringBegin,
+    /// The text of an interpolated string between two expressions, such as `}b{`.
+    InterpolatedStringMiddle,
+    /// The end of an interpolated string after its last expression, such as `` }c` ``.
+    InterpolatedStringEnd,
+
+    #[regex(r"[a-zA-Z_][a-zA-Z0-9_]*")]
+    Identifier,
+
+    #[token("--", comment)]
+    Comment,
+
     #[token("if")]
     If,
 
+    #[token("then")]
+    Then,
+
+    #[token("elseif")]
+    ElseIf,
+
     #[token("else")]
     Else,
 
+    #[token("end")]
+    End,
+
+    #[token("do")]
+    Do,
+
     #[token("while")]
     While,
 
+    #[token("repeat")]
+    Repeat,
+
+    #[token("until")]
+    Until,
+
     #[token("for")]
     For,
 
@@ -81,6 +215,9 @@ pub enum Token {
     #[token("/")]
     Slash,
 
+    #[token("//")]
+    DoubleSlash,
+
     #[token("%")]
     Percent,
 
@@ -90,6 +227,33 @@ pub enum Token {
     #[token("#")]
     Hash,
 
+    #[token("..")]
+    DoubleDot,
+
+    #[token("+=")]
+    PlusEquals,
+
+    #[token("-=")]
+    MinusEquals,
+
+    #[token("*=")]
+    AsteriskEquals,
+
+    #[token("/=")]
+    SlashEquals,
+
+    #[token("//=")]
+    DoubleSlashEquals,
+
+    #[token("%=")]
+    PercentEquals,
+
+    #[token("^=")]
+    CaretEquals,
+
+    #[token("..=")]
+    DoubleDotEquals,
+
     #[token("==")]
     DoubleEquals,
 
@@ -138,9 +302,24 @@ pub enum Token {
     #[token(":")]
     Colon,
 
+    #[token("::")]
+    DoubleColon,
+
+    #[token("->")]
+    Arrow,
+
+    #[token("?")]
+    QuestionMark,
+
+    #[token("|")]
+    Pipe,
+
+    #[token("&")]
+    Ampersand,
+
     #[token(";")]
     Semicolon,
 
     #[token("...")]
     Ellipsis, // BusyCityGuy reference
-}
\ No newline at end of file
+}
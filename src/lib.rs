@@ -1,4 +1,10 @@
+pub mod analysis;
 pub mod ast;
+pub mod edit;
 pub mod evaluation;
+pub mod fold;
 pub mod format;
 pub mod lexer;
+pub mod parser;
+pub mod types;
+pub mod visit;
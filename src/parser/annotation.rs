@@ -0,0 +1,422 @@
+use crate::ast::{
+    TypeAnnotation, TypeArgument, TypeArray, TypeBuiltIn, TypeCombination, TypeFunction,
+    TypeGeneric, TypeGenericPack, TypeGenericParameter, TypeOperator, TypeOptional, TypePack,
+    TypePackTail, TypePrimitive, TypeReference, TypeReferenceArgument, TypeSingleton, TypeTable,
+    TypeTableAccess, TypeTableEntry, TypeTableIndexer,
+};
+use crate::lexer::token::Token;
+use crate::parser::{ParseError, Parser};
+
+/// The contents of a parenthesized list of types, which is either a type pack or the arguments of a function type.
+struct TypeList {
+    arguments: Vec<TypeArgument>,
+    tail: Option<TypePackTail>,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn annotation(&mut self) -> Result<TypeAnnotation, ParseError> {
+        // A leading operator is allowed, such as in `type Direction = | "up" | "down"`.
+        if matches!(self.peek(), Some(Token::Pipe) | Some(Token::Ampersand)) {
+            self.advance();
+        }
+
+        let mut annotation = self.optional_annotation()?;
+
+        loop {
+            let operator = match self.peek() {
+                Some(Token::Pipe) => TypeOperator::Union,
+                Some(Token::Ampersand) => TypeOperator::Intersection,
+                _ => return Ok(annotation),
+            };
+
+            self.advance();
+            let right = self.optional_annotation()?;
+            annotation = TypeCombination::new(operator, annotation, right).into();
+        }
+    }
+
+    fn optional_annotation(&mut self) -> Result<TypeAnnotation, ParseError> {
+        let mut annotation = self.simple_annotation()?;
+
+        while self.eat(Token::QuestionMark) {
+            annotation = TypeOptional::new(annotation).into();
+        }
+
+        Ok(annotation)
+    }
+
+    fn simple_annotation(&mut self) -> Result<TypeAnnotation, ParseError> {
+        match self.peek() {
+            Some(Token::Nil) => {
+                self.advance();
+                Ok(TypePrimitive::Nil.into())
+            }
+            Some(Token::True) => {
+                self.advance();
+                Ok(TypeSingleton::Boolean(true).into())
+            }
+            Some(Token::False) => {
+                self.advance();
+                Ok(TypeSingleton::Boolean(false).into())
+            }
+            Some(Token::StringLiteral) => {
+                let text = self.text_at(0).to_string();
+                self.advance();
+
+                Ok(TypeSingleton::String(text).into())
+            }
+            Some(Token::LeftCurlyBracket) => self.table_annotation(),
+            Some(Token::Function) => {
+                Err(self.error("expected a type, function types are written `(...) -> ...`"))
+            }
+            Some(Token::RightAngleBracket) => {
+                let generics = self.generic_parameters()?;
+                let list = self.type_list()?;
+
+                self.function_annotation(generics, list)
+            }
+            Some(Token::LeftParenthesis) => {
+                let list = self.type_list()?;
+
+                if self.check(Token::Arrow) {
+                    return self.function_annotation(Vec::new(), list);
+                }
+
+                match (list.arguments.len(), &list.tail) {
+                    (1, None) if list.arguments[0].name().is_none() => {
+                        Ok(list.arguments.into_iter().next().unwrap().into_parts().1)
+                    }
+                    _ => Err(self.error("expected `->` after a list of types")),
+                }
+            }
+            Some(Token::Identifier) if self.text_at(0) == "typeof" => {
+                self.advance();
+                self.expect(Token::LeftParenthesis, "`(` after `typeof`")?;
+                let expression = self.expression()?;
+                self.expect(Token::RightParenthesis, "`)` to close `typeof`")?;
+
+                Ok(TypeAnnotation::typeof_expression(expression))
+            }
+            Some(Token::Identifier) => self.reference_annotation(),
+            _ => Err(self.error("expected a type")),
+        }
+    }
+
+    fn reference_annotation(&mut self) -> Result<TypeAnnotation, ParseError> {
+        let (mut name, _) = self.name()?;
+
+        let module = match self.eat(Token::Dot) {
+            true => Some(std::mem::replace(&mut name, self.name()?.0)),
+            false => None,
+        };
+
+        if self.eat(Token::RightAngleBracket) {
+            let mut arguments = Vec::new();
+
+            while !self.check(Token::LeftAngleBracket) {
+                arguments.push(self.reference_argument()?);
+
+                if !self.eat(Token::Comma) {
+                    break;
+                }
+            }
+
+            self.expect(Token::LeftAngleBracket, "`>` to close the type arguments")?;
+
+            return Ok(TypeReference::new(module, name, arguments).into());
+        }
+
+        if module.is_some() {
+            return Ok(TypeReference::new(module, name, Vec::new()).into());
+        }
+
+        Ok(match name.as_str() {
+            "any" => TypeBuiltIn::Any.into(),
+            "unknown" => TypeBuiltIn::Unknown.into(),
+            "never" => TypeBuiltIn::Never.into(),
+            "boolean" => TypePrimitive::Boolean.into(),
+            "number" => TypePrimitive::Number.into(),
+            "string" => TypePrimitive::String.into(),
+            "thread" => TypePrimitive::Thread.into(),
+            "userdata" => TypePrimitive::Userdata.into(),
+            "buffer" => TypePrimitive::Buffer.into(),
+            "vector" => TypePrimitive::Vector.into(),
+            _ => TypeReference::named(name).into(),
+        })
+    }
+
+    fn reference_argument(&mut self) -> Result<TypeReferenceArgument, ParseError> {
+        if let Some(tail) = self.pack_tail()? {
+            return Ok(TypePack::tail(tail).into());
+        }
+
+        if !self.check(Token::LeftParenthesis) {
+            return Ok(self.annotation()?.into());
+        }
+
+        // A parenthesized list is a type pack, unless it is the arguments of a function type.
+        let list = self.type_list()?;
+
+        match self.check(Token::Arrow) {
+            true => Ok(self.function_annotation(Vec::new(), list)?.into()),
+            false => Ok(list.into_pack().into()),
+        }
+    }
+
+    /// Parses a type pack tail, such as `...number` or `T...`, if there is one.
+    fn pack_tail(&mut self) -> Result<Option<TypePackTail>, ParseError> {
+        if self.eat(Token::Ellipsis) {
+            return Ok(Some(TypePackTail::variadic(self.annotation()?)));
+        }
+
+        if self.check(Token::Identifier) && self.peek_at(1) == Some(Token::Ellipsis) {
+            let (name, _) = self.name()?;
+            self.advance();
+
+            return Ok(Some(TypePackTail::generic(name)));
+        }
+
+        Ok(None)
+    }
+
+    /// Parses a parenthesized list of types, which may be named as the arguments of a function type.
+    fn type_list(&mut self) -> Result<TypeList, ParseError> {
+        self.expect(Token::LeftParenthesis, "`(`")?;
+
+        let mut arguments = Vec::new();
+        let mut tail = None;
+
+        while !self.check(Token::RightParenthesis) {
+            if let Some(pack_tail) = self.pack_tail()? {
+                tail = Some(pack_tail);
+                break;
+            }
+
+            let name = match self.check(Token::Identifier) && self.peek_at(1) == Some(Token::Colon)
+            {
+                true => {
+                    let (name, _) = self.name()?;
+                    self.advance();
+
+                    Some(name)
+                }
+                false => None,
+            };
+
+            arguments.push(TypeArgument::new(name, self.annotation()?));
+
+            if !self.eat(Token::Comma) {
+                break;
+            }
+        }
+
+        self.expect(Token::RightParenthesis, "`)` to close the list of types")?;
+
+        Ok(TypeList { arguments, tail })
+    }
+
+    fn function_annotation(
+        &mut self,
+        generics: Vec<TypeGenericParameter>,
+        list: TypeList,
+    ) -> Result<TypeAnnotation, ParseError> {
+        self.expect(Token::Arrow, "`->`")?;
+        let result = self.return_annotation()?;
+
+        Ok(TypeFunction::new(generics, list.arguments, list.tail, result).into())
+    }
+
+    /// Parses the return type of a function, such as `number`, `(number, string)` or `...number`.
+    pub(crate) fn return_annotation(&mut self) -> Result<TypePack, ParseError> {
+        if let Some(tail) = self.pack_tail()? {
+            return Ok(TypePack::tail(tail));
+        }
+
+        if !self.check(Token::LeftParenthesis) {
+            return Ok(TypePack::list(vec![self.annotation()?]));
+        }
+
+        let list = self.type_list()?;
+
+        // A function type returning a function type, such as `() -> (number) -> string`.
+        if self.check(Token::Arrow) {
+            return Ok(TypePack::list(vec![
+                self.function_annotation(Vec::new(), list)?
+            ]));
+        }
+
+        Ok(list.into_pack())
+    }
+
+    fn table_annotation(&mut self) -> Result<TypeAnnotation, ParseError> {
+        self.expect(Token::LeftCurlyBracket, "`{`")?;
+
+        if self.eat(Token::RightCurlyBracket) {
+            return Ok(TypeTable::new(Vec::new(), None).into());
+        }
+
+        // A table with a single unnamed type is an array, such as `{ number }`.
+        let is_entry = self.check(Token::LeftSquareBracket)
+            || (self.check(Token::Identifier)
+                && matches!(
+                    self.peek_at(1),
+                    Some(Token::Colon) | Some(Token::Identifier)
+                ));
+
+        if !is_entry {
+            let item = self.annotation()?;
+            self.expect(Token::RightCurlyBracket, "`}` to close the array type")?;
+
+            return Ok(TypeArray::new(Some(Box::new(item))).into());
+        }
+
+        let mut entries = Vec::new();
+        let mut indexer = None;
+
+        // Names within the indexer are counted after those of the entries, the order they are visited in.
+        let mut indexer_names = Vec::new();
+
+        while !self.check(Token::RightCurlyBracket) {
+            let access = match self.text_at(0) {
+                "read" if self.peek_at(1) != Some(Token::Colon) => TypeTableAccess::Read,
+                "write" if self.peek_at(1) != Some(Token::Colon) => TypeTableAccess::Write,
+                _ => TypeTableAccess::ReadWrite,
+            };
+
+            if access != TypeTableAccess::ReadWrite {
+                self.advance();
+            }
+
+            if self.eat(Token::LeftSquareBracket) {
+                match self.peek() {
+                    Some(Token::StringLiteral)
+                        if self.peek_at(1) == Some(Token::RightSquareBracket) =>
+                    {
+                        let key = unquote(self.text_at(0));
+                        self.advance();
+                        self.advance();
+                        self.expect(Token::Colon, "`:`")?;
+
+                        entries.push(TypeTableEntry::with_access(access, key, self.annotation()?));
+                    }
+                    _ => {
+                        let names = self.names.len();
+
+                        let index = self.annotation()?;
+                        self.expect(Token::RightSquareBracket, "`]`")?;
+                        self.expect(Token::Colon, "`:`")?;
+                        let value = self.annotation()?;
+
+                        indexer_names = self.names.split_off(names);
+                        indexer = Some(TypeTableIndexer::new(index, value));
+                    }
+                }
+            } else {
+                let (name, _) = self.name()?;
+                self.expect(Token::Colon, "`:`")?;
+
+                entries.push(TypeTableEntry::with_access(
+                    access,
+                    name,
+                    self.annotation()?,
+                ));
+            }
+
+            if !self.eat(Token::Comma) && !self.eat(Token::Semicolon) {
+                break;
+            }
+        }
+
+        self.expect(Token::RightCurlyBracket, "`}` to close the table type")?;
+        self.names.append(&mut indexer_names);
+
+        Ok(TypeTable::new(entries, indexer).into())
+    }
+
+    /// Parses the generic parameters of a declaration, such as `<T, U = string, V...>`, if there are any.
+    pub(crate) fn generic_parameters(&mut self) -> Result<Vec<TypeGenericParameter>, ParseError> {
+        let mut generics = Vec::new();
+
+        if !self.eat(Token::RightAngleBracket) {
+            return Ok(generics);
+        }
+
+        while !self.check(Token::LeftAngleBracket) {
+            let (name, _) = self.name()?;
+
+            let generic = match self.eat(Token::Ellipsis) {
+                true => {
+                    let default = match self.eat(Token::Equals) {
+                        true => Some(self.generic_pack_default()?),
+                        false => None,
+                    };
+
+                    TypeGenericPack::new(name, default).into()
+                }
+                false => {
+                    let default = match self.eat(Token::Equals) {
+                        true => Some(self.annotation()?),
+                        false => None,
+                    };
+
+                    TypeGeneric::new(name, default).into()
+                }
+            };
+
+            generics.push(generic);
+
+            if !self.eat(Token::Comma) {
+                break;
+            }
+        }
+
+        self.expect(Token::LeftAngleBracket, "`>` to close the generics")?;
+
+        Ok(generics)
+    }
+
+    fn generic_pack_default(&mut self) -> Result<TypePack, ParseError> {
+        if let Some(tail) = self.pack_tail()? {
+            return Ok(TypePack::tail(tail));
+        }
+
+        let list = self.type_list()?;
+        Ok(list.into_pack())
+    }
+}
+
+impl TypeList {
+    fn into_pack(self) -> TypePack {
+        let types = self
+            .arguments
+            .into_iter()
+            .map(|argument| argument.into_parts().1)
+            .collect();
+
+        TypePack::new(types, self.tail)
+    }
+}
+
+/// Returns the contents of a quoted string, with its escape sequences resolved.
+fn unquote(text: &str) -> String {
+    let inner = &text[1..text.len() - 1];
+    let mut result = String::with_capacity(inner.len());
+    let mut characters = inner.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            result.push(character);
+            continue;
+        }
+
+        match characters.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+
+    result
+}
@@ -0,0 +1,230 @@
+use crate::ast::{
+    TypeAnnotation, TypeArgument, TypeDeclaration, TypeFunction, TypePack, TypeReference,
+    TypeTableEntry, TypeTableIndexer,
+};
+use crate::lexer::token::Token;
+use crate::parser::{ParseError, Parser};
+
+/// A declaration of a type definition file (`.d.luau`), describing the globals and types a host
+/// provides.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Declaration {
+    /// A global value, such as `declare game: DataModel`.
+    Global(String, TypeAnnotation),
+    /// A global function, such as `declare function wait(seconds: number): number`.
+    Function(String, TypeFunction),
+    /// A class, such as `declare class Part extends Instance ... end`.
+    Class(Box<ClassDeclaration>),
+    /// A type alias, such as `type Callback = () -> ()`.
+    Type(TypeDeclaration),
+}
+
+/// A class of a type definition file, with its properties, methods and optional superclass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassDeclaration {
+    name: String,
+    superclass: Option<String>,
+    properties: Vec<TypeTableEntry>,
+    indexer: Option<TypeTableIndexer>,
+}
+
+impl ClassDeclaration {
+    /// Constructs a new [`ClassDeclaration`] with the given name, optional superclass, properties
+    /// and optional indexer. Methods are properties whose type is a function taking `self` first.
+    pub fn new<T: AsRef<str>>(
+        name: T,
+        superclass: Option<String>,
+        properties: Vec<TypeTableEntry>,
+        indexer: Option<TypeTableIndexer>,
+    ) -> Self {
+        Self {
+            name: name.as_ref().to_string(),
+            superclass,
+            properties,
+            indexer,
+        }
+    }
+
+    /// Returns the name of this [`ClassDeclaration`].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the name of the class this [`ClassDeclaration`] extends, if it extends one.
+    pub fn superclass(&self) -> Option<&str> {
+        self.superclass.as_deref()
+    }
+
+    /// Returns the properties and methods of this [`ClassDeclaration`].
+    pub fn properties(&self) -> &Vec<TypeTableEntry> {
+        &self.properties
+    }
+
+    /// Returns the indexer of this [`ClassDeclaration`], if it has one.
+    pub fn indexer(&self) -> Option<&TypeTableIndexer> {
+        self.indexer.as_ref()
+    }
+}
+
+/// Parses the given source as a type definition file, made of `declare` statements and type
+/// aliases.
+/// ```
+/// use luna::parser::{parse_declarations, Declaration};
+///
+/// let declarations = parse_declarations(
+///     "declare function wait(seconds: number?): number
+///     declare class Part extends Instance
+///         Size: Vector3
+///         function Destroy(self): ()
+///     end
+///     declare workspace: Instance",
+/// )
+/// .unwrap();
+///
+/// assert!(matches!(&declarations[0], Declaration::Function(name, _) if name == "wait"));
+/// assert!(matches!(&declarations[1], Declaration::Class(class) if class.superclass() == Some("Instance")));
+/// assert!(matches!(&declarations[2], Declaration::Global(name, _) if name == "workspace"));
+/// ```
+pub fn parse_declarations(source: &str) -> Result<Vec<Declaration>, ParseError> {
+    let mut parser = Parser::new(source)?;
+    let mut declarations = Vec::new();
+
+    loop {
+        while parser.eat(Token::Semicolon) {}
+
+        if parser.peek().is_none() {
+            return Ok(declarations);
+        }
+
+        declarations.push(parser.declaration()?);
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn declaration(&mut self) -> Result<Declaration, ParseError> {
+        if self.check_contextual("export") && self.peek_at(1) == Some(Token::Identifier) {
+            self.advance();
+
+            return match self.check_contextual("type") {
+                true => Ok(Declaration::Type(self.type_declaration(true)?)),
+                false => Err(self.error("expected `type` after `export`")),
+            };
+        }
+
+        if self.check_contextual("type") {
+            return Ok(Declaration::Type(self.type_declaration(false)?));
+        }
+
+        if !self.check_contextual("declare") {
+            return Err(self.error("expected `declare` or a type alias"));
+        }
+
+        self.advance();
+
+        if self.eat(Token::Function) {
+            let (name, _) = self.name()?;
+            let function = self.function_signature(None)?;
+
+            return Ok(Declaration::Function(name, function));
+        }
+
+        if self.check_contextual("class") && self.peek_at(1) == Some(Token::Identifier) {
+            self.advance();
+            return Ok(Declaration::Class(Box::new(self.class_declaration()?)));
+        }
+
+        let (name, _) = self.name()?;
+        self.expect(Token::Colon, "`:` after the name of the global")?;
+
+        Ok(Declaration::Global(name, self.annotation()?))
+    }
+
+    fn class_declaration(&mut self) -> Result<ClassDeclaration, ParseError> {
+        let (name, _) = self.name()?;
+
+        let superclass = match self.check_contextual("extends") {
+            true => {
+                self.advance();
+                Some(self.name()?.0)
+            }
+            false => None,
+        };
+
+        let mut properties = Vec::new();
+        let mut indexer = None;
+
+        while !self.eat(Token::End) {
+            if self.peek().is_none() {
+                return Err(self.error("expected `end` to close the class"));
+            }
+
+            if self.eat(Token::Function) {
+                let (method, _) = self.name()?;
+                let function = self.function_signature(Some(&name))?;
+
+                properties.push(TypeTableEntry::new(method, function.into()));
+            } else if self.eat(Token::LeftSquareBracket) {
+                let index = self.annotation()?;
+                self.expect(Token::RightSquareBracket, "`]`")?;
+                self.expect(Token::Colon, "`:`")?;
+
+                indexer = Some(TypeTableIndexer::new(index, self.annotation()?));
+            } else {
+                let (property, _) = self.name()?;
+                self.expect(Token::Colon, "`:` after the name of the property")?;
+
+                properties.push(TypeTableEntry::new(property, self.annotation()?));
+            }
+
+            while self.eat(Token::Comma) || self.eat(Token::Semicolon) {}
+        }
+
+        Ok(ClassDeclaration::new(name, superclass, properties, indexer))
+    }
+
+    /// Parses the generics, parameters and return type of a declared function, which has no body.
+    ///
+    /// Within a class, the `self` parameter may omit its type, which is then the class.
+    fn function_signature(&mut self, class: Option<&str>) -> Result<TypeFunction, ParseError> {
+        let generics = self.generic_parameters()?;
+        self.expect(Token::LeftParenthesis, "`(` to open the parameters")?;
+
+        let mut arguments = Vec::new();
+        let mut variadic = None;
+
+        while !self.check(Token::RightParenthesis) {
+            if self.eat(Token::Ellipsis) {
+                self.expect(Token::Colon, "`:` after `...`")?;
+                variadic = Some(self.variadic_annotation()?);
+                break;
+            }
+
+            let (name, _) = self.name()?;
+
+            let annotation = match (self.eat(Token::Colon), class) {
+                (true, _) => self.annotation()?,
+                (false, Some(class)) if name == "self" && arguments.is_empty() => {
+                    TypeReference::named(class).into()
+                }
+                (false, _) => {
+                    return Err(self.error("expected `:` after the name of the parameter"))
+                }
+            };
+
+            arguments.push(TypeArgument::named(name, annotation));
+
+            if !self.eat(Token::Comma) {
+                break;
+            }
+        }
+
+        self.expect(Token::RightParenthesis, "`)` to close the parameters")?;
+
+        let result = match self.eat(Token::Colon) {
+            true => self.return_annotation()?,
+            false => TypePack::empty(),
+        };
+
+        Ok(TypeFunction::new(generics, arguments, variadic, result))
+    }
+}
@@ -0,0 +1,471 @@
+use crate::ast::{
+    BinaryOperation, BinaryOperator, BooleanLiteral, Comment, Expression, Function, FunctionCall,
+    FunctionVariadic, Identifier, IfExpression, Index, InterpolatedString, NilLiteral,
+    NumberLiteral, Parenthesized, StringLiteral, TableConstructor, TableField, Trivia,
+    TypeAssertion, TypePackTail, UnaryOperation, UnaryOperator, VarargLiteral,
+};
+use crate::lexer::token::Token;
+use crate::parser::{ParseError, Parser};
+
+/// The priority of unary operators, which bind tighter than every binary operator but `^`.
+const UNARY_PRIORITY: u8 = 8;
+
+/// Returns the left and right priorities of a binary operator, where a higher priority binds tighter.
+///
+/// A right priority lower than the left priority makes the operator right associative.
+fn binary_priority(operator: BinaryOperator) -> (u8, u8) {
+    match operator {
+        BinaryOperator::Or => (1, 1),
+        BinaryOperator::And => (2, 2),
+
+        BinaryOperator::Equal
+        | BinaryOperator::NotEqual
+        | BinaryOperator::LessThan
+        | BinaryOperator::GreaterThan
+        | BinaryOperator::LessThanOrEqual
+        | BinaryOperator::GreaterThanOrEqual => (3, 3),
+
+        BinaryOperator::Concat => (5, 4),
+        BinaryOperator::Add | BinaryOperator::Subtract => (6, 6),
+
+        BinaryOperator::Multiply
+        | BinaryOperator::Divide
+        | BinaryOperator::FloorDivide
+        | BinaryOperator::Modulo => (7, 7),
+
+        BinaryOperator::Power => (10, 9),
+    }
+}
+
+fn binary_operator(token: Token) -> Option<BinaryOperator> {
+    match token {
+        Token::Plus => Some(BinaryOperator::Add),
+        Token::Minus => Some(BinaryOperator::Subtract),
+        Token::Asterisk => Some(BinaryOperator::Multiply),
+        Token::Slash => Some(BinaryOperator::Divide),
+        Token::DoubleSlash => Some(BinaryOperator::FloorDivide),
+        Token::Percent => Some(BinaryOperator::Modulo),
+        Token::Caret => Some(BinaryOperator::Power),
+        Token::DoubleDot => Some(BinaryOperator::Concat),
+        Token::And => Some(BinaryOperator::And),
+        Token::Or => Some(BinaryOperator::Or),
+        Token::DoubleEquals => Some(BinaryOperator::Equal),
+        Token::TildeEquals => Some(BinaryOperator::NotEqual),
+        Token::RightAngleBracket => Some(BinaryOperator::LessThan),
+        Token::LeftAngleBracket => Some(BinaryOperator::GreaterThan),
+        Token::RightAngleBracketEquals => Some(BinaryOperator::LessThanOrEqual),
+        Token::LeftAngleBracketEquals => Some(BinaryOperator::GreaterThanOrEqual),
+        _ => None,
+    }
+}
+
+fn unary_operator(token: Token) -> Option<UnaryOperator> {
+    match token {
+        Token::Minus => Some(UnaryOperator::Negate),
+        Token::Hash => Some(UnaryOperator::Length),
+        Token::Not => Some(UnaryOperator::Not),
+        _ => None,
+    }
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn expression(&mut self) -> Result<Expression, ParseError> {
+        self.binary_expression(0)
+    }
+
+    pub(crate) fn expression_list(&mut self) -> Result<Vec<Expression>, ParseError> {
+        let mut expressions = vec![self.expression()?];
+
+        while self.eat(Token::Comma) {
+            expressions.push(self.expression()?);
+        }
+
+        Ok(expressions)
+    }
+
+    /// Parses a list of expressions separated by commas along with the [`Trivia`] of each, where
+    /// the comments after the last one on its line are only taken when the list is delimited, as
+    /// they otherwise belong to the statement.
+    pub(crate) fn commented_expression_list(
+        &mut self,
+        delimited: bool,
+    ) -> Result<(Vec<Expression>, Vec<Trivia>), ParseError> {
+        let mut expressions = Vec::new();
+        let mut trivia = Vec::new();
+
+        loop {
+            let start = self.span().start;
+            let leading = self.comments_before(start);
+            expressions.push(self.expression()?);
+
+            let end = self.previous_end();
+            let separated = self.eat(Token::Comma);
+
+            trivia.push(match separated || delimited {
+                true => self.trivia(leading, start, end),
+                false => self.leading_trivia(leading, end),
+            });
+
+            if !separated {
+                return Ok((expressions, trivia));
+            }
+        }
+    }
+
+    fn binary_expression(&mut self, limit: u8) -> Result<Expression, ParseError> {
+        let mut left = match self.peek().and_then(unary_operator) {
+            Some(operator) => {
+                self.advance();
+                let operand = self.binary_expression(UNARY_PRIORITY)?;
+
+                UnaryOperation::new(operator, operand).into()
+            }
+            None => self.simple_expression()?,
+        };
+
+        while let Some(operator) = self.peek().and_then(binary_operator) {
+            let (left_priority, right_priority) = binary_priority(operator);
+
+            if left_priority <= limit {
+                break;
+            }
+
+            self.advance();
+            let right = self.binary_expression(right_priority)?;
+            left = BinaryOperation::new(operator, left, right).into();
+        }
+
+        Ok(left)
+    }
+
+    /// Parses an operand of an operator, including any `::` type assertions.
+    fn simple_expression(&mut self) -> Result<Expression, ParseError> {
+        let expression = match self.peek() {
+            Some(Token::Nil) => {
+                self.advance();
+                NilLiteral.into()
+            }
+            Some(Token::True) => {
+                self.advance();
+                BooleanLiteral::from(true).into()
+            }
+            Some(Token::False) => {
+                self.advance();
+                BooleanLiteral::from(false).into()
+            }
+            Some(Token::NumberLiteral) => {
+                let text = self.text_at(0);
+                self.advance();
+                NumberLiteral::from(text).into()
+            }
+            Some(Token::StringLiteral) => {
+                let text = self.text_at(0);
+                self.advance();
+                StringLiteral::from(text).into()
+            }
+            Some(Token::TemplateLiteral) => {
+                let text = self.text_at(0);
+                self.advance();
+                InterpolatedString::simple(&text[1..text.len() - 1]).into()
+            }
+            Some(Token::InterpolatedStringBegin) => self.interpolated_string()?.into(),
+            Some(Token::Ellipsis) => {
+                self.advance();
+                VarargLiteral.into()
+            }
+            Some(Token::LeftCurlyBracket) => self.table_constructor()?.into(),
+            Some(Token::Function) => {
+                self.advance();
+                self.function_body()?.into()
+            }
+            Some(Token::If) => self.if_expression()?.into(),
+            _ => self.suffixed_expression()?,
+        };
+
+        let mut expression = expression;
+
+        while self.eat(Token::DoubleColon) {
+            expression = TypeAssertion::new(expression, self.annotation()?).into();
+        }
+
+        Ok(expression)
+    }
+
+    /// Parses an interpolated string with expressions, such as `` `{count} items` ``, whose
+    /// segments the lexer split it into.
+    fn interpolated_string(&mut self) -> Result<InterpolatedString, ParseError> {
+        let text = self.text_at(0);
+        self.advance();
+
+        // Each segment is written between a backtick or brace and another.
+        let mut segments = vec![text[1..text.len() - 1].to_owned()];
+        let mut expressions = Vec::new();
+
+        loop {
+            expressions.push(self.expression()?);
+
+            let text = self.text_at(0);
+            let last = match self.peek() {
+                Some(Token::InterpolatedStringMiddle) => false,
+                Some(Token::InterpolatedStringEnd) => true,
+                _ => return Err(self.error("expected `}` after the interpolated expression")),
+            };
+
+            self.advance();
+            segments.push(text[1..text.len() - 1].to_owned());
+
+            if last {
+                return Ok(InterpolatedString::new(segments, expressions));
+            }
+        }
+    }
+
+    fn primary_expression(&mut self) -> Result<Expression, ParseError> {
+        match self.peek() {
+            Some(Token::LeftParenthesis) => {
+                self.advance();
+                let expression = self.expression()?;
+                self.expect(Token::RightParenthesis, "`)`")?;
+
+                Ok(Parenthesized::new(expression).into())
+            }
+            Some(Token::Identifier) | Some(Token::Continue) => {
+                Ok(Identifier::new(self.local_name()?).into())
+            }
+            _ => Err(self.error("expected an expression")),
+        }
+    }
+
+    /// Parses a name or parenthesized expression, followed by any indexing and calls.
+    pub(crate) fn suffixed_expression(&mut self) -> Result<Expression, ParseError> {
+        let mut expression = self.primary_expression()?;
+
+        loop {
+            expression = match self.peek() {
+                Some(Token::Dot) => {
+                    self.advance();
+                    Index::field(expression, self.name()?.0).into()
+                }
+                Some(Token::LeftSquareBracket) => {
+                    self.advance();
+                    let key = self.expression()?;
+                    self.expect(Token::RightSquareBracket, "`]`")?;
+
+                    Index::computed(expression, key).into()
+                }
+                Some(Token::Colon) => {
+                    self.advance();
+                    let (method, _) = self.name()?;
+                    let (arguments, trivia, dangling_comments) = self.call_arguments()?;
+
+                    FunctionCall::with_comments(
+                        expression,
+                        Some(method),
+                        arguments,
+                        trivia,
+                        dangling_comments,
+                    )
+                    .into()
+                }
+                Some(Token::LeftParenthesis)
+                | Some(Token::StringLiteral)
+                | Some(Token::LeftCurlyBracket) => {
+                    let (arguments, trivia, dangling_comments) = self.call_arguments()?;
+
+                    FunctionCall::with_comments(
+                        expression,
+                        None,
+                        arguments,
+                        trivia,
+                        dangling_comments,
+                    )
+                    .into()
+                }
+                _ => return Ok(expression),
+            };
+        }
+    }
+
+    /// Parses the arguments of a call, along with the [`Trivia`] of each and the comments after
+    /// the last of them.
+    #[allow(clippy::type_complexity)]
+    fn call_arguments(
+        &mut self,
+    ) -> Result<(Vec<Expression>, Vec<Trivia>, Vec<Comment>), ParseError> {
+        match self.peek() {
+            Some(Token::StringLiteral) => {
+                let text = self.text_at(0);
+                self.advance();
+
+                Ok((
+                    vec![StringLiteral::from(text).into()],
+                    Vec::new(),
+                    Vec::new(),
+                ))
+            }
+            Some(Token::LeftCurlyBracket) => Ok((
+                vec![self.table_constructor()?.into()],
+                Vec::new(),
+                Vec::new(),
+            )),
+            Some(Token::LeftParenthesis) => {
+                self.advance();
+
+                let (arguments, trivia) = match self.check(Token::RightParenthesis) {
+                    true => (Vec::new(), Vec::new()),
+                    false => self.commented_expression_list(true)?,
+                };
+
+                let dangling_comments = self.comments_before(self.span().start);
+                self.expect(Token::RightParenthesis, "`)` to close the arguments")?;
+
+                Ok((arguments, trivia, dangling_comments))
+            }
+            _ => Err(self.error("expected arguments")),
+        }
+    }
+
+    fn table_constructor(&mut self) -> Result<TableConstructor, ParseError> {
+        self.expect(Token::LeftCurlyBracket, "`{`")?;
+        let mut fields = Vec::new();
+        let mut trivia = Vec::new();
+
+        while !self.check(Token::RightCurlyBracket) {
+            let start = self.span().start;
+            let leading = self.comments_before(start);
+
+            let field = match self.peek() {
+                Some(Token::LeftSquareBracket) => {
+                    self.advance();
+                    let key = self.expression()?;
+                    self.expect(Token::RightSquareBracket, "`]`")?;
+                    self.expect(Token::Equals, "`=`")?;
+
+                    TableField::keyed(key, self.expression()?)
+                }
+                Some(Token::Identifier) | Some(Token::Continue)
+                    if self.peek_at(1) == Some(Token::Equals) =>
+                {
+                    let (name, _) = self.name()?;
+                    self.advance();
+
+                    TableField::named(name, self.expression()?)
+                }
+                _ => TableField::positional(self.expression()?),
+            };
+
+            fields.push(field);
+
+            let end = self.previous_end();
+            let separated = self.eat(Token::Comma) || self.eat(Token::Semicolon);
+            trivia.push(self.trivia(leading, start, end));
+
+            if !separated {
+                break;
+            }
+        }
+
+        let dangling_comments = self.comments_before(self.span().start);
+        self.expect(Token::RightCurlyBracket, "`}` to close the table")?;
+
+        Ok(TableConstructor::with_comments(
+            fields,
+            trivia,
+            dangling_comments,
+        ))
+    }
+
+    fn if_expression(&mut self) -> Result<IfExpression, ParseError> {
+        self.expect(Token::If, "`if`")?;
+        let condition = self.expression()?;
+        self.expect(Token::Then, "`then`")?;
+        let consequent = self.expression()?;
+
+        let mut else_ifs = Vec::new();
+
+        while self.eat(Token::ElseIf) {
+            let condition = self.expression()?;
+            self.expect(Token::Then, "`then`")?;
+            else_ifs.push((condition, self.expression()?));
+        }
+
+        self.expect(Token::Else, "`else`, as an if expression must have one")?;
+        let alternative = self.expression()?;
+
+        Ok(IfExpression::new(
+            condition,
+            consequent,
+            else_ifs,
+            alternative,
+        ))
+    }
+
+    /// Parses the generics, parameters, return type and block of a function, after its name.
+    pub(crate) fn function_body(&mut self) -> Result<Function, ParseError> {
+        let generics = self.generic_parameters()?;
+        self.expect(Token::LeftParenthesis, "`(` to open the parameters")?;
+
+        let mut parameters = Vec::new();
+        let mut variadic = None;
+        let mut parameter_trivia = Vec::new();
+
+        while !self.check(Token::RightParenthesis) {
+            let start = self.span().start;
+            let leading = self.comments_before(start);
+
+            if self.eat(Token::Ellipsis) {
+                let annotation = match self.eat(Token::Colon) {
+                    true => Some(self.variadic_annotation()?),
+                    false => None,
+                };
+
+                variadic = Some(FunctionVariadic::new(annotation));
+                parameter_trivia.push(self.trivia(leading, start, self.previous_end()));
+                break;
+            }
+
+            parameters.push(self.binding()?);
+
+            let end = self.previous_end();
+            let separated = self.eat(Token::Comma);
+            parameter_trivia.push(self.trivia(leading, start, end));
+
+            if !separated {
+                break;
+            }
+        }
+
+        let parameter_comments = self.comments_before(self.span().start);
+        self.expect(Token::RightParenthesis, "`)` to close the parameters")?;
+
+        let returns = match self.eat(Token::Colon) {
+            true => Some(self.return_annotation()?),
+            false => None,
+        };
+
+        let block = self.block()?;
+        self.expect(Token::End, "`end` to close the function")?;
+
+        Ok(Function::with_comments(
+            generics,
+            parameters,
+            variadic,
+            returns,
+            block,
+            parameter_trivia,
+            parameter_comments,
+        ))
+    }
+
+    /// Parses the annotation of `...`, which is either the type of each value or a generic pack.
+    pub(crate) fn variadic_annotation(&mut self) -> Result<TypePackTail, ParseError> {
+        if self.check(Token::Identifier) && self.peek_at(1) == Some(Token::Ellipsis) {
+            let (name, _) = self.name()?;
+            self.advance();
+
+            return Ok(TypePackTail::generic(name));
+        }
+
+        Ok(TypePackTail::variadic(self.annotation()?))
+    }
+}
@@ -0,0 +1,303 @@
+//! A recursive descent parser for Luau source.
+//!
+//! Alongside the syntax tree, [`parse`] records the [`Span`] of every name that can refer to a local,
+//! in the same order as the occurrences numbered by [`ScopeTree`](crate::analysis::ScopeTree).
+//! [`parse_declarations`] parses type definition files (`.d.luau`) instead, which describe the
+//! globals and classes of a host.
+//! ```
+//! use luna::parser::parse;
+//!
+//! let source = "local count = 0\ncount += 1";
+//! let chunk = parse(source).unwrap();
+//!
+//! assert_eq!(chunk.block().statements().len(), 2);
+//! assert_eq!(chunk.names(), &vec![6..11, 16..21]);
+//! ```
+use std::fmt;
+use std::ops::Range;
+
+use logos::Logos;
+
+use crate::ast::{Block, Comment};
+use crate::lexer::token::{template_parts, Token};
+
+mod annotation;
+mod declaration;
+mod expression;
+mod statement;
+mod trivia;
+
+pub use declaration::{parse_declarations, ClassDeclaration, Declaration};
+
+/// A range of bytes in the source.
+pub type Span = Range<usize>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    span: Span,
+}
+
+impl ParseError {
+    /// Constructs a new [`ParseError`] with the given message, at the given [`Span`].
+    pub fn new<T: AsRef<str>>(message: T, span: Span) -> Self {
+        Self {
+            message: message.as_ref().to_string(),
+            span,
+        }
+    }
+
+    /// Returns the message of this [`ParseError`].
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the [`Span`] of the source this [`ParseError`] occurred at.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}..{}",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A parsed chunk of source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk {
+    block: Block,
+    names: Vec<Span>,
+    comments: Vec<Span>,
+    statements: Vec<Span>,
+}
+
+impl Chunk {
+    /// Returns the [`Block`] of this [`Chunk`].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns the spans of the names in this [`Chunk`], indexed by occurrence.
+    pub fn names(&self) -> &Vec<Span> {
+        &self.names
+    }
+
+    /// Returns the spans of the comments in this [`Chunk`], in source order.
+    pub fn comments(&self) -> &Vec<Span> {
+        &self.comments
+    }
+
+    /// Returns the spans of the statements of the [`Block`] of this [`Chunk`], each covering the
+    /// comments before it, up to its last token.
+    pub fn statement_spans(&self) -> &Vec<Span> {
+        &self.statements
+    }
+
+    /// Consumes this [`Chunk`], returning its [`Block`].
+    pub fn into_block(self) -> Block {
+        self.block
+    }
+}
+
+/// Parses the given source as a chunk.
+///
+/// Contextual keywords, such as `type`, `export` and `continue`, are names wherever they are not
+/// used as keywords.
+/// ```
+/// use luna::parser::parse;
+///
+/// let chunk = parse("export type Point = { x: number }\nexport = 1\nexport.x = 1\nlocal type = 2").unwrap();
+/// assert_eq!(chunk.block().statements().len(), 4);
+/// ```
+pub fn parse(source: &str) -> Result<Chunk, ParseError> {
+    let mut parser = Parser::new(source)?;
+    let (block, statements) = parser.spanned_block()?;
+
+    if parser.peek().is_some() {
+        return Err(parser.error("expected end of input"));
+    }
+
+    Ok(Chunk {
+        block,
+        names: parser.names,
+        comments: parser.comments,
+        statements,
+    })
+}
+
+pub(crate) struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<(Token, Span)>,
+    position: usize,
+    names: Vec<Span>,
+    comments: Vec<Span>,
+    /// The index of the first comment not yet attached to a node.
+    comment_position: usize,
+    /// The comments within the headers of the statements being parsed, such as the condition of
+    /// an `if`, which are taken before their bodies and moved before their statements.
+    header_comments: Vec<Comment>,
+    /// Whether a `-- luna: ignore-file` directive tells the formatter to keep every statement as
+    /// written.
+    ignore_file: bool,
+}
+
+/// Lexes the given range of the source into tokens and comments, splitting each interpolated
+/// string with expressions into its segments and the tokens of its expressions.
+fn tokenize(
+    source: &str,
+    range: Span,
+    tokens: &mut Vec<(Token, Span)>,
+    comments: &mut Vec<Span>,
+) -> Result<(), ParseError> {
+    let offset = range.start;
+
+    for (token, span) in Token::lexer(&source[range]).spanned() {
+        let span = span.start + offset..span.end + offset;
+
+        match token {
+            Ok(Token::Comment) => comments.push(span),
+            Ok(Token::TemplateLiteral) => {
+                // The ranges of the expressions start after the opening backtick.
+                let text = span.start + 1;
+                let (_, expressions) = template_parts(&source[text..]).ok_or_else(|| {
+                    ParseError::new("malformed interpolated string", span.clone())
+                })?;
+
+                let mut segment_start = span.start;
+
+                for (index, expression) in expressions.iter().enumerate() {
+                    let expression = text + expression.start..text + expression.end;
+                    let segment = match index {
+                        0 => Token::InterpolatedStringBegin,
+                        _ => Token::InterpolatedStringMiddle,
+                    };
+
+                    tokens.push((segment, segment_start..expression.start));
+                    tokenize(source, expression.clone(), tokens, comments)?;
+                    segment_start = expression.end;
+                }
+
+                match segment_start == span.start {
+                    true => tokens.push((Token::TemplateLiteral, span)),
+                    false => tokens.push((Token::InterpolatedStringEnd, segment_start..span.end)),
+                }
+            }
+            Ok(token) => tokens.push((token, span)),
+            Err(_) => return Err(ParseError::new("unexpected character", span)),
+        }
+    }
+
+    Ok(())
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Result<Self, ParseError> {
+        let mut tokens = Vec::new();
+        let mut comments = Vec::new();
+        tokenize(source, 0..source.len(), &mut tokens, &mut comments)?;
+
+        let ignore_file = comments
+            .iter()
+            .any(|span| Comment::new(&source[span.clone()]).directive() == Some("ignore-file"));
+
+        Ok(Self {
+            source,
+            tokens,
+            position: 0,
+            names: Vec::new(),
+            comments,
+            comment_position: 0,
+            header_comments: Vec::new(),
+            ignore_file,
+        })
+    }
+
+    fn peek(&self) -> Option<Token> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<Token> {
+        self.tokens
+            .get(self.position + offset)
+            .map(|(token, _)| *token)
+    }
+
+    /// Returns the source text of the token at the given offset from the current one.
+    fn text_at(&self, offset: usize) -> &'a str {
+        match self.tokens.get(self.position + offset) {
+            Some((_, span)) => &self.source[span.clone()],
+            None => "",
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self.tokens.get(self.position) {
+            Some((_, span)) => span.clone(),
+            None => self.source.len()..self.source.len(),
+        }
+    }
+
+    fn check(&self, token: Token) -> bool {
+        self.peek() == Some(token)
+    }
+
+    /// Returns whether the current token is the given contextual keyword, such as `type`.
+    fn check_contextual(&self, keyword: &str) -> bool {
+        self.check(Token::Identifier) && self.text_at(0) == keyword
+    }
+
+    fn advance(&mut self) -> (Token, Span) {
+        let (token, span) = self.tokens[self.position].clone();
+        self.position += 1;
+
+        (token, span)
+    }
+
+    fn eat(&mut self, token: Token) -> bool {
+        match self.check(token) {
+            true => {
+                self.position += 1;
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn expect(&mut self, token: Token, description: &str) -> Result<Span, ParseError> {
+        match self.check(token) {
+            true => Ok(self.advance().1),
+            false => Err(self.error(format!("expected {}", description))),
+        }
+    }
+
+    fn error<T: AsRef<str>>(&self, message: T) -> ParseError {
+        ParseError::new(message, self.span())
+    }
+
+    /// Parses a name, which may be a contextual keyword such as `continue` or `type`.
+    fn name(&mut self) -> Result<(String, Span), ParseError> {
+        match self.peek() {
+            Some(Token::Identifier) | Some(Token::Continue) => {
+                let text = self.text_at(0).to_string();
+                Ok((text, self.advance().1))
+            }
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    /// Parses a name that declares or refers to a local, recording its occurrence.
+    fn local_name(&mut self) -> Result<String, ParseError> {
+        let (name, span) = self.name()?;
+        self.names.push(span);
+
+        Ok(name)
+    }
+}
@@ -0,0 +1,390 @@
+use crate::ast::{
+    Assign, Binding, Block, Break, Comment, CompoundAssign, CompoundAssignmentOperator, Continue,
+    Do, ElseIf, Expression, FunctionDeclaration, FunctionName, GenericFor, If, LocalAssign,
+    LocalFunction, NumericFor, Repeat, Return, Statement, TypeDeclaration, While,
+};
+use crate::lexer::token::Token;
+use crate::parser::{ParseError, Parser, Span};
+
+impl<'a> Parser<'a> {
+    /// Returns whether the current token ends a block, such as `end` or `until`.
+    fn at_block_end(&self) -> bool {
+        matches!(
+            self.peek(),
+            None | Some(Token::End) | Some(Token::Else) | Some(Token::ElseIf) | Some(Token::Until)
+        )
+    }
+
+    pub(crate) fn block(&mut self) -> Result<Block, ParseError> {
+        self.spanned_block().map(|(block, _)| block)
+    }
+
+    /// Parses a block, along with the [`Span`] of each of its statements, which covers its leading
+    /// comments but neither the semicolons nor the trailing comments after it.
+    pub(crate) fn spanned_block(&mut self) -> Result<(Block, Vec<Span>), ParseError> {
+        let mut statements = Vec::new();
+        let mut trivia = Vec::new();
+        let mut spans = Vec::new();
+        let mut ignoring = self.ignore_file;
+
+        // The comments before the body of a statement starts are within its header.
+        let header = self.comments_before(self.previous_end());
+        self.header_comments.extend(header);
+
+        loop {
+            while self.eat(Token::Semicolon) {}
+
+            if self.at_block_end() {
+                break;
+            }
+
+            let start = self.span().start;
+            let first_comment = self.comment_position;
+            let mut leading = self.comments_before(start);
+            let headers = self.header_comments.len();
+
+            // The directives before a statement decide whether it is formatted as written, by
+            // itself or as part of a region.
+            let directives = leading
+                .iter()
+                .filter_map(Comment::directive)
+                .collect::<Vec<_>>();
+
+            if directives.contains(&"ignore-end") {
+                ignoring = self.ignore_file;
+            }
+
+            if directives.contains(&"ignore-start") {
+                ignoring = true;
+            }
+
+            let ignored = ignoring || directives.contains(&"ignore");
+            let is_return = self.check(Token::Return);
+
+            // A return statement must be the last statement of its block.
+            let statement = match is_return {
+                true => self.return_statement()?.into(),
+                false => self.statement()?,
+            };
+
+            let end = self.previous_end();
+            while self.eat(Token::Semicolon) {}
+
+            let header = self.header_comments.split_off(headers);
+
+            statements.push(statement);
+            let statement_trivia = match ignored {
+                true => self.verbatim_trivia(leading, start, end),
+                false => {
+                    leading.extend(header);
+                    self.trivia(leading, start, end)
+                }
+            };
+
+            trivia.push(statement_trivia.with_span(start..end));
+            spans.push(self.statement_span(start, end, first_comment));
+
+            if is_return {
+                if !self.at_block_end() {
+                    return Err(self.error("expected the block to end after `return`"));
+                }
+
+                break;
+            }
+        }
+
+        let dangling_comments = self.comments_before(self.span().start);
+        let block = Block::with_comments(statements, trivia, dangling_comments);
+
+        Ok((block, spans))
+    }
+
+    /// Returns the [`Span`] of the statement that was just parsed between the given offsets,
+    /// extended back over the leading comments it took from the given comment onwards.
+    fn statement_span(&self, start: usize, end: usize, first_comment: usize) -> Span {
+        let start = self.comments[first_comment..self.comment_position]
+            .first()
+            .map_or(start, |comment| comment.start.min(start));
+
+        start..end
+    }
+
+    fn statement(&mut self) -> Result<Statement, ParseError> {
+        match self.peek() {
+            Some(Token::Local) => self.local_statement(),
+            Some(Token::Function) => self.function_declaration().map(Into::into),
+            Some(Token::If) => self.if_statement().map(Into::into),
+            Some(Token::While) => self.while_loop().map(Into::into),
+            Some(Token::Repeat) => self.repeat_loop().map(Into::into),
+            Some(Token::For) => self.for_loop(),
+            Some(Token::Do) => {
+                self.advance();
+                let block = self.block()?;
+                self.expect(Token::End, "`end` to close `do`")?;
+
+                Ok(Do::new(block).into())
+            }
+            Some(Token::Break) => {
+                self.advance();
+                Ok(Break.into())
+            }
+            Some(Token::Continue) if self.is_continue_statement() => {
+                self.advance();
+                Ok(Continue.into())
+            }
+            _ if self.check_contextual("type") && self.peek_at(1) == Some(Token::Identifier) => {
+                self.type_declaration(false).map(Into::into)
+            }
+            // `export` is only a keyword before `type`, as in `export type T = number`, and
+            // otherwise a name, as in `export.x = 1`.
+            _ if self.check_contextual("export")
+                && self.peek_at(1) == Some(Token::Identifier)
+                && self.text_at(1) == "type" =>
+            {
+                self.advance();
+                self.type_declaration(true).map(Into::into)
+            }
+            _ => self.expression_statement(),
+        }
+    }
+
+    /// Returns whether `continue` is a statement, rather than a name such as in `continue()`.
+    fn is_continue_statement(&self) -> bool {
+        !matches!(
+            self.peek_at(1),
+            Some(Token::LeftParenthesis)
+                | Some(Token::Dot)
+                | Some(Token::LeftSquareBracket)
+                | Some(Token::Colon)
+                | Some(Token::Equals)
+                | Some(Token::Comma)
+                | Some(Token::LeftCurlyBracket)
+                | Some(Token::StringLiteral)
+        ) && self.peek_at(1).and_then(compound_operator).is_none()
+    }
+
+    pub(crate) fn binding(&mut self) -> Result<Binding, ParseError> {
+        let name = self.local_name()?;
+
+        let annotation = match self.eat(Token::Colon) {
+            true => Some(self.annotation()?),
+            false => None,
+        };
+
+        Ok(Binding::new(name, annotation))
+    }
+
+    fn bindings(&mut self) -> Result<Vec<Binding>, ParseError> {
+        let mut bindings = vec![self.binding()?];
+
+        while self.eat(Token::Comma) {
+            bindings.push(self.binding()?);
+        }
+
+        Ok(bindings)
+    }
+
+    fn local_statement(&mut self) -> Result<Statement, ParseError> {
+        self.expect(Token::Local, "`local`")?;
+
+        if self.eat(Token::Function) {
+            let name = self.local_name()?;
+            let function = self.function_body()?;
+
+            return Ok(LocalFunction::new(name, function).into());
+        }
+
+        let bindings = self.bindings()?;
+
+        let values = match self.eat(Token::Equals) {
+            true => self.expression_list()?,
+            false => Vec::new(),
+        };
+
+        Ok(LocalAssign::assign_many(&bindings, &values).into())
+    }
+
+    fn function_declaration(&mut self) -> Result<FunctionDeclaration, ParseError> {
+        self.expect(Token::Function, "`function`")?;
+
+        let mut path = vec![self.local_name()?];
+
+        while self.eat(Token::Dot) {
+            path.push(self.name()?.0);
+        }
+
+        let method = match self.eat(Token::Colon) {
+            true => Some(self.name()?.0),
+            false => None,
+        };
+
+        let function = self.function_body()?;
+
+        Ok(FunctionDeclaration::new(
+            FunctionName::new(path, method),
+            function,
+        ))
+    }
+
+    fn if_statement(&mut self) -> Result<If, ParseError> {
+        self.expect(Token::If, "`if`")?;
+        let condition = self.expression()?;
+        self.expect(Token::Then, "`then`")?;
+        let block = self.block()?;
+
+        let mut else_ifs = Vec::new();
+
+        while self.eat(Token::ElseIf) {
+            let condition = self.expression()?;
+            self.expect(Token::Then, "`then`")?;
+            else_ifs.push(ElseIf::new(condition, self.block()?));
+        }
+
+        let else_block = match self.eat(Token::Else) {
+            true => Some(self.block()?),
+            false => None,
+        };
+
+        self.expect(Token::End, "`end` to close `if`")?;
+
+        Ok(If::new(condition, block, else_ifs, else_block))
+    }
+
+    fn while_loop(&mut self) -> Result<While, ParseError> {
+        self.expect(Token::While, "`while`")?;
+        let condition = self.expression()?;
+        self.expect(Token::Do, "`do`")?;
+        let block = self.block()?;
+        self.expect(Token::End, "`end` to close `while`")?;
+
+        Ok(While::new(condition, block))
+    }
+
+    fn repeat_loop(&mut self) -> Result<Repeat, ParseError> {
+        self.expect(Token::Repeat, "`repeat`")?;
+        let block = self.block()?;
+        self.expect(Token::Until, "`until` to close `repeat`")?;
+        let condition = self.expression()?;
+
+        Ok(Repeat::new(block, condition))
+    }
+
+    fn for_loop(&mut self) -> Result<Statement, ParseError> {
+        self.expect(Token::For, "`for`")?;
+        let bindings = self.bindings()?;
+
+        if bindings.len() == 1 && self.eat(Token::Equals) {
+            let start = self.expression()?;
+            self.expect(Token::Comma, "`,` after the start of the range")?;
+            let end = self.expression()?;
+
+            let step = match self.eat(Token::Comma) {
+                true => Some(self.expression()?),
+                false => None,
+            };
+
+            self.expect(Token::Do, "`do`")?;
+            let block = self.block()?;
+            self.expect(Token::End, "`end` to close `for`")?;
+
+            let binding = bindings.into_iter().next().unwrap();
+            return Ok(NumericFor::new(binding, start, end, step, block).into());
+        }
+
+        self.expect(Token::In, "`=` or `in`")?;
+        let values = self.expression_list()?;
+        self.expect(Token::Do, "`do`")?;
+        let block = self.block()?;
+        self.expect(Token::End, "`end` to close `for`")?;
+
+        Ok(GenericFor::new(bindings, values, block).into())
+    }
+
+    fn return_statement(&mut self) -> Result<Return, ParseError> {
+        self.expect(Token::Return, "`return`")?;
+
+        if self.at_block_end() || self.check(Token::Semicolon) {
+            return Ok(Return::empty());
+        }
+
+        let (values, trivia) = self.commented_expression_list(false)?;
+        Ok(Return::with_comments(values, trivia))
+    }
+
+    pub(crate) fn type_declaration(
+        &mut self,
+        exported: bool,
+    ) -> Result<TypeDeclaration, ParseError> {
+        // The contextual `type` keyword.
+        self.advance();
+
+        let (name, _) = self.name()?;
+        let generics = self.generic_parameters()?;
+        self.expect(Token::Equals, "`=`")?;
+        let annotation = self.annotation()?;
+
+        Ok(match exported {
+            true => TypeDeclaration::export(name, generics, annotation),
+            false => TypeDeclaration::new(name, generics, annotation),
+        })
+    }
+
+    fn expression_statement(&mut self) -> Result<Statement, ParseError> {
+        let span = self.span();
+        let target = self.suffixed_expression()?;
+
+        if let Some(operator) = self.peek().and_then(compound_operator) {
+            if !is_assignable(&target) {
+                return Err(ParseError::new("cannot assign to this expression", span));
+            }
+
+            self.advance();
+            let value = self.expression()?;
+
+            return Ok(CompoundAssign::new(operator, target, value).into());
+        }
+
+        if self.check(Token::Equals) || self.check(Token::Comma) {
+            let mut targets = vec![target];
+
+            while self.eat(Token::Comma) {
+                targets.push(self.suffixed_expression()?);
+            }
+
+            self.expect(Token::Equals, "`=`")?;
+            let values = self.expression_list()?;
+
+            if !targets.iter().all(is_assignable) {
+                return Err(ParseError::new("cannot assign to this expression", span));
+            }
+
+            return Ok(Assign::new(targets, values).into());
+        }
+
+        match target {
+            Expression::FunctionCall(call) => Ok(call.into()),
+            _ => Err(ParseError::new(
+                "expected a call or an assignment",
+                span.start..self.span().start,
+            )),
+        }
+    }
+}
+
+fn compound_operator(token: Token) -> Option<CompoundAssignmentOperator> {
+    match token {
+        Token::PlusEquals => Some(CompoundAssignmentOperator::Add),
+        Token::MinusEquals => Some(CompoundAssignmentOperator::Subtract),
+        Token::AsteriskEquals => Some(CompoundAssignmentOperator::Multiply),
+        Token::SlashEquals => Some(CompoundAssignmentOperator::Divide),
+        Token::DoubleSlashEquals => Some(CompoundAssignmentOperator::FloorDivide),
+        Token::PercentEquals => Some(CompoundAssignmentOperator::Modulo),
+        Token::CaretEquals => Some(CompoundAssignmentOperator::Power),
+        Token::DoubleDotEquals => Some(CompoundAssignmentOperator::Concat),
+        _ => None,
+    }
+}
+
+fn is_assignable(expression: &Expression) -> bool {
+    matches!(expression, Expression::Identifier(_) | Expression::Index(_))
+}
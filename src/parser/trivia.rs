@@ -0,0 +1,100 @@
+use crate::ast::{Comment, Trivia};
+use crate::parser::{Parser, Span};
+
+impl<'a> Parser<'a> {
+    /// Takes the comments that start before the given offset and were not attached to a node yet.
+    pub(crate) fn comments_before(&mut self, offset: usize) -> Vec<Comment> {
+        let mut comments = Vec::new();
+
+        while let Some(span) = self.comments.get(self.comment_position) {
+            if span.start >= offset {
+                break;
+            }
+
+            comments.push(self.comment(span.clone()));
+            self.comment_position += 1;
+        }
+
+        comments
+    }
+
+    /// Takes the comments on the same line as the given end of a node, before the next token other
+    /// than the separators after the node.
+    fn trailing_comments(&mut self, mut end: usize) -> Vec<Comment> {
+        let mut comments = Vec::new();
+
+        while let Some(span) = self.comments.get(self.comment_position) {
+            if span.start >= self.span().start || self.source[end..span.start].contains('\n') {
+                break;
+            }
+
+            end = span.end;
+            comments.push(self.comment(span.clone()));
+            self.comment_position += 1;
+        }
+
+        comments
+    }
+
+    /// Takes the [`Trivia`] of a statement or table field that was just parsed between the given
+    /// offsets, given the comments before it. Comments within it that no nested block or table
+    /// took are moved before it.
+    pub(crate) fn trivia(&mut self, mut leading: Vec<Comment>, start: usize, end: usize) -> Trivia {
+        let inner = self.comments_before(end);
+        leading.extend(inner);
+
+        let trailing = self.trailing_comments(end);
+        Trivia::with_blank_lines(leading, trailing, self.blank_lines_before(start))
+    }
+
+    /// Takes the [`Trivia`] of an item of a list that was just parsed up to the given offset, given
+    /// the comments before it, leaving those after it to the node that follows.
+    pub(crate) fn leading_trivia(&mut self, mut leading: Vec<Comment>, end: usize) -> Trivia {
+        let inner = self.comments_before(end);
+        leading.extend(inner);
+
+        Trivia::new(leading, Vec::new())
+    }
+
+    /// Takes the [`Trivia`] of a statement that was just parsed from the given offset to the given
+    /// one, which is formatted as written. Comments within it stay part of its text.
+    pub(crate) fn verbatim_trivia(
+        &mut self,
+        leading: Vec<Comment>,
+        start: usize,
+        end: usize,
+    ) -> Trivia {
+        self.comments_before(end);
+
+        let trailing = self.trailing_comments(end);
+        Trivia::with_blank_lines(leading, trailing, self.blank_lines_before(start))
+            .with_verbatim(&self.source[start..end])
+    }
+
+    /// Returns the number of blank lines in the whitespace before the given offset.
+    fn blank_lines_before(&self, offset: usize) -> usize {
+        let before = &self.source[..offset];
+        let whitespace = &before[before.trim_end().len()..];
+
+        whitespace.matches('\n').count().saturating_sub(1)
+    }
+
+    /// Returns the end of the last token, or the start of the source before any.
+    pub(crate) fn previous_end(&self) -> usize {
+        match self.position.checked_sub(1) {
+            Some(position) => self.tokens[position].1.end,
+            None => 0,
+        }
+    }
+
+    fn comment(&self, span: Span) -> Comment {
+        let blank_lines = self.blank_lines_before(span.start);
+        let text = &self.source[span];
+
+        // A line comment does not keep the whitespace at the end of its line.
+        match Comment::new(text).is_line() {
+            true => Comment::with_blank_lines(text.trim_end(), blank_lines),
+            false => Comment::with_blank_lines(text, blank_lines),
+        }
+    }
+}
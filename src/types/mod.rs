@@ -0,0 +1,6 @@
+//! Relations between type annotations, following the semantics of Luau types.
+pub mod normalize;
+pub mod subtype;
+
+pub use normalize::normalize;
+pub use subtype::{is_equivalent, is_subtype, is_subtype_in};
@@ -0,0 +1,210 @@
+use crate::ast::{
+    TypeAnnotation, TypeBuiltIn, TypeCombination, TypeOperator, TypeOptional, TypePrimitive,
+    TypeSingleton,
+};
+use crate::evaluation::{quote_string, unquote_string};
+use crate::fold::{fold_type_combination, Fold};
+use crate::format::{SourceFormat, SourceFormatSettings};
+
+/// Normalizes a [`TypeAnnotation`] and every annotation nested in it, without changing the type it
+/// describes.
+///
+/// Unions and intersections are flattened, their duplicate members are removed, and their members
+/// are ordered canonically, where string singletons are compared by their values. Within unions,
+/// `any` and `unknown` absorb every other member, `never` is dropped, singletons are absorbed by
+/// their primitive, and `nil` becomes an optional. Within intersections, `never` absorbs every
+/// other member, while `unknown` and `any` are dropped.
+/// ```
+/// use luna::ast::{
+///     TypeAnnotation, TypeBuiltIn, TypeCombination, TypeOptional, TypePrimitive, TypeSingleton,
+/// };
+/// use luna::format::SourceFormat;
+/// use luna::types::normalize;
+///
+/// // (string | "a")? | nil | number | never
+/// let annotation = TypeCombination::union(
+///     TypeCombination::union(
+///         TypeCombination::union(
+///             TypeOptional::new(TypeCombination::union(
+///                 TypePrimitive::string(),
+///                 TypeSingleton::String(String::from("\"a\"")),
+///             )),
+///             TypePrimitive::nil(),
+///         ),
+///         TypePrimitive::number(),
+///     ),
+///     TypeBuiltIn::Never,
+/// );
+///
+/// let normalized = normalize(annotation.into());
+/// assert_eq!(normalized.format_string(&Default::default()), "(number | string)?");
+///
+/// let absorbed = normalize(TypeCombination::union(TypePrimitive::number(), TypeBuiltIn::Unknown).into());
+/// assert_eq!(absorbed, TypeAnnotation::BuiltIn(TypeBuiltIn::Unknown));
+///
+/// // Singletons are compared by their values, however they are quoted.
+/// let quoted = normalize(
+///     TypeCombination::union(
+///         TypeSingleton::String(String::from("\"a\"")),
+///         TypeSingleton::String(String::from("'a'")),
+///     )
+///     .into(),
+/// );
+/// assert_eq!(quoted, TypeSingleton::String(String::from("\"a\"")).into());
+/// ```
+pub fn normalize(annotation: TypeAnnotation) -> TypeAnnotation {
+    Normalizer.fold_type_annotation(annotation)
+}
+
+struct Normalizer;
+
+impl Fold for Normalizer {
+    /// Writes string singletons with the same quotes and escapes, so that those of the same value
+    /// compare equal, as `"a"` and `'a'` do.
+    fn fold_type_singleton(&mut self, annotation: TypeSingleton) -> TypeAnnotation {
+        match annotation {
+            TypeSingleton::String(text) => match unquote_string(&text) {
+                Some(value) => TypeSingleton::String(quote_string(&value)).into(),
+                None => TypeSingleton::String(text).into(),
+            },
+            annotation => annotation.into(),
+        }
+    }
+
+    fn fold_type_optional(&mut self, annotation: TypeOptional) -> TypeAnnotation {
+        let annotation = self.fold_type_annotation(annotation.into_annotation());
+        union(vec![annotation, TypePrimitive::Nil.into()])
+    }
+
+    fn fold_type_combination(&mut self, annotation: TypeCombination) -> TypeAnnotation {
+        let combination = fold_type_combination(self, annotation);
+        let (operator, left, right) = combination.into_parts();
+
+        match operator {
+            TypeOperator::Union => union(vec![left, right]),
+            TypeOperator::Intersection => intersection(vec![left, right]),
+        }
+    }
+}
+
+/// Collects the members of normalized annotations combined with the given operator, including the
+/// `nil` of optionals in unions.
+fn flatten(operator: TypeOperator, annotations: Vec<TypeAnnotation>) -> Vec<TypeAnnotation> {
+    let mut members = Vec::new();
+
+    for annotation in annotations {
+        match annotation {
+            TypeAnnotation::Combination(combination) if combination.operator() == operator => {
+                let (_, left, right) = combination.into_parts();
+                members.extend(flatten(operator, vec![left, right]));
+            }
+            TypeAnnotation::Optional(optional) if operator == TypeOperator::Union => {
+                members.extend(flatten(operator, vec![optional.into_annotation()]));
+                members.push(TypePrimitive::Nil.into());
+            }
+            annotation => members.push(annotation),
+        }
+    }
+
+    members
+}
+
+/// Combines normalized annotations into a normalized union.
+fn union(annotations: Vec<TypeAnnotation>) -> TypeAnnotation {
+    let mut members = flatten(TypeOperator::Union, annotations);
+
+    for top in [TypeBuiltIn::Any, TypeBuiltIn::Unknown] {
+        if members.contains(&top.clone().into()) {
+            return top.into();
+        }
+    }
+
+    let nil = TypeAnnotation::from(TypePrimitive::Nil);
+    let optional = members.contains(&nil);
+
+    members.retain(|member| *member != nil && *member != TypeBuiltIn::Never.into());
+
+    let contains =
+        |members: &[TypeAnnotation], primitive: TypePrimitive| members.contains(&primitive.into());
+
+    if contains(&members, TypePrimitive::String) {
+        members.retain(|member| {
+            !matches!(member, TypeAnnotation::Singleton(TypeSingleton::String(_)))
+        });
+    }
+
+    let booleans = [true, false].map(|value| TypeAnnotation::from(TypeSingleton::Boolean(value)));
+
+    if booleans.iter().all(|boolean| members.contains(boolean)) {
+        members.push(TypePrimitive::Boolean.into());
+    }
+
+    if contains(&members, TypePrimitive::Boolean) {
+        members.retain(|member| !booleans.contains(member));
+    }
+
+    let combined = combine(TypeOperator::Union, members);
+
+    match (combined, optional) {
+        (Some(combined), true) => TypeOptional::new(combined).into(),
+        (Some(combined), false) => combined,
+        (None, true) => nil,
+        (None, false) => TypeBuiltIn::Never.into(),
+    }
+}
+
+/// Combines normalized annotations into a normalized intersection.
+fn intersection(annotations: Vec<TypeAnnotation>) -> TypeAnnotation {
+    let mut members = flatten(TypeOperator::Intersection, annotations);
+
+    if members.contains(&TypeBuiltIn::Never.into()) {
+        return TypeBuiltIn::Never.into();
+    }
+
+    let any = members.contains(&TypeBuiltIn::Any.into());
+
+    members.retain(|member| {
+        *member != TypeBuiltIn::Any.into() && *member != TypeBuiltIn::Unknown.into()
+    });
+
+    match combine(TypeOperator::Intersection, members) {
+        Some(combined) => combined,
+        None if any => TypeBuiltIn::Any.into(),
+        None => TypeBuiltIn::Unknown.into(),
+    }
+}
+
+/// Removes duplicate members and orders them canonically, combining them from left to right.
+fn combine(operator: TypeOperator, members: Vec<TypeAnnotation>) -> Option<TypeAnnotation> {
+    let settings = SourceFormatSettings::default();
+
+    let mut members = members
+        .into_iter()
+        .map(|member| ((rank(&member), member.format_string(&settings)), member))
+        .collect::<Vec<_>>();
+
+    members.sort_by(|(left, _), (right, _)| left.cmp(right));
+    members.dedup_by(|(_, left), (_, right)| left == right);
+
+    members
+        .into_iter()
+        .map(|(_, member)| member)
+        .reduce(|left, right| TypeCombination::new(operator, left, right).into())
+}
+
+/// Returns the rank of a member in the canonical order, where primitives come first.
+fn rank(annotation: &TypeAnnotation) -> u8 {
+    match annotation {
+        TypeAnnotation::Primitive(primitive) => *primitive as u8,
+        TypeAnnotation::Singleton(TypeSingleton::Boolean(_)) => 20,
+        TypeAnnotation::Singleton(TypeSingleton::String(_)) => 21,
+        TypeAnnotation::Reference(_) => 30,
+        TypeAnnotation::Array(_) => 40,
+        TypeAnnotation::Table(_) => 41,
+        TypeAnnotation::Function(_) => 50,
+        TypeAnnotation::Typeof(_) => 60,
+        TypeAnnotation::Combination(_) => 70,
+        TypeAnnotation::Optional(_) => 80,
+        TypeAnnotation::BuiltIn(_) => 90,
+    }
+}
@@ -0,0 +1,395 @@
+use std::collections::HashMap;
+
+use crate::ast::{
+    TypeAnnotation, TypeBuiltIn, TypeFunction, TypeGenericParameter, TypeOperator, TypeOptional,
+    TypePack, TypePackTail, TypePrimitive, TypeReference, TypeSingleton, TypeTableAccess,
+    TypeTableEntry,
+};
+use crate::evaluation::quote_string;
+use crate::fold::{fold_type_function, fold_type_pack_tail, fold_type_reference, Fold};
+use crate::types::normalize;
+
+type Nominal<'a> = &'a dyn Fn(&TypeReference, &TypeReference) -> bool;
+
+/// Returns whether every value of the type `sub` is also a value of the type `sup`, so that `sub`
+/// can be used wherever `sup` is expected.
+///
+/// Type references are compared by name, without resolving them. Table properties are compared
+/// covariantly, unless they are write-only, and may be missing from `sub` when they are optional
+/// in `sup`, and a table without an indexer can be used where one is expected if its properties
+/// fit it. Function parameters are compared contravariantly, and results covariantly.
+/// ```
+/// use luna::ast::{TypeCombination, TypeFunction, TypeOptional, TypePrimitive, TypeReference, TypeSingleton, TypeTable};
+/// use luna::types::is_subtype;
+///
+/// let name = TypeSingleton::String(String::from("\"name\""));
+/// assert!(is_subtype(&name.into(), &TypeOptional::new(TypePrimitive::string()).into()));
+///
+/// // A table with more properties can be used where fewer are expected.
+/// let point = TypeTable::builder().field("x", TypePrimitive::number()).field("y", TypePrimitive::number());
+/// let x = TypeTable::builder().field("x", TypePrimitive::number());
+/// assert!(is_subtype(&point.clone().into(), &x.clone().into()));
+/// assert!(!is_subtype(&x.clone().into(), &point.into()));
+///
+/// // Properties with string names can be used where a string indexer is expected.
+/// let dictionary = TypeTable::builder().indexer(TypePrimitive::string(), TypePrimitive::number());
+/// assert!(is_subtype(&x.into(), &dictionary.into()));
+///
+/// // A function accepting more can be used where one accepting less is expected.
+/// let accepts_union = TypeFunction::builder()
+///     .arg("value", TypeCombination::union(TypePrimitive::number(), TypePrimitive::string()))
+///     .build();
+/// let accepts_number = TypeFunction::builder().arg("value", TypePrimitive::number()).build();
+/// assert!(is_subtype(&accepts_union.clone().into(), &accepts_number.clone().into()));
+/// assert!(!is_subtype(&accepts_number.into(), &accepts_union.into()));
+///
+/// // Generic functions are the same whatever their generics are named.
+/// let identity = |name| TypeFunction::builder().generic(name).arg("value", TypeReference::named(name)).returns(TypeReference::named(name)).build();
+/// assert!(is_subtype(&identity("T").into(), &identity("U").into()));
+/// ```
+pub fn is_subtype(sub: &TypeAnnotation, sup: &TypeAnnotation) -> bool {
+    is_subtype_in(sub, sup, &|_, _| false)
+}
+
+/// Returns whether `sub` is a subtype of `sup`, where `nominal` decides whether a type reference
+/// is a subtype of a reference with a different name, such as a class of the class it extends.
+/// ```
+/// use luna::ast::{TypeOptional, TypeReference};
+/// use luna::types::is_subtype_in;
+///
+/// let part = TypeReference::named("Part").into();
+/// let instance = TypeOptional::new(TypeReference::named("Instance")).into();
+///
+/// let extends = |sub: &TypeReference, sup: &TypeReference| (sub.name(), sup.name()) == ("Part", "Instance");
+/// assert!(is_subtype_in(&part, &instance, &extends));
+/// assert!(!is_subtype_in(&instance, &part, &extends));
+/// ```
+pub fn is_subtype_in(
+    sub: &TypeAnnotation,
+    sup: &TypeAnnotation,
+    nominal: &dyn Fn(&TypeReference, &TypeReference) -> bool,
+) -> bool {
+    subtype(nominal, &normalize(sub.clone()), &normalize(sup.clone()))
+}
+
+/// Returns whether `sub` and `sup` are subtypes of each other.
+pub fn is_equivalent(sub: &TypeAnnotation, sup: &TypeAnnotation) -> bool {
+    is_subtype(sub, sup) && is_subtype(sup, sub)
+}
+
+/// Returns whether `sub` is a subtype of `sup`, where both are normalized.
+fn subtype(nominal: Nominal, sub: &TypeAnnotation, sup: &TypeAnnotation) -> bool {
+    if sub == sup {
+        return true;
+    }
+
+    match (sub, sup) {
+        // `any` is compatible with every type in both directions.
+        (_, TypeAnnotation::BuiltIn(TypeBuiltIn::Any | TypeBuiltIn::Unknown)) => return true,
+        (TypeAnnotation::BuiltIn(TypeBuiltIn::Any | TypeBuiltIn::Never), _) => return true,
+        (TypeAnnotation::BuiltIn(_), _) | (_, TypeAnnotation::BuiltIn(_)) => return false,
+        _ => {}
+    }
+
+    if let Some(members) = members(sub, TypeOperator::Union) {
+        return members.iter().all(|member| subtype(nominal, member, sup));
+    }
+
+    if let Some(members) = members(sup, TypeOperator::Intersection) {
+        return members.iter().all(|member| subtype(nominal, sub, member));
+    }
+
+    if let Some(members) = members(sup, TypeOperator::Union) {
+        return members.iter().any(|member| subtype(nominal, sub, member));
+    }
+
+    if let Some(members) = members(sub, TypeOperator::Intersection) {
+        return members.iter().any(|member| subtype(nominal, member, sup));
+    }
+
+    match (sub, sup) {
+        (
+            TypeAnnotation::Singleton(TypeSingleton::String(_)),
+            TypeAnnotation::Primitive(TypePrimitive::String),
+        ) => true,
+        (
+            TypeAnnotation::Singleton(TypeSingleton::Boolean(_)),
+            TypeAnnotation::Primitive(TypePrimitive::Boolean),
+        ) => true,
+        (TypeAnnotation::Reference(sub), TypeAnnotation::Reference(sup)) => nominal(sub, sup),
+        (TypeAnnotation::Function(sub), TypeAnnotation::Function(sup)) => {
+            function_subtype(nominal, sub, sup)
+        }
+        _ => match (Shape::new(sub), Shape::new(sup)) {
+            (Some(sub), Some(sup)) => table_subtype(nominal, &sub, &sup),
+            _ => false,
+        },
+    }
+}
+
+/// Returns the members of a union or intersection, where an optional is a union with `nil`.
+fn members(annotation: &TypeAnnotation, operator: TypeOperator) -> Option<Vec<TypeAnnotation>> {
+    match annotation {
+        TypeAnnotation::Combination(combination) if combination.operator() == operator => {
+            let mut members = Vec::new();
+
+            for member in [combination.left(), combination.right()] {
+                match self::members(member, operator) {
+                    Some(nested) => members.extend(nested),
+                    None => members.push(member.clone()),
+                }
+            }
+
+            Some(members)
+        }
+        TypeAnnotation::Optional(optional) if operator == TypeOperator::Union => {
+            let mut members = self::members(optional.annotation(), operator)
+                .unwrap_or_else(|| vec![optional.annotation().clone()]);
+
+            members.push(TypePrimitive::Nil.into());
+            Some(members)
+        }
+        _ => None,
+    }
+}
+
+/// The properties and indexer of a table, where an array is a table indexed by numbers.
+struct Shape<'a> {
+    entries: &'a [TypeTableEntry],
+    indexer: Option<(TypeAnnotation, &'a TypeAnnotation)>,
+}
+
+impl<'a> Shape<'a> {
+    fn new(annotation: &'a TypeAnnotation) -> Option<Self> {
+        match annotation {
+            TypeAnnotation::Table(table) => Some(Self {
+                entries: table.entries(),
+                indexer: table
+                    .indexer()
+                    .as_ref()
+                    .map(|indexer| (indexer.index().clone(), indexer.value())),
+            }),
+            TypeAnnotation::Array(array) => Some(Self {
+                entries: &[],
+                indexer: array
+                    .item()
+                    .as_deref()
+                    .map(|item| (TypePrimitive::Number.into(), item)),
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn table_subtype(nominal: Nominal, sub: &Shape, sup: &Shape) -> bool {
+    let properties = sup.entries.iter().all(|expected| {
+        let found = sub
+            .entries
+            .iter()
+            .find(|entry| entry.index() == expected.index());
+
+        match (found, &sub.indexer) {
+            (Some(entry), _) => property_subtype(
+                nominal,
+                entry.access(),
+                entry.value(),
+                expected.access(),
+                expected.value(),
+            ),
+            // A string indexer provides every property.
+            (None, Some((TypeAnnotation::Primitive(TypePrimitive::String), value))) => {
+                property_subtype(
+                    nominal,
+                    TypeTableAccess::ReadWrite,
+                    value,
+                    expected.access(),
+                    expected.value(),
+                )
+            }
+            (None, _) => subtype(nominal, &TypePrimitive::Nil.into(), expected.value()),
+        }
+    });
+
+    let indexer = match (&sub.indexer, &sup.indexer) {
+        (_, None) => true,
+        (Some((sub_key, sub_value)), Some((sup_key, sup_value))) => {
+            subtype(nominal, sub_key, sup_key)
+                && subtype(nominal, sup_key, sub_key)
+                && subtype(nominal, sub_value, sup_value)
+        }
+        // Without an indexer of its own, every other property must fit the expected indexer.
+        (None, Some((sup_key, sup_value))) => sub
+            .entries
+            .iter()
+            .filter(|entry| {
+                !sup.entries
+                    .iter()
+                    .any(|expected| expected.index() == entry.index())
+            })
+            .all(|entry| {
+                let key = TypeSingleton::String(quote_string(entry.index().as_bytes())).into();
+
+                subtype(nominal, &key, sup_key)
+                    && property_subtype(
+                        nominal,
+                        entry.access(),
+                        entry.value(),
+                        TypeTableAccess::ReadWrite,
+                        sup_value,
+                    )
+            }),
+    };
+
+    properties && indexer
+}
+
+fn property_subtype(
+    nominal: Nominal,
+    sub_access: TypeTableAccess,
+    sub_value: &TypeAnnotation,
+    sup_access: TypeTableAccess,
+    sup_value: &TypeAnnotation,
+) -> bool {
+    match sup_access {
+        TypeTableAccess::Write => {
+            sub_access != TypeTableAccess::Read && subtype(nominal, sup_value, sub_value)
+        }
+        TypeTableAccess::Read => {
+            sub_access != TypeTableAccess::Write && subtype(nominal, sub_value, sup_value)
+        }
+        TypeTableAccess::ReadWrite => {
+            sub_access == TypeTableAccess::ReadWrite && subtype(nominal, sub_value, sup_value)
+        }
+    }
+}
+
+fn function_subtype(nominal: Nominal, sub: &TypeFunction, sup: &TypeFunction) -> bool {
+    let parameters = |function: &TypeFunction| {
+        TypePack::new(
+            function
+                .arguments()
+                .iter()
+                .map(|argument| argument.val().clone())
+                .collect(),
+            function.variadic().cloned(),
+        )
+    };
+
+    // Generic functions are compared up to the names of their generics, as `<T>(T) -> T` and
+    // `<U>(U) -> U` are the same function.
+    let Some(sup) = rename_generics(sup, sub.generics()) else {
+        return false;
+    };
+
+    pack_subtype(nominal, &parameters(&sup), &parameters(sub))
+        && pack_subtype(nominal, sub.result(), sup.result())
+}
+
+/// Returns the function with its generics renamed to the given ones, by position, or `None` if
+/// it does not declare as many generics of the same kinds.
+fn rename_generics(
+    function: &TypeFunction,
+    generics: &[TypeGenericParameter],
+) -> Option<TypeFunction> {
+    if function.generics().len() != generics.len() {
+        return None;
+    }
+
+    let mut renaming = HashMap::new();
+
+    for (from, to) in function.generics().iter().zip(generics) {
+        match (from, to) {
+            (TypeGenericParameter::Type(_), TypeGenericParameter::Type(_))
+            | (TypeGenericParameter::Pack(_), TypeGenericParameter::Pack(_)) => {
+                renaming.insert(from.name().to_owned(), to.name().to_owned());
+            }
+            _ => return None,
+        }
+    }
+
+    let (_, arguments, variadic, result) =
+        fold_type_function(&mut Renaming(renaming), function.clone()).into_parts();
+
+    Some(TypeFunction::new(
+        generics.to_vec(),
+        arguments,
+        variadic,
+        result,
+    ))
+}
+
+/// Renames the generics of a function where they are referred to, leaving the generics of the
+/// functions within it that declare the same names.
+struct Renaming(HashMap<String, String>);
+
+impl Fold for Renaming {
+    fn fold_type_reference(&mut self, annotation: TypeReference) -> TypeAnnotation {
+        match self.0.get(annotation.name()) {
+            Some(name) if annotation.module().is_none() && annotation.arguments().is_empty() => {
+                TypeReference::named(name).into()
+            }
+            _ => fold_type_reference(self, annotation).into(),
+        }
+    }
+
+    fn fold_type_pack_tail(&mut self, tail: TypePackTail) -> TypePackTail {
+        match tail {
+            TypePackTail::Generic(name) => match self.0.get(&name) {
+                Some(renamed) => TypePackTail::Generic(renamed.clone()),
+                None => TypePackTail::Generic(name),
+            },
+            tail => fold_type_pack_tail(self, tail),
+        }
+    }
+
+    fn fold_type_function(&mut self, annotation: TypeFunction) -> TypeAnnotation {
+        let shadowed = annotation
+            .generics()
+            .iter()
+            .filter_map(|generic| self.0.remove_entry(generic.name()))
+            .collect::<Vec<_>>();
+
+        let function = fold_type_function(self, annotation);
+        self.0.extend(shadowed);
+
+        function.into()
+    }
+}
+
+/// Returns whether the values of the pack `sub` can be passed where the pack `sup` is expected,
+/// where missing values are `nil` and extra values are dropped.
+fn pack_subtype(nominal: Nominal, sub: &TypePack, sup: &TypePack) -> bool {
+    let length = sub.types().len().max(sup.types().len());
+
+    for index in 0..length {
+        let expected = match (sup.types().get(index), sup.pack_tail()) {
+            (Some(annotation), _) => annotation.clone(),
+            (None, Some(TypePackTail::Variadic(annotation))) => (**annotation).clone(),
+            (None, Some(TypePackTail::Generic(_))) => return false,
+            (None, None) => continue,
+        };
+
+        let actual = match (sub.types().get(index), sub.pack_tail()) {
+            (Some(annotation), _) => annotation.clone(),
+            // A variadic tail can have fewer values than expected.
+            (None, Some(TypePackTail::Variadic(annotation))) => {
+                normalize(TypeOptional::new((**annotation).clone()).into())
+            }
+            (None, Some(TypePackTail::Generic(_))) => return false,
+            (None, None) => TypePrimitive::Nil.into(),
+        };
+
+        if !subtype(nominal, &actual, &expected) {
+            return false;
+        }
+    }
+
+    match (sub.pack_tail(), sup.pack_tail()) {
+        (Some(TypePackTail::Variadic(sub)), Some(TypePackTail::Variadic(sup))) => {
+            subtype(nominal, sub, sup)
+        }
+        (Some(TypePackTail::Generic(sub)), Some(TypePackTail::Generic(sup))) => sub == sup,
+        (None, _) | (Some(_), None) => true,
+        _ => false,
+    }
+}
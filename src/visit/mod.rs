@@ -0,0 +1,5 @@
+pub mod visitor;
+pub mod visitor_mut;
+
+pub use visitor::Visitor;
+pub use visitor_mut::VisitorMut;
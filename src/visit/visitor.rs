@@ -0,0 +1,646 @@
+use crate::ast::{
+    Assign, BinaryOperation, Binding, Block, BooleanLiteral, Break, CompoundAssign, Continue, Do,
+    ElseIf, Expression, Function, FunctionCall, FunctionDeclaration, FunctionVariadic, GenericFor,
+    Identifier, If, IfExpression, Index, IndexKey, InterpolatedString, LocalAssign, LocalFunction,
+    NilLiteral, NumberLiteral, NumericFor, Parenthesized, Repeat, Return, Statement, StringLiteral,
+    TableConstructor, TableField, TypeAnnotation, TypeArgument, TypeArray, TypeAssertion,
+    TypeBuiltIn, TypeCombination, TypeDeclaration, TypeFunction, TypeGeneric, TypeGenericPack,
+    TypeGenericParameter, TypeOptional, TypePack, TypePackTail, TypePrimitive, TypeReference,
+    TypeReferenceArgument, TypeSingleton, TypeTable, TypeTableEntry, TypeTableIndexer,
+    UnaryOperation, VarargLiteral, While,
+};
+
+/// Walks a syntax tree by shared reference.
+///
+/// Every method defaults to visiting the children of its node through the matching `walk_*` function,
+/// so an implementation only needs to override the nodes it cares about. An overriding method can call
+/// the `walk_*` function itself to keep descending into the children.
+/// ```
+/// use luna::ast::{BinaryOperation, NumberLiteral, UnaryOperation};
+/// use luna::visit::Visitor;
+///
+/// #[derive(Default)]
+/// struct NumberCounter(usize);
+///
+/// impl Visitor for NumberCounter {
+///     fn visit_number_literal(&mut self, _literal: &NumberLiteral) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let expression = BinaryOperation::add(
+///     NumberLiteral::from("1"),
+///     UnaryOperation::negate(NumberLiteral::from("2")),
+/// );
+///
+/// let mut counter = NumberCounter::default();
+/// counter.visit_expression(&expression.into());
+/// assert_eq!(counter.0, 2);
+/// ```
+pub trait Visitor {
+    // Statements
+
+    fn visit_statement(&mut self, statement: &Statement) {
+        walk_statement(self, statement);
+    }
+
+    fn visit_local_assign(&mut self, local_assign: &LocalAssign) {
+        walk_local_assign(self, local_assign);
+    }
+
+    fn visit_assign(&mut self, assign: &Assign) {
+        walk_assign(self, assign);
+    }
+
+    fn visit_compound_assign(&mut self, compound_assign: &CompoundAssign) {
+        walk_compound_assign(self, compound_assign);
+    }
+
+    fn visit_do(&mut self, do_block: &Do) {
+        walk_do(self, do_block);
+    }
+
+    fn visit_while(&mut self, while_loop: &While) {
+        walk_while(self, while_loop);
+    }
+
+    fn visit_repeat(&mut self, repeat_loop: &Repeat) {
+        walk_repeat(self, repeat_loop);
+    }
+
+    fn visit_if(&mut self, if_statement: &If) {
+        walk_if(self, if_statement);
+    }
+
+    fn visit_else_if(&mut self, else_if: &ElseIf) {
+        walk_else_if(self, else_if);
+    }
+
+    fn visit_numeric_for(&mut self, numeric_for: &NumericFor) {
+        walk_numeric_for(self, numeric_for);
+    }
+
+    fn visit_generic_for(&mut self, generic_for: &GenericFor) {
+        walk_generic_for(self, generic_for);
+    }
+
+    fn visit_function_declaration(&mut self, function_declaration: &FunctionDeclaration) {
+        walk_function_declaration(self, function_declaration);
+    }
+
+    fn visit_local_function(&mut self, local_function: &LocalFunction) {
+        walk_local_function(self, local_function);
+    }
+
+    fn visit_return(&mut self, return_statement: &Return) {
+        walk_return(self, return_statement);
+    }
+
+    fn visit_break(&mut self, _break_statement: &Break) {}
+
+    fn visit_continue(&mut self, _continue_statement: &Continue) {}
+
+    fn visit_type_declaration(&mut self, type_declaration: &TypeDeclaration) {
+        walk_type_declaration(self, type_declaration);
+    }
+
+    // Expressions
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    fn visit_nil_literal(&mut self, _literal: &NilLiteral) {}
+
+    fn visit_boolean_literal(&mut self, _literal: &BooleanLiteral) {}
+
+    fn visit_string_literal(&mut self, _literal: &StringLiteral) {}
+
+    fn visit_number_literal(&mut self, _literal: &NumberLiteral) {}
+
+    fn visit_vararg_literal(&mut self, _literal: &VarargLiteral) {}
+
+    fn visit_interpolated_string(&mut self, string: &InterpolatedString) {
+        walk_interpolated_string(self, string);
+    }
+
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+
+    fn visit_index(&mut self, index: &Index) {
+        walk_index(self, index);
+    }
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        walk_function_call(self, call);
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        walk_function(self, function);
+    }
+
+    fn visit_function_variadic(&mut self, variadic: &FunctionVariadic) {
+        walk_function_variadic(self, variadic);
+    }
+
+    fn visit_table_constructor(&mut self, table: &TableConstructor) {
+        walk_table_constructor(self, table);
+    }
+
+    fn visit_table_field(&mut self, field: &TableField) {
+        walk_table_field(self, field);
+    }
+
+    fn visit_parenthesized(&mut self, parenthesized: &Parenthesized) {
+        walk_parenthesized(self, parenthesized);
+    }
+
+    fn visit_if_expression(&mut self, if_expression: &IfExpression) {
+        walk_if_expression(self, if_expression);
+    }
+
+    fn visit_type_assertion(&mut self, assertion: &TypeAssertion) {
+        walk_type_assertion(self, assertion);
+    }
+
+    fn visit_unary_operation(&mut self, operation: &UnaryOperation) {
+        walk_unary_operation(self, operation);
+    }
+
+    fn visit_binary_operation(&mut self, operation: &BinaryOperation) {
+        walk_binary_operation(self, operation);
+    }
+
+    // Constructs
+
+    fn visit_block(&mut self, block: &Block) {
+        walk_block(self, block);
+    }
+
+    fn visit_binding(&mut self, binding: &Binding) {
+        walk_binding(self, binding);
+    }
+
+    // Type annotations
+
+    fn visit_type_annotation(&mut self, annotation: &TypeAnnotation) {
+        walk_type_annotation(self, annotation);
+    }
+
+    fn visit_type_built_in(&mut self, _annotation: &TypeBuiltIn) {}
+
+    fn visit_type_primitive(&mut self, _annotation: &TypePrimitive) {}
+
+    fn visit_type_singleton(&mut self, _annotation: &TypeSingleton) {}
+
+    fn visit_type_reference(&mut self, annotation: &TypeReference) {
+        walk_type_reference(self, annotation);
+    }
+
+    fn visit_type_reference_argument(&mut self, argument: &TypeReferenceArgument) {
+        walk_type_reference_argument(self, argument);
+    }
+
+    fn visit_type_function(&mut self, annotation: &TypeFunction) {
+        walk_type_function(self, annotation);
+    }
+
+    fn visit_type_argument(&mut self, argument: &TypeArgument) {
+        walk_type_argument(self, argument);
+    }
+
+    fn visit_type_table(&mut self, annotation: &TypeTable) {
+        walk_type_table(self, annotation);
+    }
+
+    fn visit_type_table_entry(&mut self, entry: &TypeTableEntry) {
+        walk_type_table_entry(self, entry);
+    }
+
+    fn visit_type_table_indexer(&mut self, indexer: &TypeTableIndexer) {
+        walk_type_table_indexer(self, indexer);
+    }
+
+    fn visit_type_array(&mut self, annotation: &TypeArray) {
+        walk_type_array(self, annotation);
+    }
+
+    fn visit_type_optional(&mut self, annotation: &TypeOptional) {
+        walk_type_optional(self, annotation);
+    }
+
+    fn visit_type_combination(&mut self, annotation: &TypeCombination) {
+        walk_type_combination(self, annotation);
+    }
+
+    fn visit_type_pack(&mut self, pack: &TypePack) {
+        walk_type_pack(self, pack);
+    }
+
+    fn visit_type_pack_tail(&mut self, tail: &TypePackTail) {
+        walk_type_pack_tail(self, tail);
+    }
+
+    fn visit_type_generic_parameter(&mut self, parameter: &TypeGenericParameter) {
+        walk_type_generic_parameter(self, parameter);
+    }
+
+    fn visit_type_generic(&mut self, generic: &TypeGeneric) {
+        walk_type_generic(self, generic);
+    }
+
+    fn visit_type_generic_pack(&mut self, generic: &TypeGenericPack) {
+        walk_type_generic_pack(self, generic);
+    }
+}
+
+// Statements
+
+pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, statement: &Statement) {
+    match statement {
+        Statement::LocalAssign(node) => visitor.visit_local_assign(node),
+        Statement::Assign(node) => visitor.visit_assign(node),
+        Statement::CompoundAssign(node) => visitor.visit_compound_assign(node),
+        Statement::FunctionCall(node) => visitor.visit_function_call(node),
+        Statement::Do(node) => visitor.visit_do(node),
+        Statement::While(node) => visitor.visit_while(node),
+        Statement::Repeat(node) => visitor.visit_repeat(node),
+        Statement::If(node) => visitor.visit_if(node),
+        Statement::NumericFor(node) => visitor.visit_numeric_for(node),
+        Statement::GenericFor(node) => visitor.visit_generic_for(node),
+        Statement::FunctionDeclaration(node) => visitor.visit_function_declaration(node),
+        Statement::LocalFunction(node) => visitor.visit_local_function(node),
+        Statement::Return(node) => visitor.visit_return(node),
+        Statement::Break(node) => visitor.visit_break(node),
+        Statement::Continue(node) => visitor.visit_continue(node),
+        Statement::TypeDeclaration(node) => visitor.visit_type_declaration(node),
+    }
+}
+
+pub fn walk_local_assign<V: Visitor + ?Sized>(visitor: &mut V, local_assign: &LocalAssign) {
+    for binding in local_assign.bindings() {
+        visitor.visit_binding(binding);
+    }
+
+    for value in local_assign.values() {
+        visitor.visit_expression(value);
+    }
+}
+
+pub fn walk_assign<V: Visitor + ?Sized>(visitor: &mut V, assign: &Assign) {
+    for target in assign.targets() {
+        visitor.visit_expression(target);
+    }
+
+    for value in assign.values() {
+        visitor.visit_expression(value);
+    }
+}
+
+pub fn walk_compound_assign<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    compound_assign: &CompoundAssign,
+) {
+    visitor.visit_expression(compound_assign.target());
+    visitor.visit_expression(compound_assign.value());
+}
+
+pub fn walk_do<V: Visitor + ?Sized>(visitor: &mut V, do_block: &Do) {
+    visitor.visit_block(do_block.block());
+}
+
+pub fn walk_while<V: Visitor + ?Sized>(visitor: &mut V, while_loop: &While) {
+    visitor.visit_expression(while_loop.condition());
+    visitor.visit_block(while_loop.block());
+}
+
+pub fn walk_repeat<V: Visitor + ?Sized>(visitor: &mut V, repeat_loop: &Repeat) {
+    visitor.visit_block(repeat_loop.block());
+    visitor.visit_expression(repeat_loop.condition());
+}
+
+pub fn walk_if<V: Visitor + ?Sized>(visitor: &mut V, if_statement: &If) {
+    visitor.visit_expression(if_statement.condition());
+    visitor.visit_block(if_statement.block());
+
+    for else_if in if_statement.else_ifs() {
+        visitor.visit_else_if(else_if);
+    }
+
+    if let Some(block) = if_statement.else_block() {
+        visitor.visit_block(block);
+    }
+}
+
+pub fn walk_else_if<V: Visitor + ?Sized>(visitor: &mut V, else_if: &ElseIf) {
+    visitor.visit_expression(else_if.condition());
+    visitor.visit_block(else_if.block());
+}
+
+pub fn walk_numeric_for<V: Visitor + ?Sized>(visitor: &mut V, numeric_for: &NumericFor) {
+    visitor.visit_binding(numeric_for.binding());
+    visitor.visit_expression(numeric_for.start());
+    visitor.visit_expression(numeric_for.end());
+
+    if let Some(step) = numeric_for.step() {
+        visitor.visit_expression(step);
+    }
+
+    visitor.visit_block(numeric_for.block());
+}
+
+pub fn walk_generic_for<V: Visitor + ?Sized>(visitor: &mut V, generic_for: &GenericFor) {
+    for binding in generic_for.bindings() {
+        visitor.visit_binding(binding);
+    }
+
+    for value in generic_for.values() {
+        visitor.visit_expression(value);
+    }
+
+    visitor.visit_block(generic_for.block());
+}
+
+pub fn walk_function_declaration<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    function_declaration: &FunctionDeclaration,
+) {
+    visitor.visit_function(function_declaration.function());
+}
+
+pub fn walk_local_function<V: Visitor + ?Sized>(visitor: &mut V, local_function: &LocalFunction) {
+    visitor.visit_function(local_function.function());
+}
+
+pub fn walk_return<V: Visitor + ?Sized>(visitor: &mut V, return_statement: &Return) {
+    for value in return_statement.values() {
+        visitor.visit_expression(value);
+    }
+}
+
+pub fn walk_type_declaration<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    type_declaration: &TypeDeclaration,
+) {
+    for generic in type_declaration.generics() {
+        visitor.visit_type_generic_parameter(generic);
+    }
+
+    visitor.visit_type_annotation(type_declaration.annotation());
+}
+
+// Expressions
+
+pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    match expression {
+        Expression::NilLiteral(node) => visitor.visit_nil_literal(node),
+        Expression::BooleanLiteral(node) => visitor.visit_boolean_literal(node),
+        Expression::StringLiteral(node) => visitor.visit_string_literal(node),
+        Expression::NumberLiteral(node) => visitor.visit_number_literal(node),
+        Expression::VarargLiteral(node) => visitor.visit_vararg_literal(node),
+        Expression::InterpolatedString(node) => visitor.visit_interpolated_string(node),
+
+        Expression::Identifier(node) => visitor.visit_identifier(node),
+        Expression::Index(node) => visitor.visit_index(node),
+        Expression::Function(node) => visitor.visit_function(node),
+        Expression::TableConstructor(node) => visitor.visit_table_constructor(node),
+        Expression::FunctionCall(node) => visitor.visit_function_call(node),
+
+        Expression::UnaryOperation(node) => visitor.visit_unary_operation(node),
+        Expression::BinaryOperation(node) => visitor.visit_binary_operation(node),
+        Expression::Parenthesized(node) => visitor.visit_parenthesized(node),
+        Expression::IfExpression(node) => visitor.visit_if_expression(node),
+        Expression::TypeAssertion(node) => visitor.visit_type_assertion(node),
+    }
+}
+
+pub fn walk_interpolated_string<V: Visitor + ?Sized>(visitor: &mut V, string: &InterpolatedString) {
+    for expression in string.expressions() {
+        visitor.visit_expression(expression);
+    }
+}
+
+pub fn walk_index<V: Visitor + ?Sized>(visitor: &mut V, index: &Index) {
+    visitor.visit_expression(index.object());
+
+    if let IndexKey::Computed(key) = index.key() {
+        visitor.visit_expression(key);
+    }
+}
+
+pub fn walk_function_call<V: Visitor + ?Sized>(visitor: &mut V, call: &FunctionCall) {
+    visitor.visit_expression(call.function());
+
+    for argument in call.arguments() {
+        visitor.visit_expression(argument);
+    }
+}
+
+pub fn walk_function<V: Visitor + ?Sized>(visitor: &mut V, function: &Function) {
+    for generic in function.generics() {
+        visitor.visit_type_generic_parameter(generic);
+    }
+
+    for parameter in function.parameters() {
+        visitor.visit_binding(parameter);
+    }
+
+    if let Some(variadic) = function.variadic() {
+        visitor.visit_function_variadic(variadic);
+    }
+
+    if let Some(returns) = function.returns() {
+        visitor.visit_type_pack(returns);
+    }
+
+    visitor.visit_block(function.block());
+}
+
+pub fn walk_function_variadic<V: Visitor + ?Sized>(visitor: &mut V, variadic: &FunctionVariadic) {
+    if let Some(annotation) = variadic.annotation() {
+        visitor.visit_type_pack_tail(annotation);
+    }
+}
+
+pub fn walk_table_constructor<V: Visitor + ?Sized>(visitor: &mut V, table: &TableConstructor) {
+    for field in table.fields() {
+        visitor.visit_table_field(field);
+    }
+}
+
+pub fn walk_table_field<V: Visitor + ?Sized>(visitor: &mut V, field: &TableField) {
+    if let TableField::Keyed(key, _) = field {
+        visitor.visit_expression(key);
+    }
+
+    visitor.visit_expression(field.value());
+}
+
+pub fn walk_parenthesized<V: Visitor + ?Sized>(visitor: &mut V, parenthesized: &Parenthesized) {
+    visitor.visit_expression(parenthesized.expression());
+}
+
+pub fn walk_if_expression<V: Visitor + ?Sized>(visitor: &mut V, if_expression: &IfExpression) {
+    visitor.visit_expression(if_expression.condition());
+    visitor.visit_expression(if_expression.consequent());
+
+    for (condition, consequent) in if_expression.else_ifs() {
+        visitor.visit_expression(condition);
+        visitor.visit_expression(consequent);
+    }
+
+    visitor.visit_expression(if_expression.alternative());
+}
+
+pub fn walk_type_assertion<V: Visitor + ?Sized>(visitor: &mut V, assertion: &TypeAssertion) {
+    visitor.visit_expression(assertion.expression());
+    visitor.visit_type_annotation(assertion.annotation());
+}
+
+pub fn walk_unary_operation<V: Visitor + ?Sized>(visitor: &mut V, operation: &UnaryOperation) {
+    visitor.visit_expression(operation.operand());
+}
+
+pub fn walk_binary_operation<V: Visitor + ?Sized>(visitor: &mut V, operation: &BinaryOperation) {
+    visitor.visit_expression(operation.left());
+    visitor.visit_expression(operation.right());
+}
+
+// Constructs
+
+pub fn walk_block<V: Visitor + ?Sized>(visitor: &mut V, block: &Block) {
+    for statement in block.statements() {
+        visitor.visit_statement(statement);
+    }
+}
+
+pub fn walk_binding<V: Visitor + ?Sized>(visitor: &mut V, binding: &Binding) {
+    if let Some(annotation) = &binding.annotation {
+        visitor.visit_type_annotation(annotation);
+    }
+}
+
+// Type annotations
+
+pub fn walk_type_annotation<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeAnnotation) {
+    match annotation {
+        TypeAnnotation::BuiltIn(node) => visitor.visit_type_built_in(node),
+        TypeAnnotation::Primitive(node) => visitor.visit_type_primitive(node),
+        TypeAnnotation::Reference(node) => visitor.visit_type_reference(node),
+        TypeAnnotation::Function(node) => visitor.visit_type_function(node),
+        TypeAnnotation::Table(node) => visitor.visit_type_table(node),
+        TypeAnnotation::Array(node) => visitor.visit_type_array(node),
+        TypeAnnotation::Singleton(node) => visitor.visit_type_singleton(node),
+        TypeAnnotation::Optional(node) => visitor.visit_type_optional(node),
+        TypeAnnotation::Combination(node) => visitor.visit_type_combination(node),
+        TypeAnnotation::Typeof(node) => visitor.visit_expression(node),
+    }
+}
+
+pub fn walk_type_reference<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeReference) {
+    for argument in annotation.arguments() {
+        visitor.visit_type_reference_argument(argument);
+    }
+}
+
+pub fn walk_type_reference_argument<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    argument: &TypeReferenceArgument,
+) {
+    match argument {
+        TypeReferenceArgument::Type(node) => visitor.visit_type_annotation(node),
+        TypeReferenceArgument::Pack(node) => visitor.visit_type_pack(node),
+    }
+}
+
+pub fn walk_type_function<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeFunction) {
+    for generic in annotation.generics() {
+        visitor.visit_type_generic_parameter(generic);
+    }
+
+    for argument in annotation.arguments() {
+        visitor.visit_type_argument(argument);
+    }
+
+    if let Some(variadic) = annotation.variadic() {
+        visitor.visit_type_pack_tail(variadic);
+    }
+
+    visitor.visit_type_pack(annotation.result());
+}
+
+pub fn walk_type_argument<V: Visitor + ?Sized>(visitor: &mut V, argument: &TypeArgument) {
+    visitor.visit_type_annotation(argument.val());
+}
+
+pub fn walk_type_table<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeTable) {
+    for entry in annotation.entries() {
+        visitor.visit_type_table_entry(entry);
+    }
+
+    if let Some(indexer) = annotation.indexer() {
+        visitor.visit_type_table_indexer(indexer);
+    }
+}
+
+pub fn walk_type_table_entry<V: Visitor + ?Sized>(visitor: &mut V, entry: &TypeTableEntry) {
+    visitor.visit_type_annotation(entry.value());
+}
+
+pub fn walk_type_table_indexer<V: Visitor + ?Sized>(visitor: &mut V, indexer: &TypeTableIndexer) {
+    visitor.visit_type_annotation(indexer.index());
+    visitor.visit_type_annotation(indexer.value());
+}
+
+pub fn walk_type_array<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeArray) {
+    if let Some(item) = annotation.item() {
+        visitor.visit_type_annotation(item);
+    }
+}
+
+pub fn walk_type_optional<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeOptional) {
+    visitor.visit_type_annotation(annotation.annotation());
+}
+
+pub fn walk_type_combination<V: Visitor + ?Sized>(visitor: &mut V, annotation: &TypeCombination) {
+    visitor.visit_type_annotation(annotation.left());
+    visitor.visit_type_annotation(annotation.right());
+}
+
+pub fn walk_type_pack<V: Visitor + ?Sized>(visitor: &mut V, pack: &TypePack) {
+    for annotation in pack.types() {
+        visitor.visit_type_annotation(annotation);
+    }
+
+    if let Some(tail) = pack.pack_tail() {
+        visitor.visit_type_pack_tail(tail);
+    }
+}
+
+pub fn walk_type_pack_tail<V: Visitor + ?Sized>(visitor: &mut V, tail: &TypePackTail) {
+    match tail {
+        TypePackTail::Variadic(node) => visitor.visit_type_annotation(node),
+        TypePackTail::Generic(_) => {}
+    }
+}
+
+pub fn walk_type_generic_parameter<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    parameter: &TypeGenericParameter,
+) {
+    match parameter {
+        TypeGenericParameter::Type(node) => visitor.visit_type_generic(node),
+        TypeGenericParameter::Pack(node) => visitor.visit_type_generic_pack(node),
+    }
+}
+
+pub fn walk_type_generic<V: Visitor + ?Sized>(visitor: &mut V, generic: &TypeGeneric) {
+    if let Some(default) = generic.default() {
+        visitor.visit_type_annotation(default);
+    }
+}
+
+pub fn walk_type_generic_pack<V: Visitor + ?Sized>(visitor: &mut V, generic: &TypeGenericPack) {
+    if let Some(default) = generic.default() {
+        visitor.visit_type_pack(default);
+    }
+}
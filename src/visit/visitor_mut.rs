@@ -0,0 +1,703 @@
+use crate::ast::{
+    Assign, BinaryOperation, Binding, Block, BooleanLiteral, Break, CompoundAssign, Continue, Do,
+    ElseIf, Expression, Function, FunctionCall, FunctionDeclaration, FunctionVariadic, GenericFor,
+    Identifier, If, IfExpression, Index, IndexKey, InterpolatedString, LocalAssign, LocalFunction,
+    NilLiteral, NumberLiteral, NumericFor, Parenthesized, Repeat, Return, Statement, StringLiteral,
+    TableConstructor, TableField, TypeAnnotation, TypeArgument, TypeArray, TypeAssertion,
+    TypeBuiltIn, TypeCombination, TypeDeclaration, TypeFunction, TypeGeneric, TypeGenericPack,
+    TypeGenericParameter, TypeOptional, TypePack, TypePackTail, TypePrimitive, TypeReference,
+    TypeReferenceArgument, TypeSingleton, TypeTable, TypeTableEntry, TypeTableIndexer,
+    UnaryOperation, VarargLiteral, While,
+};
+
+/// Walks a syntax tree by mutable reference, allowing nodes to be modified in place.
+///
+/// This mirrors [`Visitor`](crate::visit::Visitor), with every method and `walk_*` function suffixed with `_mut`.
+/// ```
+/// use luna::ast::{BinaryOperation, Expression, NumberLiteral};
+/// use luna::visit::VisitorMut;
+///
+/// struct Doubler;
+///
+/// impl VisitorMut for Doubler {
+///     fn visit_number_literal_mut(&mut self, literal: &mut NumberLiteral) {
+///         let value: f64 = literal.value().parse().unwrap();
+///         *literal = NumberLiteral::from((value * 2.0).to_string());
+///     }
+/// }
+///
+/// let mut expression: Expression = BinaryOperation::add(
+///     NumberLiteral::from("1"),
+///     NumberLiteral::from("2"),
+/// ).into();
+///
+/// Doubler.visit_expression_mut(&mut expression);
+/// assert_eq!(expression, BinaryOperation::add(
+///     NumberLiteral::from("2"),
+///     NumberLiteral::from("4"),
+/// ).into());
+/// ```
+pub trait VisitorMut {
+    // Statements
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        walk_statement_mut(self, statement);
+    }
+
+    fn visit_local_assign_mut(&mut self, local_assign: &mut LocalAssign) {
+        walk_local_assign_mut(self, local_assign);
+    }
+
+    fn visit_assign_mut(&mut self, assign: &mut Assign) {
+        walk_assign_mut(self, assign);
+    }
+
+    fn visit_compound_assign_mut(&mut self, compound_assign: &mut CompoundAssign) {
+        walk_compound_assign_mut(self, compound_assign);
+    }
+
+    fn visit_do_mut(&mut self, do_block: &mut Do) {
+        walk_do_mut(self, do_block);
+    }
+
+    fn visit_while_mut(&mut self, while_loop: &mut While) {
+        walk_while_mut(self, while_loop);
+    }
+
+    fn visit_repeat_mut(&mut self, repeat_loop: &mut Repeat) {
+        walk_repeat_mut(self, repeat_loop);
+    }
+
+    fn visit_if_mut(&mut self, if_statement: &mut If) {
+        walk_if_mut(self, if_statement);
+    }
+
+    fn visit_else_if_mut(&mut self, else_if: &mut ElseIf) {
+        walk_else_if_mut(self, else_if);
+    }
+
+    fn visit_numeric_for_mut(&mut self, numeric_for: &mut NumericFor) {
+        walk_numeric_for_mut(self, numeric_for);
+    }
+
+    fn visit_generic_for_mut(&mut self, generic_for: &mut GenericFor) {
+        walk_generic_for_mut(self, generic_for);
+    }
+
+    fn visit_function_declaration_mut(&mut self, function_declaration: &mut FunctionDeclaration) {
+        walk_function_declaration_mut(self, function_declaration);
+    }
+
+    fn visit_local_function_mut(&mut self, local_function: &mut LocalFunction) {
+        walk_local_function_mut(self, local_function);
+    }
+
+    fn visit_return_mut(&mut self, return_statement: &mut Return) {
+        walk_return_mut(self, return_statement);
+    }
+
+    fn visit_break_mut(&mut self, _break_statement: &mut Break) {}
+
+    fn visit_continue_mut(&mut self, _continue_statement: &mut Continue) {}
+
+    fn visit_type_declaration_mut(&mut self, type_declaration: &mut TypeDeclaration) {
+        walk_type_declaration_mut(self, type_declaration);
+    }
+
+    // Expressions
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+    }
+
+    fn visit_nil_literal_mut(&mut self, _literal: &mut NilLiteral) {}
+
+    fn visit_boolean_literal_mut(&mut self, _literal: &mut BooleanLiteral) {}
+
+    fn visit_string_literal_mut(&mut self, _literal: &mut StringLiteral) {}
+
+    fn visit_number_literal_mut(&mut self, _literal: &mut NumberLiteral) {}
+
+    fn visit_vararg_literal_mut(&mut self, _literal: &mut VarargLiteral) {}
+
+    fn visit_interpolated_string_mut(&mut self, string: &mut InterpolatedString) {
+        walk_interpolated_string_mut(self, string);
+    }
+
+    fn visit_identifier_mut(&mut self, _identifier: &mut Identifier) {}
+
+    fn visit_index_mut(&mut self, index: &mut Index) {
+        walk_index_mut(self, index);
+    }
+
+    fn visit_function_call_mut(&mut self, call: &mut FunctionCall) {
+        walk_function_call_mut(self, call);
+    }
+
+    fn visit_function_mut(&mut self, function: &mut Function) {
+        walk_function_mut(self, function);
+    }
+
+    fn visit_function_variadic_mut(&mut self, variadic: &mut FunctionVariadic) {
+        walk_function_variadic_mut(self, variadic);
+    }
+
+    fn visit_table_constructor_mut(&mut self, table: &mut TableConstructor) {
+        walk_table_constructor_mut(self, table);
+    }
+
+    fn visit_table_field_mut(&mut self, field: &mut TableField) {
+        walk_table_field_mut(self, field);
+    }
+
+    fn visit_parenthesized_mut(&mut self, parenthesized: &mut Parenthesized) {
+        walk_parenthesized_mut(self, parenthesized);
+    }
+
+    fn visit_if_expression_mut(&mut self, if_expression: &mut IfExpression) {
+        walk_if_expression_mut(self, if_expression);
+    }
+
+    fn visit_type_assertion_mut(&mut self, assertion: &mut TypeAssertion) {
+        walk_type_assertion_mut(self, assertion);
+    }
+
+    fn visit_unary_operation_mut(&mut self, operation: &mut UnaryOperation) {
+        walk_unary_operation_mut(self, operation);
+    }
+
+    fn visit_binary_operation_mut(&mut self, operation: &mut BinaryOperation) {
+        walk_binary_operation_mut(self, operation);
+    }
+
+    // Constructs
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        walk_block_mut(self, block);
+    }
+
+    fn visit_binding_mut(&mut self, binding: &mut Binding) {
+        walk_binding_mut(self, binding);
+    }
+
+    // Type annotations
+
+    fn visit_type_annotation_mut(&mut self, annotation: &mut TypeAnnotation) {
+        walk_type_annotation_mut(self, annotation);
+    }
+
+    fn visit_type_built_in_mut(&mut self, _annotation: &mut TypeBuiltIn) {}
+
+    fn visit_type_primitive_mut(&mut self, _annotation: &mut TypePrimitive) {}
+
+    fn visit_type_singleton_mut(&mut self, _annotation: &mut TypeSingleton) {}
+
+    fn visit_type_reference_mut(&mut self, annotation: &mut TypeReference) {
+        walk_type_reference_mut(self, annotation);
+    }
+
+    fn visit_type_reference_argument_mut(&mut self, argument: &mut TypeReferenceArgument) {
+        walk_type_reference_argument_mut(self, argument);
+    }
+
+    fn visit_type_function_mut(&mut self, annotation: &mut TypeFunction) {
+        walk_type_function_mut(self, annotation);
+    }
+
+    fn visit_type_argument_mut(&mut self, argument: &mut TypeArgument) {
+        walk_type_argument_mut(self, argument);
+    }
+
+    fn visit_type_table_mut(&mut self, annotation: &mut TypeTable) {
+        walk_type_table_mut(self, annotation);
+    }
+
+    fn visit_type_table_entry_mut(&mut self, entry: &mut TypeTableEntry) {
+        walk_type_table_entry_mut(self, entry);
+    }
+
+    fn visit_type_table_indexer_mut(&mut self, indexer: &mut TypeTableIndexer) {
+        walk_type_table_indexer_mut(self, indexer);
+    }
+
+    fn visit_type_array_mut(&mut self, annotation: &mut TypeArray) {
+        walk_type_array_mut(self, annotation);
+    }
+
+    fn visit_type_optional_mut(&mut self, annotation: &mut TypeOptional) {
+        walk_type_optional_mut(self, annotation);
+    }
+
+    fn visit_type_combination_mut(&mut self, annotation: &mut TypeCombination) {
+        walk_type_combination_mut(self, annotation);
+    }
+
+    fn visit_type_pack_mut(&mut self, pack: &mut TypePack) {
+        walk_type_pack_mut(self, pack);
+    }
+
+    fn visit_type_pack_tail_mut(&mut self, tail: &mut TypePackTail) {
+        walk_type_pack_tail_mut(self, tail);
+    }
+
+    fn visit_type_generic_parameter_mut(&mut self, parameter: &mut TypeGenericParameter) {
+        walk_type_generic_parameter_mut(self, parameter);
+    }
+
+    fn visit_type_generic_mut(&mut self, generic: &mut TypeGeneric) {
+        walk_type_generic_mut(self, generic);
+    }
+
+    fn visit_type_generic_pack_mut(&mut self, generic: &mut TypeGenericPack) {
+        walk_type_generic_pack_mut(self, generic);
+    }
+}
+
+// Statements
+
+pub fn walk_statement_mut<V: VisitorMut + ?Sized>(visitor: &mut V, statement: &mut Statement) {
+    match statement {
+        Statement::LocalAssign(node) => visitor.visit_local_assign_mut(node),
+        Statement::Assign(node) => visitor.visit_assign_mut(node),
+        Statement::CompoundAssign(node) => visitor.visit_compound_assign_mut(node),
+        Statement::FunctionCall(node) => visitor.visit_function_call_mut(node),
+        Statement::Do(node) => visitor.visit_do_mut(node),
+        Statement::While(node) => visitor.visit_while_mut(node),
+        Statement::Repeat(node) => visitor.visit_repeat_mut(node),
+        Statement::If(node) => visitor.visit_if_mut(node),
+        Statement::NumericFor(node) => visitor.visit_numeric_for_mut(node),
+        Statement::GenericFor(node) => visitor.visit_generic_for_mut(node),
+        Statement::FunctionDeclaration(node) => visitor.visit_function_declaration_mut(node),
+        Statement::LocalFunction(node) => visitor.visit_local_function_mut(node),
+        Statement::Return(node) => visitor.visit_return_mut(node),
+        Statement::Break(node) => visitor.visit_break_mut(node),
+        Statement::Continue(node) => visitor.visit_continue_mut(node),
+        Statement::TypeDeclaration(node) => visitor.visit_type_declaration_mut(node),
+    }
+}
+
+pub fn walk_local_assign_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    local_assign: &mut LocalAssign,
+) {
+    for binding in local_assign.bindings_mut() {
+        visitor.visit_binding_mut(binding);
+    }
+
+    for value in local_assign.values_mut() {
+        visitor.visit_expression_mut(value);
+    }
+}
+
+pub fn walk_assign_mut<V: VisitorMut + ?Sized>(visitor: &mut V, assign: &mut Assign) {
+    for target in assign.targets_mut() {
+        visitor.visit_expression_mut(target);
+    }
+
+    for value in assign.values_mut() {
+        visitor.visit_expression_mut(value);
+    }
+}
+
+pub fn walk_compound_assign_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    compound_assign: &mut CompoundAssign,
+) {
+    visitor.visit_expression_mut(compound_assign.target_mut());
+    visitor.visit_expression_mut(compound_assign.value_mut());
+}
+
+pub fn walk_do_mut<V: VisitorMut + ?Sized>(visitor: &mut V, do_block: &mut Do) {
+    visitor.visit_block_mut(do_block.block_mut());
+}
+
+pub fn walk_while_mut<V: VisitorMut + ?Sized>(visitor: &mut V, while_loop: &mut While) {
+    visitor.visit_expression_mut(while_loop.condition_mut());
+    visitor.visit_block_mut(while_loop.block_mut());
+}
+
+pub fn walk_repeat_mut<V: VisitorMut + ?Sized>(visitor: &mut V, repeat_loop: &mut Repeat) {
+    visitor.visit_block_mut(repeat_loop.block_mut());
+    visitor.visit_expression_mut(repeat_loop.condition_mut());
+}
+
+pub fn walk_if_mut<V: VisitorMut + ?Sized>(visitor: &mut V, if_statement: &mut If) {
+    visitor.visit_expression_mut(if_statement.condition_mut());
+    visitor.visit_block_mut(if_statement.block_mut());
+
+    for else_if in if_statement.else_ifs_mut() {
+        visitor.visit_else_if_mut(else_if);
+    }
+
+    if let Some(block) = if_statement.else_block_mut() {
+        visitor.visit_block_mut(block);
+    }
+}
+
+pub fn walk_else_if_mut<V: VisitorMut + ?Sized>(visitor: &mut V, else_if: &mut ElseIf) {
+    visitor.visit_expression_mut(else_if.condition_mut());
+    visitor.visit_block_mut(else_if.block_mut());
+}
+
+pub fn walk_numeric_for_mut<V: VisitorMut + ?Sized>(visitor: &mut V, numeric_for: &mut NumericFor) {
+    visitor.visit_binding_mut(numeric_for.binding_mut());
+    visitor.visit_expression_mut(numeric_for.start_mut());
+    visitor.visit_expression_mut(numeric_for.end_mut());
+
+    if let Some(step) = numeric_for.step_mut() {
+        visitor.visit_expression_mut(step);
+    }
+
+    visitor.visit_block_mut(numeric_for.block_mut());
+}
+
+pub fn walk_generic_for_mut<V: VisitorMut + ?Sized>(visitor: &mut V, generic_for: &mut GenericFor) {
+    for binding in generic_for.bindings_mut() {
+        visitor.visit_binding_mut(binding);
+    }
+
+    for value in generic_for.values_mut() {
+        visitor.visit_expression_mut(value);
+    }
+
+    visitor.visit_block_mut(generic_for.block_mut());
+}
+
+pub fn walk_function_declaration_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    function_declaration: &mut FunctionDeclaration,
+) {
+    visitor.visit_function_mut(function_declaration.function_mut());
+}
+
+pub fn walk_local_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    local_function: &mut LocalFunction,
+) {
+    visitor.visit_function_mut(local_function.function_mut());
+}
+
+pub fn walk_return_mut<V: VisitorMut + ?Sized>(visitor: &mut V, return_statement: &mut Return) {
+    for value in return_statement.values_mut() {
+        visitor.visit_expression_mut(value);
+    }
+}
+
+pub fn walk_type_declaration_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    type_declaration: &mut TypeDeclaration,
+) {
+    for generic in type_declaration.generics_mut() {
+        visitor.visit_type_generic_parameter_mut(generic);
+    }
+
+    visitor.visit_type_annotation_mut(type_declaration.annotation_mut());
+}
+
+// Expressions
+
+pub fn walk_expression_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expression: &mut Expression) {
+    match expression {
+        Expression::NilLiteral(node) => visitor.visit_nil_literal_mut(node),
+        Expression::BooleanLiteral(node) => visitor.visit_boolean_literal_mut(node),
+        Expression::StringLiteral(node) => visitor.visit_string_literal_mut(node),
+        Expression::NumberLiteral(node) => visitor.visit_number_literal_mut(node),
+        Expression::VarargLiteral(node) => visitor.visit_vararg_literal_mut(node),
+        Expression::InterpolatedString(node) => visitor.visit_interpolated_string_mut(node),
+
+        Expression::Identifier(node) => visitor.visit_identifier_mut(node),
+        Expression::Index(node) => visitor.visit_index_mut(node),
+        Expression::Function(node) => visitor.visit_function_mut(node),
+        Expression::TableConstructor(node) => visitor.visit_table_constructor_mut(node),
+        Expression::FunctionCall(node) => visitor.visit_function_call_mut(node),
+
+        Expression::UnaryOperation(node) => visitor.visit_unary_operation_mut(node),
+        Expression::BinaryOperation(node) => visitor.visit_binary_operation_mut(node),
+        Expression::Parenthesized(node) => visitor.visit_parenthesized_mut(node),
+        Expression::IfExpression(node) => visitor.visit_if_expression_mut(node),
+        Expression::TypeAssertion(node) => visitor.visit_type_assertion_mut(node),
+    }
+}
+
+pub fn walk_interpolated_string_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    string: &mut InterpolatedString,
+) {
+    for expression in string.expressions_mut() {
+        visitor.visit_expression_mut(expression);
+    }
+}
+
+pub fn walk_index_mut<V: VisitorMut + ?Sized>(visitor: &mut V, index: &mut Index) {
+    visitor.visit_expression_mut(index.object_mut());
+
+    if let IndexKey::Computed(key) = index.key_mut() {
+        visitor.visit_expression_mut(key);
+    }
+}
+
+pub fn walk_function_call_mut<V: VisitorMut + ?Sized>(visitor: &mut V, call: &mut FunctionCall) {
+    visitor.visit_expression_mut(call.function_mut());
+
+    for argument in call.arguments_mut() {
+        visitor.visit_expression_mut(argument);
+    }
+}
+
+pub fn walk_function_mut<V: VisitorMut + ?Sized>(visitor: &mut V, function: &mut Function) {
+    for generic in function.generics_mut() {
+        visitor.visit_type_generic_parameter_mut(generic);
+    }
+
+    for parameter in function.parameters_mut() {
+        visitor.visit_binding_mut(parameter);
+    }
+
+    if let Some(variadic) = function.variadic_mut() {
+        visitor.visit_function_variadic_mut(variadic);
+    }
+
+    if let Some(returns) = function.returns_mut() {
+        visitor.visit_type_pack_mut(returns);
+    }
+
+    visitor.visit_block_mut(function.block_mut());
+}
+
+pub fn walk_function_variadic_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    variadic: &mut FunctionVariadic,
+) {
+    if let Some(annotation) = variadic.annotation_mut() {
+        visitor.visit_type_pack_tail_mut(annotation);
+    }
+}
+
+pub fn walk_table_constructor_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    table: &mut TableConstructor,
+) {
+    for field in table.fields_mut() {
+        visitor.visit_table_field_mut(field);
+    }
+}
+
+pub fn walk_table_field_mut<V: VisitorMut + ?Sized>(visitor: &mut V, field: &mut TableField) {
+    if let TableField::Keyed(key, _) = field {
+        visitor.visit_expression_mut(key);
+    }
+
+    visitor.visit_expression_mut(field.value_mut());
+}
+
+pub fn walk_parenthesized_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    parenthesized: &mut Parenthesized,
+) {
+    visitor.visit_expression_mut(parenthesized.expression_mut());
+}
+
+pub fn walk_if_expression_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    if_expression: &mut IfExpression,
+) {
+    visitor.visit_expression_mut(if_expression.condition_mut());
+    visitor.visit_expression_mut(if_expression.consequent_mut());
+
+    for (condition, consequent) in if_expression.else_ifs_mut() {
+        visitor.visit_expression_mut(condition);
+        visitor.visit_expression_mut(consequent);
+    }
+
+    visitor.visit_expression_mut(if_expression.alternative_mut());
+}
+
+pub fn walk_type_assertion_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    assertion: &mut TypeAssertion,
+) {
+    visitor.visit_expression_mut(assertion.expression_mut());
+    visitor.visit_type_annotation_mut(assertion.annotation_mut());
+}
+
+pub fn walk_unary_operation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    operation: &mut UnaryOperation,
+) {
+    visitor.visit_expression_mut(operation.operand_mut());
+}
+
+pub fn walk_binary_operation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    operation: &mut BinaryOperation,
+) {
+    visitor.visit_expression_mut(operation.left_mut());
+    visitor.visit_expression_mut(operation.right_mut());
+}
+
+// Constructs
+
+pub fn walk_block_mut<V: VisitorMut + ?Sized>(visitor: &mut V, block: &mut Block) {
+    for statement in block.statements_mut() {
+        visitor.visit_statement_mut(statement);
+    }
+}
+
+pub fn walk_binding_mut<V: VisitorMut + ?Sized>(visitor: &mut V, binding: &mut Binding) {
+    if let Some(annotation) = &mut binding.annotation {
+        visitor.visit_type_annotation_mut(annotation);
+    }
+}
+
+// Type annotations
+
+pub fn walk_type_annotation_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    annotation: &mut TypeAnnotation,
+) {
+    match annotation {
+        TypeAnnotation::BuiltIn(node) => visitor.visit_type_built_in_mut(node),
+        TypeAnnotation::Primitive(node) => visitor.visit_type_primitive_mut(node),
+        TypeAnnotation::Reference(node) => visitor.visit_type_reference_mut(node),
+        TypeAnnotation::Function(node) => visitor.visit_type_function_mut(node),
+        TypeAnnotation::Table(node) => visitor.visit_type_table_mut(node),
+        TypeAnnotation::Array(node) => visitor.visit_type_array_mut(node),
+        TypeAnnotation::Singleton(node) => visitor.visit_type_singleton_mut(node),
+        TypeAnnotation::Optional(node) => visitor.visit_type_optional_mut(node),
+        TypeAnnotation::Combination(node) => visitor.visit_type_combination_mut(node),
+        TypeAnnotation::Typeof(node) => visitor.visit_expression_mut(node),
+    }
+}
+
+pub fn walk_type_reference_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    annotation: &mut TypeReference,
+) {
+    for argument in annotation.arguments_mut() {
+        visitor.visit_type_reference_argument_mut(argument);
+    }
+}
+
+pub fn walk_type_reference_argument_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    argument: &mut TypeReferenceArgument,
+) {
+    match argument {
+        TypeReferenceArgument::Type(node) => visitor.visit_type_annotation_mut(node),
+        TypeReferenceArgument::Pack(node) => visitor.visit_type_pack_mut(node),
+    }
+}
+
+pub fn walk_type_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    annotation: &mut TypeFunction,
+) {
+    for generic in annotation.generics_mut() {
+        visitor.visit_type_generic_parameter_mut(generic);
+    }
+
+    for argument in annotation.arguments_mut() {
+        visitor.visit_type_argument_mut(argument);
+    }
+
+    if let Some(variadic) = annotation.variadic_mut() {
+        visitor.visit_type_pack_tail_mut(variadic);
+    }
+
+    visitor.visit_type_pack_mut(annotation.result_mut());
+}
+
+pub fn walk_type_argument_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    argument: &mut TypeArgument,
+) {
+    visitor.visit_type_annotation_mut(argument.val_mut());
+}
+
+pub fn walk_type_table_mut<V: VisitorMut + ?Sized>(visitor: &mut V, annotation: &mut TypeTable) {
+    for entry in annotation.entries_mut() {
+        visitor.visit_type_table_entry_mut(entry);
+    }
+
+    if let Some(indexer) = annotation.indexer_mut() {
+        visitor.visit_type_table_indexer_mut(indexer);
+    }
+}
+
+pub fn walk_type_table_entry_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    entry: &mut TypeTableEntry,
+) {
+    visitor.visit_type_annotation_mut(entry.value_mut());
+}
+
+pub fn walk_type_table_indexer_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    indexer: &mut TypeTableIndexer,
+) {
+    visitor.visit_type_annotation_mut(indexer.index_mut());
+    visitor.visit_type_annotation_mut(indexer.value_mut());
+}
+
+pub fn walk_type_array_mut<V: VisitorMut + ?Sized>(visitor: &mut V, annotation: &mut TypeArray) {
+    if let Some(item) = annotation.item_mut() {
+        visitor.visit_type_annotation_mut(item);
+    }
+}
+
+pub fn walk_type_optional_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    annotation: &mut TypeOptional,
+) {
+    visitor.visit_type_annotation_mut(annotation.annotation_mut());
+}
+
+pub fn walk_type_combination_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    annotation: &mut TypeCombination,
+) {
+    visitor.visit_type_annotation_mut(annotation.left_mut());
+    visitor.visit_type_annotation_mut(annotation.right_mut());
+}
+
+pub fn walk_type_pack_mut<V: VisitorMut + ?Sized>(visitor: &mut V, pack: &mut TypePack) {
+    for annotation in pack.types_mut() {
+        visitor.visit_type_annotation_mut(annotation);
+    }
+
+    if let Some(tail) = pack.pack_tail_mut() {
+        visitor.visit_type_pack_tail_mut(tail);
+    }
+}
+
+pub fn walk_type_pack_tail_mut<V: VisitorMut + ?Sized>(visitor: &mut V, tail: &mut TypePackTail) {
+    match tail {
+        TypePackTail::Variadic(node) => visitor.visit_type_annotation_mut(node),
+        TypePackTail::Generic(_) => {}
+    }
+}
+
+pub fn walk_type_generic_parameter_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    parameter: &mut TypeGenericParameter,
+) {
+    match parameter {
+        TypeGenericParameter::Type(node) => visitor.visit_type_generic_mut(node),
+        TypeGenericParameter::Pack(node) => visitor.visit_type_generic_pack_mut(node),
+    }
+}
+
+pub fn walk_type_generic_mut<V: VisitorMut + ?Sized>(visitor: &mut V, generic: &mut TypeGeneric) {
+    if let Some(default) = generic.default_mut() {
+        visitor.visit_type_annotation_mut(default);
+    }
+}
+
+pub fn walk_type_generic_pack_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    generic: &mut TypeGenericPack,
+) {
+    if let Some(default) = generic.default_mut() {
+        visitor.visit_type_pack_mut(default);
+    }
+}